@@ -0,0 +1,215 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+  punctuated::Punctuated, Data, DeriveInput, Error, Fields, Ident, Path,
+  Result, Token, Variant, Visibility,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Channel {
+  Mistake,
+  Failure,
+}
+
+#[derive(Default)]
+struct Container {
+  mistake: Option<Ident>,
+  failure: Option<Ident>,
+  derives: Vec<Path>,
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+  let variants = match &input.data {
+    Data::Enum(data) => &data.variants,
+    _ => {
+      return Err(Error::new_spanned(
+        &input.ident,
+        "`Aberrant` can only be derived for enums",
+      ))
+    }
+  };
+  if !input.generics.params.is_empty() {
+    return Err(Error::new_spanned(
+      &input.generics,
+      "`Aberrant` does not support generic enums",
+    ));
+  }
+
+  let container = parse_container(input)?;
+  let ident = &input.ident;
+  let vis = &input.vis;
+  let mistake = container
+    .mistake
+    .unwrap_or_else(|| format_ident!("{}Mistake", ident));
+  let failure = container
+    .failure
+    .unwrap_or_else(|| format_ident!("{}Failure", ident));
+  let alias = format_ident!("{}Aberration", ident);
+  let derives = &container.derives;
+
+  let mut mistakes = Vec::new();
+  let mut failures = Vec::new();
+  for variant in variants {
+    match parse_channel(variant)? {
+      Channel::Mistake => mistakes.push(variant),
+      Channel::Failure => failures.push(variant),
+    }
+  }
+
+  let mistake_enum = expand_enum(vis, &mistake, derives, &mistakes);
+  let failure_enum = expand_enum(vis, &failure, derives, &failures);
+  let mistake_arms = mistakes.iter().map(|variant| {
+    let (pattern, construct) = destructure(variant);
+    let name = &variant.ident;
+    (
+      quote! { #ident::#name #pattern => ::outcome::Aberration::Mistake(#mistake::#name #construct) },
+      quote! { #mistake::#name #pattern => #ident::#name #construct },
+    )
+  });
+  let failure_arms = failures.iter().map(|variant| {
+    let (pattern, construct) = destructure(variant);
+    let name = &variant.ident;
+    (
+      quote! { #ident::#name #pattern => ::outcome::Aberration::Failure(#failure::#name #construct) },
+      quote! { #failure::#name #pattern => #ident::#name #construct },
+    )
+  });
+  let (split_mistakes, join_mistakes): (Vec<_>, Vec<_>) = mistake_arms.unzip();
+  let (split_failures, join_failures): (Vec<_>, Vec<_>) = failure_arms.unzip();
+
+  let alias_doc =
+    format!("An [`Aberration`](::outcome::Aberration) split from [`{ident}`].");
+
+  Ok(quote! {
+    #mistake_enum
+    #failure_enum
+
+    #[doc = #alias_doc]
+    #vis type #alias = ::outcome::Aberration<#mistake, #failure>;
+
+    impl ::core::convert::From<#ident> for #alias {
+      fn from(value: #ident) -> Self {
+        match value {
+          #(#split_mistakes,)*
+          #(#split_failures,)*
+        }
+      }
+    }
+
+    impl ::core::convert::From<#alias> for #ident {
+      fn from(value: #alias) -> Self {
+        match value {
+          ::outcome::Aberration::Mistake(value) => value.into(),
+          ::outcome::Aberration::Failure(value) => value.into(),
+        }
+      }
+    }
+
+    impl ::core::convert::From<#mistake> for #ident {
+      fn from(value: #mistake) -> Self {
+        match value {
+          #(#join_mistakes,)*
+        }
+      }
+    }
+
+    impl ::core::convert::From<#failure> for #ident {
+      fn from(value: #failure) -> Self {
+        match value {
+          #(#join_failures,)*
+        }
+      }
+    }
+  })
+}
+
+fn expand_enum(
+  vis: &Visibility,
+  name: &Ident,
+  derives: &[Path],
+  variants: &[&Variant],
+) -> TokenStream {
+  let variants = variants.iter().map(|variant| {
+    let docs = variant.attrs.iter().filter(|a| a.path().is_ident("doc"));
+    let name = &variant.ident;
+    let fields = &variant.fields;
+    quote! { #(#docs)* #name #fields }
+  });
+  let derive =
+    (!derives.is_empty()).then(|| quote! { #[derive(#(#derives),*)] });
+  quote! {
+    #derive
+    #[allow(missing_docs)]
+    #vis enum #name {
+      #(#variants,)*
+    }
+  }
+}
+
+/// Returns a pattern binding every field of `variant`, along with the
+/// expression rebuilding it from those bindings.
+fn destructure(variant: &Variant) -> (TokenStream, TokenStream) {
+  match &variant.fields {
+    Fields::Named(fields) => {
+      let names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+      let tokens = quote! { { #(#names),* } };
+      (tokens.clone(), tokens)
+    }
+    Fields::Unnamed(fields) => {
+      let names: Vec<_> = (0..fields.unnamed.len())
+        .map(|index| Ident::new(&format!("__{index}"), Span::call_site()))
+        .collect();
+      let tokens = quote! { ( #(#names),* ) };
+      (tokens.clone(), tokens)
+    }
+    Fields::Unit => (TokenStream::new(), TokenStream::new()),
+  }
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+  let mut container = Container::default();
+  for attr in input.attrs.iter().filter(|a| a.path().is_ident("aberrant")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("mistake") {
+        container.mistake = Some(meta.value()?.parse()?);
+      } else if meta.path.is_ident("failure") {
+        container.failure = Some(meta.value()?.parse()?);
+      } else if meta.path.is_ident("derive") {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let paths = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+        container.derives.extend(paths);
+      } else {
+        return Err(meta.error("unsupported `aberrant` attribute"));
+      }
+      Ok(())
+    })?;
+  }
+  Ok(container)
+}
+
+fn parse_channel(variant: &Variant) -> Result<Channel> {
+  let mut channel = None;
+  for attr in variant
+    .attrs
+    .iter()
+    .filter(|a| a.path().is_ident("aberrant"))
+  {
+    attr.parse_nested_meta(|meta| {
+      let parsed = if meta.path.is_ident("mistake") {
+        Channel::Mistake
+      } else if meta.path.is_ident("failure") {
+        Channel::Failure
+      } else {
+        return Err(meta.error("expected `mistake` or `failure`"));
+      };
+      if channel.replace(parsed).is_some_and(|c| c != parsed) {
+        return Err(
+          meta.error("variant cannot be both `mistake` and `failure`"),
+        );
+      }
+      Ok(())
+    })?;
+  }
+  Ok(channel.unwrap_or(Channel::Failure))
+}
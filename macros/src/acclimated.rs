@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+  parse_quote, visit_mut::VisitMut, Error, Expr, GenericArgument, Item, ItemFn,
+  PathArguments, Result, ReturnType, Type,
+};
+
+/* Rewrites the body of the function, so that every `?` accepts an `Outcome`
+ * and every `return` produces a `Result`. Closures, async blocks, and nested
+ * items have their own `?` and `return`, and are left untouched.
+ */
+struct Rewrite;
+
+impl VisitMut for Rewrite {
+  fn visit_expr_mut(&mut self, expr: &mut Expr) {
+    match expr {
+      Expr::Closure(_) | Expr::Async(_) => {}
+      Expr::Try(inner) => {
+        self.visit_expr_mut(&mut inner.expr);
+        let operand = &inner.expr;
+        *inner.expr = parse_quote! {
+          ::outcome::__private::Acclimate::acclimate(#operand)
+        };
+      }
+      Expr::Return(inner) => {
+        if let Some(value) = &mut inner.expr {
+          self.visit_expr_mut(value);
+          **value = parse_quote! { ::outcome::Outcome::acclimate(#value) };
+        }
+      }
+      _ => syn::visit_mut::visit_expr_mut(self, expr),
+    }
+  }
+
+  fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/* Returns the `S`, `M`, and `F` of an `Outcome<S, M, F>` return type. */
+fn parameters(output: &ReturnType) -> Option<[&Type; 3]> {
+  let ReturnType::Type(_, ty) = output else {
+    return None;
+  };
+  let Type::Path(path) = &**ty else {
+    return None;
+  };
+  let segment = path.path.segments.last()?;
+  if segment.ident != "Outcome" {
+    return None;
+  }
+  let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+    return None;
+  };
+  let mut types = arguments.args.iter().map(|argument| match argument {
+    GenericArgument::Type(ty) => Some(ty),
+    _ => None,
+  });
+  let parameters = [types.next()??, types.next()??, types.next()??];
+  types.next().is_none().then_some(parameters)
+}
+
+pub fn expand(mut item: ItemFn) -> Result<TokenStream> {
+  let Some([success, mistake, failure]) = parameters(&item.sig.output) else {
+    return Err(Error::new_spanned(
+      &item.sig,
+      "`acclimated` functions must return an `Outcome<S, M, F>`",
+    ));
+  };
+  let output: ReturnType = parse_quote! {
+    -> ::core::result::Result<::outcome::Concern<#success, #mistake>, #failure>
+  };
+  Rewrite.visit_block_mut(&mut item.block);
+  let block = &item.block;
+  *item.block = parse_quote! {{
+    ::outcome::Outcome::acclimate(#block)
+  }};
+  item.sig.output = output;
+  Ok(quote! { #item })
+}
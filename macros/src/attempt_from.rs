@@ -0,0 +1,289 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+  visit_mut::{self, VisitMut},
+  Data, DeriveInput, Error, Fields, Generics, Ident, Lifetime, LifetimeParam,
+  Member, Result, Type, TypeReference, Variant,
+};
+
+#[derive(Default)]
+struct Container {
+  sources: Vec<Type>,
+  mistake: Option<Type>,
+  failure: Option<Type>,
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+  let container = parse_container(input)?;
+  if container.sources.is_empty() {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "`AttemptFrom` requires at least one `#[outcome(from = Type)]`",
+    ));
+  }
+  let impls = container
+    .sources
+    .iter()
+    .map(|source| {
+      let (source, generics) = name_lifetimes(source, &input.generics);
+      (source, generics, &input.data)
+    })
+    .map(|(source, generics, data)| match data {
+      Data::Struct(data) => {
+        expand_newtype(input, &generics, &container, &source, &data.fields)
+      }
+      Data::Enum(data) => {
+        let variants: Vec<_> = data.variants.iter().collect();
+        expand_enum(input, &generics, &container, &source, &variants)
+      }
+      Data::Union(_) => Err(Error::new_spanned(
+        &input.ident,
+        "`AttemptFrom` can only be derived for structs and enums",
+      )),
+    })
+    .collect::<Result<Vec<_>>>()?;
+  Ok(quote! { #(#impls)* })
+}
+
+/* A newtype delegates to the `AttemptFrom` implementation of its only field,
+ * converting the errors into the requested types, if any.
+ */
+fn expand_newtype(
+  input: &DeriveInput,
+  generics: &Generics,
+  container: &Container,
+  source: &Type,
+  fields: &Fields,
+) -> Result<TokenStream> {
+  let field = match fields.iter().collect::<Vec<_>>().as_slice() {
+    [field] => *field,
+    _ => {
+      return Err(Error::new_spanned(
+        &input.ident,
+        "`AttemptFrom` can only be derived for structs with a single field",
+      ))
+    }
+  };
+  let inner = &field.ty;
+  let member = field
+    .ident
+    .clone()
+    .map_or_else(|| Member::from(0), Member::Named);
+  let attempt = quote! { <#inner as ::outcome::convert::AttemptFrom<#source>> };
+  let (mistake, convert_mistake) = match &container.mistake {
+    Some(mistake) => {
+      (quote! { #mistake }, quote! { ::core::convert::Into::into })
+    }
+    None => (
+      quote! { #attempt::Mistake },
+      quote! { ::core::convert::identity },
+    ),
+  };
+  let (failure, convert_failure) = match &container.failure {
+    Some(failure) => {
+      (quote! { #failure }, quote! { ::core::convert::Into::into })
+    }
+    None => (
+      quote! { #attempt::Failure },
+      quote! { ::core::convert::identity },
+    ),
+  };
+
+  let ident = &input.ident;
+  let (impl_generics, _, _) = generics.split_for_impl();
+  let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+  let predicates = where_clause.map(|clause| &clause.predicates);
+  Ok(quote! {
+    impl #impl_generics ::outcome::convert::AttemptFrom<#source>
+      for #ident #ty_generics
+    where
+      #inner: ::outcome::convert::AttemptFrom<#source>,
+      #predicates
+    {
+      type Mistake = #mistake;
+      type Failure = #failure;
+
+      fn attempt_from(
+        value: #source,
+      ) -> ::outcome::Outcome<Self, Self::Mistake, Self::Failure> {
+        match #attempt::attempt_from(value) {
+          ::outcome::Outcome::Success(value) => {
+            ::outcome::Outcome::Success(Self { #member: value })
+          }
+          ::outcome::Outcome::Mistake(value) => {
+            ::outcome::Outcome::Mistake(#convert_mistake(value))
+          }
+          ::outcome::Outcome::Failure(value) => {
+            ::outcome::Outcome::Failure(#convert_failure(value))
+          }
+        }
+      }
+    }
+  })
+}
+
+/* An enum attempts the conversion of each variant in declaration order, and
+ * returns the first success. Should none succeed, the first mistake is
+ * returned, as the input might still become valid for that variant.
+ * Otherwise, the failure of the last variant is returned.
+ */
+fn expand_enum(
+  input: &DeriveInput,
+  generics: &Generics,
+  container: &Container,
+  source: &Type,
+  variants: &[&Variant],
+) -> Result<TokenStream> {
+  let (Some(mistake), Some(failure)) = (&container.mistake, &container.failure)
+  else {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "`AttemptFrom` requires `mistake` and `failure` types for enums",
+    ));
+  };
+  if variants.is_empty() {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "`AttemptFrom` cannot be derived for empty enums",
+    ));
+  }
+  let attempts = variants
+    .iter()
+    .map(|variant| {
+      let inner = variant_type(variant)?;
+      let name: &Ident = &variant.ident;
+      let attempt =
+        quote! { <#inner as ::outcome::convert::AttemptFrom<#source>> };
+      Ok((
+        quote! {
+          match #attempt::attempt_from(::core::clone::Clone::clone(&value)) {
+            ::outcome::Outcome::Success(value) => {
+              return ::outcome::Outcome::Success(Self::#name(value));
+            }
+            ::outcome::Outcome::Mistake(value) => {
+              mistake.get_or_insert_with(|| ::core::convert::Into::into(value));
+            }
+            ::outcome::Outcome::Failure(value) => {
+              let value = ::core::convert::Into::into(value);
+              failure = ::core::option::Option::Some(value);
+            }
+          }
+        },
+        quote! {
+          #inner: ::outcome::convert::AttemptFrom<#source>,
+          #attempt::Mistake: ::core::convert::Into<#mistake>,
+          #attempt::Failure: ::core::convert::Into<#failure>,
+        },
+      ))
+    })
+    .collect::<Result<Vec<_>>>()?;
+  let (attempts, bounds): (Vec<_>, Vec<_>) = attempts.into_iter().unzip();
+
+  let ident = &input.ident;
+  let (impl_generics, _, _) = generics.split_for_impl();
+  let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+  let predicates = where_clause.map(|clause| &clause.predicates);
+  Ok(quote! {
+    impl #impl_generics ::outcome::convert::AttemptFrom<#source>
+      for #ident #ty_generics
+    where
+      #source: ::core::clone::Clone,
+      #(#bounds)*
+      #predicates
+    {
+      type Mistake = #mistake;
+      type Failure = #failure;
+
+      fn attempt_from(
+        value: #source,
+      ) -> ::outcome::Outcome<Self, Self::Mistake, Self::Failure> {
+        let mut mistake: ::core::option::Option<#mistake> =
+          ::core::option::Option::None;
+        let mut failure: ::core::option::Option<#failure> =
+          ::core::option::Option::None;
+        #(#attempts)*
+        match (mistake, failure) {
+          (::core::option::Option::Some(value), _) => {
+            ::outcome::Outcome::Mistake(value)
+          }
+          (_, ::core::option::Option::Some(value)) => {
+            ::outcome::Outcome::Failure(value)
+          }
+          _ => ::core::unreachable!(),
+        }
+      }
+    }
+  })
+}
+
+/* Elided lifetimes cannot be used in the `where` clause or the signature of
+ * `attempt_from`, so they are replaced with a named lifetime that is added to
+ * the generics of the generated implementation.
+ */
+struct NameLifetimes {
+  lifetime: Lifetime,
+  used: bool,
+}
+
+impl VisitMut for NameLifetimes {
+  fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+    if lifetime.ident == "_" {
+      *lifetime = self.lifetime.clone();
+      self.used = true;
+    }
+  }
+
+  fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+    if reference.lifetime.is_none() {
+      reference.lifetime = Some(self.lifetime.clone());
+      self.used = true;
+    }
+    visit_mut::visit_type_reference_mut(self, reference);
+  }
+}
+
+fn name_lifetimes(source: &Type, generics: &Generics) -> (Type, Generics) {
+  let mut source = source.clone();
+  let mut generics = generics.clone();
+  let mut visitor = NameLifetimes {
+    lifetime: Lifetime::new("'__source", Span::call_site()),
+    used: false,
+  };
+  visitor.visit_type_mut(&mut source);
+  if visitor.used {
+    let param = LifetimeParam::new(visitor.lifetime);
+    generics.params.insert(0, param.into());
+  }
+  (source, generics)
+}
+
+fn variant_type(variant: &Variant) -> Result<&Type> {
+  match &variant.fields {
+    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+      Ok(&fields.unnamed[0].ty)
+    }
+    _ => Err(Error::new_spanned(
+      variant,
+      "`AttemptFrom` requires every variant to have a single unnamed field",
+    )),
+  }
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+  let mut container = Container::default();
+  for attr in input.attrs.iter().filter(|a| a.path().is_ident("outcome")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("from") {
+        container.sources.push(meta.value()?.parse()?);
+      } else if meta.path.is_ident("mistake") {
+        container.mistake = Some(meta.value()?.parse()?);
+      } else if meta.path.is_ident("failure") {
+        container.failure = Some(meta.value()?.parse()?);
+      } else {
+        return Err(meta.error("unsupported `outcome` attribute"));
+      }
+      Ok(())
+    })?;
+  }
+  Ok(container)
+}
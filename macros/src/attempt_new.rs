@@ -0,0 +1,150 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+  Data, DeriveInput, Error, Expr, Field, Fields, LitStr, Path, Result,
+};
+
+enum Check {
+  Range(Expr),
+  NonEmpty,
+  Regex(LitStr),
+  With(Path),
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+  let fields = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => &fields.named,
+      _ => {
+        return Err(Error::new_spanned(
+          &input.ident,
+          "`AttemptNew` can only be derived for structs with named fields",
+        ))
+      }
+    },
+    _ => {
+      return Err(Error::new_spanned(
+        &input.ident,
+        "`AttemptNew` can only be derived for structs",
+      ))
+    }
+  };
+
+  let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+  let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+  let checks = fields
+    .iter()
+    .map(expand_field)
+    .collect::<Result<Vec<_>>>()?;
+
+  let vis = &input.vis;
+  let ident = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  Ok(quote! {
+    impl #impl_generics #ident #ty_generics #where_clause {
+      /// Attempts to construct a new value, validating each field.
+      ///
+      /// All correctable violations are returned together as a `Mistake`.
+      /// Structural errors are returned immediately as a `Failure`.
+      #[allow(clippy::too_many_arguments)]
+      #vis fn attempt_new(#(#names: #types),*) -> ::outcome::Outcome<
+        Self,
+        ::outcome::validate::__private::Vec<::outcome::validate::Violation>,
+        ::outcome::validate::Malformed,
+      > {
+        #[allow(unused_mut)]
+        let mut violations = ::outcome::validate::__private::Vec::new();
+        #(#checks)*
+        if !violations.is_empty() {
+          return ::outcome::Outcome::Mistake(violations);
+        }
+        ::outcome::Outcome::Success(Self { #(#names),* })
+      }
+    }
+  })
+}
+
+fn expand_field(field: &Field) -> Result<TokenStream> {
+  let name = &field.ident;
+  let label = name.as_ref().map(ToString::to_string).unwrap_or_default();
+  let checks = parse_checks(field)?;
+  let checks = checks.iter().map(|check| match check {
+    Check::Range(range) => quote! {
+      if !::core::ops::RangeBounds::contains(&(#range), &#name) {
+        violations.push(::outcome::validate::Violation::new(
+          #label,
+          ::outcome::validate::Reason::OutOfRange,
+        ));
+      }
+    },
+    Check::NonEmpty => quote! {
+      if #name.is_empty() {
+        violations.push(::outcome::validate::Violation::new(
+          #label,
+          ::outcome::validate::Reason::Empty,
+        ));
+      }
+    },
+    Check::Regex(pattern) => quote! {
+      {
+        use ::outcome::validate::__private::{regex, OnceLock, ToString};
+        static PATTERN: OnceLock<
+          ::core::result::Result<regex::Regex, regex::Error>,
+        > = OnceLock::new();
+        match PATTERN.get_or_init(|| regex::Regex::new(#pattern)) {
+          ::core::result::Result::Ok(pattern) => {
+            if !pattern.is_match(::core::convert::AsRef::<str>::as_ref(&#name)) {
+              violations.push(::outcome::validate::Violation::new(
+                #label,
+                ::outcome::validate::Reason::Mismatch,
+              ));
+            }
+          }
+          ::core::result::Result::Err(error) => {
+            return ::outcome::Outcome::Failure(
+              ::outcome::validate::Malformed::new(#label, error.to_string()),
+            );
+          }
+        }
+      }
+    },
+    Check::With(path) => quote! {
+      match #path(&#name) {
+        ::outcome::Outcome::Success(()) => {}
+        ::outcome::Outcome::Mistake(reason) => {
+          violations.push(::outcome::validate::Violation::new(#label, reason));
+        }
+        ::outcome::Outcome::Failure(failure) => {
+          return ::outcome::Outcome::Failure(failure);
+        }
+      }
+    },
+  });
+  Ok(quote! { #(#checks)* })
+}
+
+fn parse_checks(field: &Field) -> Result<Vec<Check>> {
+  let mut checks = Vec::new();
+  for attr in field.attrs.iter().filter(|a| a.path().is_ident("attempt")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("range") {
+        checks.push(Check::Range(meta.value()?.parse()?));
+      } else if meta.path.is_ident("non_empty") {
+        checks.push(Check::NonEmpty);
+      } else if meta.path.is_ident("regex") {
+        if cfg!(not(feature = "regex")) {
+          return Err(meta.error("`regex` requires the `regex` feature"));
+        }
+        checks.push(Check::Regex(meta.value()?.parse()?));
+      } else if meta.path.is_ident("with") {
+        checks.push(Check::With(meta.value()?.parse()?));
+      } else {
+        return Err(meta.error("unsupported `attempt` attribute"));
+      }
+      Ok(())
+    })?;
+  }
+  Ok(checks)
+}
@@ -0,0 +1,97 @@
+//! Procedural macros for the [`outcome`] crate.
+//!
+//! This crate is not meant to be used directly. Instead, enable the
+//! corresponding feature on `outcome` and use the re-exported macros.
+//!
+//! [`outcome`]: https://docs.rs/outcome-46f94afc-026f-5511-9d7e-7d1fd495fb5c
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use syn::{meta, parse_macro_input, DeriveInput, ItemFn};
+
+mod aberrant;
+mod acclimated;
+mod attempt_from;
+mod attempt_new;
+mod should;
+
+/// Generates a validating `attempt_new` constructor.
+///
+/// See the `outcome::validate` module for more details.
+#[proc_macro_derive(AttemptNew, attributes(attempt))]
+pub fn derive_attempt_new(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  attempt_new::expand(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Generates `AttemptFrom` implementations for newtypes and enum wrappers.
+///
+/// See the `outcome::convert::AttemptFrom` re-export for more details.
+#[proc_macro_derive(AttemptFrom, attributes(outcome))]
+pub fn derive_attempt_from(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  attempt_from::expand(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Splits an error enum into separate mistake and failure enums.
+///
+/// See the `outcome::Aberrant` re-export for more details.
+#[proc_macro_derive(Aberrant, attributes(aberrant))]
+pub fn derive_aberrant(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  aberrant::expand(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Allows `?` to be used on an `Outcome` in a function returning `Result`.
+///
+/// See the `outcome::acclimated` re-export for more details.
+#[proc_macro_attribute]
+pub fn acclimated(args: TokenStream, item: TokenStream) -> TokenStream {
+  if !args.is_empty() {
+    let args = proc_macro2::TokenStream::from(args);
+    return syn::Error::new_spanned(args, "`acclimated` takes no arguments")
+      .into_compile_error()
+      .into();
+  }
+  let item = parse_macro_input!(item as ItemFn);
+  acclimated::expand(item)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// Expects a test function to return a `Mistake`.
+///
+/// See the `outcome::testing` module for more details.
+#[proc_macro_attribute]
+pub fn should_mistake(args: TokenStream, item: TokenStream) -> TokenStream {
+  should(args, item, "should_mistake", "Mistake")
+}
+
+/// Expects a test function to return a `Failure`.
+///
+/// See the `outcome::testing` module for more details.
+#[proc_macro_attribute]
+pub fn should_fail(args: TokenStream, item: TokenStream) -> TokenStream {
+  should(args, item, "should_fail", "Failure")
+}
+
+fn should(
+  args: TokenStream,
+  item: TokenStream,
+  attribute: &str,
+  variant: &str,
+) -> TokenStream {
+  let mut parsed = should::Args::default();
+  let parser = meta::parser(|meta| parsed.parse(&meta));
+  parse_macro_input!(args with parser);
+  let item = parse_macro_input!(item as ItemFn);
+  should::expand(attribute, variant, parsed, item)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
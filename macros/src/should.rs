@@ -0,0 +1,97 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+  meta::ParseNestedMeta, Error, Expr, Ident, ItemFn, Pat, Result, ReturnType,
+  Visibility,
+};
+
+enum Expectation {
+  Any,
+  Matches(Pat),
+  With(Expr),
+}
+
+#[derive(Default)]
+pub struct Args {
+  expectation: Option<Expectation>,
+}
+
+impl Args {
+  pub fn parse(&mut self, meta: &ParseNestedMeta<'_>) -> Result<()> {
+    if self.expectation.is_some() {
+      return Err(meta.error("only one of `matches` or `with` may be given"));
+    }
+    if meta.path.is_ident("matches") {
+      let pattern = Pat::parse_multi_with_leading_vert(meta.value()?)?;
+      self.expectation = Some(Expectation::Matches(pattern));
+    } else if meta.path.is_ident("with") {
+      self.expectation = Some(Expectation::With(meta.value()?.parse()?));
+    } else {
+      return Err(
+        meta.error("expected `matches = <pattern>` or `with = <fn>`"),
+      );
+    }
+    Ok(())
+  }
+}
+
+pub fn expand(
+  attribute: &str,
+  variant: &str,
+  args: Args,
+  mut item: ItemFn,
+) -> Result<TokenStream> {
+  if let Some(asyncness) = &item.sig.asyncness {
+    return Err(Error::new_spanned(
+      asyncness,
+      format!("`{attribute}` does not support async functions"),
+    ));
+  }
+  if !item.sig.inputs.is_empty() || !item.sig.generics.params.is_empty() {
+    return Err(Error::new_spanned(
+      &item.sig,
+      "test functions cannot take arguments or generic parameters",
+    ));
+  }
+  if matches!(item.sig.output, ReturnType::Default) {
+    return Err(Error::new_spanned(
+      &item.sig,
+      "test function must return an `Outcome`",
+    ));
+  }
+
+  let (description, predicate) =
+    match args.expectation.unwrap_or(Expectation::Any) {
+      Expectation::Any => (String::new(), quote! { |_| true }),
+      Expectation::Matches(pattern) => (
+        pattern.to_token_stream().to_string(),
+        quote! { |value| ::core::matches!(value, #pattern) },
+      ),
+      Expectation::With(predicate) => (
+        predicate.to_token_stream().to_string(),
+        quote! { #predicate },
+      ),
+    };
+
+  let attrs = core::mem::take(&mut item.attrs);
+  let vis = core::mem::replace(&mut item.vis, Visibility::Inherited);
+  let name = core::mem::replace(
+    &mut item.sig.ident,
+    Ident::new("__outcome_test", Span::call_site()),
+  );
+  let check = Ident::new(
+    &format!("expect_{}", variant.to_lowercase()),
+    Span::call_site(),
+  );
+  Ok(quote! {
+    #(#attrs)*
+    #vis fn #name() {
+      #item
+      ::outcome::testing::__private::#check(
+        __outcome_test(),
+        #predicate,
+        #description,
+      );
+    }
+  })
+}
@@ -2,18 +2,17 @@
 extern crate std;
 
 #[cfg(feature = "std")]
-use std::{
-  eprintln,
-  process::{ExitCode, Termination},
-};
+use std::process::{ExitCode, Termination};
 
 #[cfg(not(feature = "nightly"))]
 use core::convert::Infallible;
-use core::fmt::Debug;
+use core::fmt::{self, Debug, Display, Formatter};
 
 #[cfg(not(feature = "nightly"))]
 use crate::outcome::Outcome;
-use crate::private::panic;
+#[cfg(feature = "std")]
+use crate::private::terminate;
+use crate::{iter::*, private::panic, severity::Severity};
 
 /// `Aberration` is a type that can represent a [`Mistake`], or [`Failure`].
 ///
@@ -49,7 +48,7 @@ impl<M, F> Aberration<M, F> {
   /// let x: Aberration<&str, u32> = Aberration::Failure(47);
   /// assert_eq!(x.as_ref(), Aberration::Failure(&47));
   #[inline]
-  pub fn as_ref(&self) -> Aberration<&M, &F> {
+  pub const fn as_ref(&self) -> Aberration<&M, &F> {
     match *self {
       Self::Mistake(ref value) => Aberration::Mistake(value),
       Self::Failure(ref value) => Aberration::Failure(value),
@@ -78,13 +77,62 @@ impl<M, F> Aberration<M, F> {
   /// assert_eq!(x.unwrap_failure(), 47);
   /// ```
   #[inline]
-  pub fn as_mut(&mut self) -> Aberration<&mut M, &mut F> {
+  pub const fn as_mut(&mut self) -> Aberration<&mut M, &mut F> {
     match *self {
       Self::Mistake(ref mut value) => Aberration::Mistake(value),
       Self::Failure(ref mut value) => Aberration::Failure(value),
     }
   }
 
+  /// Returns an iterator over the possibly contained [`Mistake`] value.
+  ///
+  /// The iterator yields one value if the aberration is a [`Mistake`],
+  /// otherwise none.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, i32> = Aberration::Mistake(42);
+  /// assert_eq!(x.iter().next(), Some(&42));
+  ///
+  /// let x: Aberration<u32, i32> = Aberration::Failure(47);
+  /// assert_eq!(x.iter().next(), None);
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn iter(&self) -> Iter<'_, M> {
+    Iter {
+      inner: self.as_ref().mistake(),
+    }
+  }
+
+  /// Returns a mutable iterator over the possibly contained [`Mistake`]
+  /// value.
+  ///
+  /// The iterator yields one value if the aberration is a [`Mistake`],
+  /// otherwise none.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Aberration<i32, &str> = Aberration::Mistake(7);
+  /// if let Some(v) = x.iter_mut().next() {
+  ///   *v += 40;
+  /// }
+  /// assert_eq!(x, Aberration::Mistake(47));
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn iter_mut(&mut self) -> IterMut<'_, M> {
+    IterMut {
+      inner: self.as_mut().mistake(),
+    }
+  }
+
   /// Returns `true` if the aberration is a [`Mistake`]
   ///
   /// # Examples
@@ -103,7 +151,7 @@ impl<M, F> Aberration<M, F> {
   /// [`Mistake`]: Aberration::Mistake
   #[must_use = "if you intended to assert a mistake, consider `.unwrap_mistake()` instead"]
   #[inline]
-  pub fn is_mistake(&self) -> bool {
+  pub const fn is_mistake(&self) -> bool {
     if let Self::Mistake(_) = self {
       return true;
     }
@@ -127,13 +175,98 @@ impl<M, F> Aberration<M, F> {
   /// [`Failure`]: Aberration::Failure
   #[must_use = "if you intended to assert a failure, consider `.unwrap_failure()` instead"]
   #[inline]
-  pub fn is_failure(&self) -> bool {
+  pub const fn is_failure(&self) -> bool {
     if let Self::Failure(_) = self {
       return true;
     }
     false
   }
 
+  /// Returns the [`Severity`] of the aberration, which is never
+  /// [`Severity::Success`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::Severity;
+  ///
+  /// let x: Aberration<u32, i32> = Aberration::Failure(1);
+  /// assert_eq!(x.severity(), Severity::Failure);
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn severity(&self) -> Severity {
+    match self {
+      Self::Mistake(_) => Severity::Mistake,
+      Self::Failure(_) => Severity::Failure,
+    }
+  }
+
+  /// Returns the contained [`Mistake`] value, consuming the `self` value,
+  /// without checking that the value is not a [`Failure`].
+  ///
+  /// # Safety
+  ///
+  /// Calling this method on a [`Failure`] is *[undefined behavior]*
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, f32> = Aberration::Mistake(47);
+  /// assert_eq!(unsafe { x.unwrap_mistake_unchecked() }, 47);
+  /// ```
+  ///
+  /// ```no_run
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, f32> = Aberration::Failure(0.0f32);
+  /// unsafe { x.unwrap_mistake_unchecked(); } // Undefined Behavior!
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+  #[inline]
+  #[track_caller]
+  #[allow(unsafe_code)]
+  pub unsafe fn unwrap_mistake_unchecked(self) -> M {
+    debug_assert!(self.is_mistake());
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    core::hint::unreachable_unchecked();
+  }
+
+  /// Returns the contained [`Failure`] value, consuming the `self` value,
+  /// without checking that the value is not a [`Mistake`].
+  ///
+  /// # Safety
+  ///
+  /// Calling this method on a [`Mistake`] is *[undefined behavior]*
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, &str> = Aberration::Failure("emergency!");
+  /// assert_eq!(unsafe { x.unwrap_failure_unchecked() }, "emergency!");
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+  #[inline]
+  #[track_caller]
+  #[allow(unsafe_code)]
+  pub unsafe fn unwrap_failure_unchecked(self) -> F {
+    debug_assert!(self.is_failure());
+    if let Self::Failure(value) = self {
+      return value;
+    }
+    core::hint::unreachable_unchecked();
+  }
+
   /// Converts from `Aberration<M, F>` to [`Option<M>`]
   ///
   /// Converts `self` into an [`Option<M>`], consuming `self`, and discarding
@@ -180,6 +313,123 @@ impl<M, F> Aberration<M, F> {
     None
   }
 
+  /// Returns the contained [`Mistake`] value or a provided default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, &str> = Aberration::Mistake(2);
+  /// assert_eq!(x.unwrap_mistake_or(3), 2);
+  ///
+  /// let x: Aberration<u32, &str> = Aberration::Failure("emergency");
+  /// assert_eq!(x.unwrap_mistake_or(3), 3);
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn unwrap_mistake_or(self, default: M) -> M {
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    default
+  }
+
+  /// Returns the contained [`Mistake`] value or computes it from the
+  /// contained [`Failure`] value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<usize, &str> = Aberration::Failure("emergency");
+  /// assert_eq!(x.unwrap_mistake_or_else(str::len), 9);
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn unwrap_mistake_or_else<C>(self, callable: C) -> M
+  where
+    C: FnOnce(F) -> M,
+  {
+    match self {
+      Self::Mistake(value) => value,
+      Self::Failure(value) => callable(value),
+    }
+  }
+
+  /// Returns the contained [`Failure`] value or a provided default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, u32> = Aberration::Failure(2);
+  /// assert_eq!(x.unwrap_failure_or(3), 2);
+  ///
+  /// let x: Aberration<&str, u32> = Aberration::Mistake("busy");
+  /// assert_eq!(x.unwrap_failure_or(3), 3);
+  /// ```
+  ///
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn unwrap_failure_or(self, default: F) -> F {
+    if let Self::Failure(value) = self {
+      return value;
+    }
+    default
+  }
+
+  /// Returns the contained [`Failure`] value or computes it from the
+  /// contained [`Mistake`] value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, usize> = Aberration::Mistake("busy");
+  /// assert_eq!(x.unwrap_failure_or_else(str::len), 4);
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn unwrap_failure_or_else<C>(self, callable: C) -> F
+  where
+    C: FnOnce(M) -> F,
+  {
+    match self {
+      Self::Mistake(value) => callable(value),
+      Self::Failure(value) => value,
+    }
+  }
+
+  /// Returns a `Result<M, F>`, which allows a user to still rely on the `?`
+  /// operator until [`Try`] has been stabilized.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn invoke(x: Aberration<u32, &'static str>) -> Result<u32, &'static str> {
+  ///   let mistake = x.acclimate()?;
+  ///   Ok(mistake + 1)
+  /// }
+  ///
+  /// assert_eq!(invoke(Aberration::Mistake(1)), Ok(2));
+  /// assert_eq!(invoke(Aberration::Failure("error")), Err("error"));
+  /// ```
+  ///
+  /// [`Try`]: core::ops::Try
+  #[inline]
+  pub fn acclimate(self) -> Result<M, F> {
+    match self {
+      Self::Mistake(value) => Ok(value),
+      Self::Failure(value) => Err(value),
+    }
+  }
+
   /// Maps an `Aberration<M, F>` to `Aberration<N, F>` by applying a function
   /// to a contained [`Mistake`] value, leaving any [`Failure`] value
   /// untouched.
@@ -224,6 +474,159 @@ impl<M, F> Aberration<M, F> {
       Self::Failure(value) => Aberration::Failure(callable(value)),
     }
   }
+
+  /// Calls `callable` if the aberration is a [`Mistake`], otherwise returns
+  /// the [`Failure`] value of `self`.
+  ///
+  /// This can be used to reclassify a mistake, such as escalating it into a
+  /// failure once it can no longer be retried.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn classify(attempts: u32) -> Aberration<u32, &'static str> {
+  ///   match attempts {
+  ///     0..=2 => Aberration::Mistake(attempts + 1),
+  ///     _ => Aberration::Failure("too many attempts"),
+  ///   }
+  /// }
+  ///
+  /// let x: Aberration<u32, &str> = Aberration::Mistake(1);
+  /// assert_eq!(x.and_then(classify), Aberration::Mistake(2));
+  ///
+  /// let x: Aberration<u32, &str> = Aberration::Mistake(3);
+  /// assert_eq!(x.and_then(classify), Aberration::Failure("too many attempts"));
+  ///
+  /// let x: Aberration<u32, &str> = Aberration::Failure("gone");
+  /// assert_eq!(x.and_then(classify), Aberration::Failure("gone"));
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn and_then<N, C>(self, callable: C) -> Aberration<N, F>
+  where
+    C: FnOnce(M) -> Aberration<N, F>,
+  {
+    match self {
+      Self::Mistake(value) => callable(value),
+      Self::Failure(value) => Aberration::Failure(value),
+    }
+  }
+
+  /// Calls `callable` if the aberration is a [`Failure`], otherwise returns
+  /// the [`Mistake`] value of `self`.
+  ///
+  /// This can be used to recover from a failure, such as by demoting it to a
+  /// mistake when it is known to be transient.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn demote(error: &'static str) -> Aberration<&'static str, u32> {
+  ///   match error {
+  ///     "timed out" => Aberration::Mistake(error),
+  ///     _ => Aberration::Failure(500),
+  ///   }
+  /// }
+  ///
+  /// let x: Aberration<&str, &str> = Aberration::Failure("timed out");
+  /// assert_eq!(x.or_else(demote), Aberration::Mistake("timed out"));
+  ///
+  /// let x: Aberration<&str, &str> = Aberration::Failure("disk full");
+  /// assert_eq!(x.or_else(demote), Aberration::Failure(500));
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn or_else<G, C>(self, callable: C) -> Aberration<M, G>
+  where
+    C: FnOnce(F) -> Aberration<M, G>,
+  {
+    match self {
+      Self::Mistake(value) => Aberration::Mistake(value),
+      Self::Failure(value) => callable(value),
+    }
+  }
+
+  /// Calls `callable` with a reference to the contained [`Mistake`] value,
+  /// if any, and returns the `Aberration` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Aberration<&str, &str> = Aberration::Mistake("busy");
+  /// let x = x.inspect_mistake(|value| seen = Some(*value));
+  /// assert_eq!(x, Aberration::Mistake("busy"));
+  /// assert_eq!(seen, Some("busy"));
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn inspect_mistake<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&M),
+  {
+    if let Self::Mistake(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Calls `callable` with a reference to the contained [`Failure`] value,
+  /// if any, and returns the `Aberration` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Aberration<&str, &str> = Aberration::Failure("error");
+  /// let x = x.inspect_failure(|value| seen = Some(*value));
+  /// assert_eq!(x, Aberration::Failure("error"));
+  /// assert_eq!(seen, Some("error"));
+  /// ```
+  ///
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn inspect_failure<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&F),
+  {
+    if let Self::Failure(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Collapses both variants into a single error type `E`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, String> = Aberration::Mistake("foo");
+  /// assert_eq!(x.unify::<String>(), "foo");
+  ///
+  /// let x: Aberration<&str, String> = Aberration::Failure("bar".into());
+  /// assert_eq!(x.unify::<String>(), "bar");
+  /// ```
+  #[inline]
+  pub fn unify<E>(self) -> E
+  where
+    M: Into<E>,
+    F: Into<E>,
+  {
+    match self {
+      Self::Mistake(value) => value.into(),
+      Self::Failure(value) => value.into(),
+    }
+  }
 }
 
 #[cfg(not(feature = "nightly"))]
@@ -308,6 +711,179 @@ impl<M: Debug, F> Aberration<M, F> {
   }
 }
 
+impl<M: Default, F> Aberration<M, F> {
+  /// Returns the contained [`Mistake`] value or a default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u32, &str> = Aberration::Failure("emergency");
+  /// assert_eq!(x.mistake_or_default(), 0);
+  /// ```
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn mistake_or_default(self) -> M {
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    M::default()
+  }
+}
+
+impl<M, F: Default> Aberration<M, F> {
+  /// Returns the contained [`Failure`] value or a default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, String> = Aberration::Mistake("busy");
+  /// assert_eq!(x.failure_or_default(), "");
+  /// ```
+  ///
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn failure_or_default(self) -> F {
+    if let Self::Failure(value) = self {
+      return value;
+    }
+    F::default()
+  }
+}
+
+impl<M, F> Aberration<M, F> {
+  /// Converts the contained value into a `T`, regardless of the variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, String> = Aberration::Mistake("busy");
+  /// assert_eq!(x.converge::<String>(), "busy");
+  /// ```
+  #[inline]
+  pub fn converge<T>(self) -> T
+  where
+    M: Into<T>,
+    F: Into<T>,
+  {
+    match self {
+      Self::Mistake(value) => value.into(),
+      Self::Failure(value) => value.into(),
+    }
+  }
+}
+
+impl<T> Aberration<T, T> {
+  /// Returns the contained value, regardless of the variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<&str, &str> = Aberration::Failure("emergency!");
+  /// assert_eq!(x.into_inner(), "emergency!");
+  /// ```
+  #[inline]
+  pub fn into_inner(self) -> T {
+    match self {
+      Self::Mistake(value) | Self::Failure(value) => value,
+    }
+  }
+}
+
+impl<M: Clone, F: Clone> Aberration<&M, &F> {
+  /// Maps a `Aberration<&M, &F>` to a `Aberration<M, F>` by cloning the
+  /// contained value, whatever its variant.
+  ///
+  /// This is the equivalent of [`Outcome::cloned_all`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let errors: Vec<Aberration<String, String>> =
+  ///   vec![Aberration::Mistake("a".into()), Aberration::Failure("b".into())];
+  /// let owned: Vec<_> =
+  ///   errors.iter().map(|x| x.as_ref().cloned_all()).collect();
+  /// assert_eq!(owned, errors);
+  /// ```
+  ///
+  /// [`Outcome::cloned_all`]: crate::prelude::Outcome::cloned_all
+  #[inline]
+  pub fn cloned_all(self) -> Aberration<M, F> {
+    match self {
+      Self::Mistake(value) => Aberration::Mistake(value.clone()),
+      Self::Failure(value) => Aberration::Failure(value.clone()),
+    }
+  }
+}
+
+impl<M: Clone, F: Clone> Aberration<&mut M, &mut F> {
+  /// Maps a `Aberration<&mut M, &mut F>` to a `Aberration<M, F>` by cloning the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Aberration<String, ()> = Aberration::Mistake("busy".into());
+  /// assert_eq!(
+  ///   x.as_mut().cloned_all(),
+  ///   Aberration::Mistake("busy".to_string())
+  /// );
+  /// ```
+  #[inline]
+  pub fn cloned_all(self) -> Aberration<M, F> {
+    match self {
+      Self::Mistake(value) => Aberration::Mistake(value.clone()),
+      Self::Failure(value) => Aberration::Failure(value.clone()),
+    }
+  }
+}
+
+impl<M: Copy, F: Copy> Aberration<&M, &F> {
+  /// Maps a `Aberration<&M, &F>` to a `Aberration<M, F>` by copying the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Aberration<u8, i8> = Aberration::Failure(-1);
+  /// assert_eq!(x.as_ref().copied_all(), Aberration::Failure(-1));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Aberration<M, F> {
+    match self {
+      Self::Mistake(&value) => Aberration::Mistake(value),
+      Self::Failure(&value) => Aberration::Failure(value),
+    }
+  }
+}
+
+impl<M: Copy, F: Copy> Aberration<&mut M, &mut F> {
+  /// Maps a `Aberration<&mut M, &mut F>` to a `Aberration<M, F>` by copying the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Aberration<u8, i8> = Aberration::Mistake(3);
+  /// assert_eq!(x.as_mut().copied_all(), Aberration::Mistake(3));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Aberration<M, F> {
+    match self {
+      Self::Mistake(&mut value) => Aberration::Mistake(value),
+      Self::Failure(&mut value) => Aberration::Failure(value),
+    }
+  }
+}
+
 impl<M: Clone, F: Clone> Clone for Aberration<M, F> {
   #[inline]
   fn clone(&self) -> Self {
@@ -327,15 +903,75 @@ impl<M: Clone, F: Clone> Clone for Aberration<M, F> {
   }
 }
 
+/// Formats the contained value, prefixed with the name of its variant.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let x: Aberration<&str, &str> = Aberration::Failure("gave up");
+/// assert_eq!(x.to_string(), "Failure: gave up");
+/// ```
+impl<M: Display, F: Display> Display for Aberration<M, F> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Mistake(value) => write!(f, "Mistake: {value}"),
+      Self::Failure(value) => write!(f, "Failure: {value}"),
+    }
+  }
+}
+
+/// Allows an `Aberration` to be used wherever an error is expected, such as
+/// in a `Box<dyn Error>`.
+///
+/// The [`source`] of the contained error is returned as the source of the
+/// `Aberration`, as its [`Display`] output already includes the contained
+/// error itself.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use std::{error::Error, fmt};
+///
+/// #[derive(Debug)]
+/// struct Busy;
+///
+/// impl fmt::Display for Busy {
+///   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///     f.write_str("the server is busy")
+///   }
+/// }
+///
+/// impl Error for Busy {}
+///
+/// let x: Aberration<Busy, std::io::Error> = Aberration::Mistake(Busy);
+/// let error: Box<dyn Error> = Box::new(x);
+/// assert_eq!(error.to_string(), "Mistake: the server is busy");
+/// ```
+///
+/// [`source`]: std::error::Error::source
+#[cfg(feature = "std")]
+impl<M, F> std::error::Error for Aberration<M, F>
+where
+  M: std::error::Error,
+  F: std::error::Error,
+{
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::Mistake(value) => value.source(),
+      Self::Failure(value) => value.source(),
+    }
+  }
+}
+
 #[cfg(feature = "std")]
 impl<M: Debug, F: Debug> Termination for Aberration<M, F> {
   #[inline]
   fn report(self) -> ExitCode {
-    #[allow(clippy::print_stderr)]
     match self {
-      Self::Mistake(m) => eprintln!("Mistake: {m:?}"),
-      Self::Failure(f) => eprintln!("Failure: {f:?}"),
-    };
-    ExitCode::FAILURE
+      Self::Mistake(m) => terminate("Mistake", &m),
+      Self::Failure(f) => terminate("Failure", &f),
+    }
   }
 }
@@ -0,0 +1,64 @@
+//! Support for the [`anyhow`] crate.
+//!
+//! This module re-exports `anyhow`'s [`Error`] as well as [`Result`], and the
+//! [`WrapFailure`] trait, as a mirror to the [`Context`] trait. Additionally,
+//! [`WrapFailure`] is also implemented for [`Result<T, E>`]. Lastly, to stay
+//! in line with the `report` and `diagnostic` modules, the [`WrapFailure`]
+//! trait is *also* sealed. These traits are shared with the `report` and
+//! `diagnostic` modules (see the [`backend`](crate::backend) module).
+//!
+//! Any value that converts into an [`Error`] may be wrapped, and an
+//! [`Aberration`] whose values do so can be converted into an [`Error`] with
+//! [`Aberration::into_anyhow`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::anyhow::{Error, WrapFailure};
+//! use std::io::ErrorKind;
+//!
+//! let x: Outcome<(), (), std::io::Error> = Failure(ErrorKind::NotFound.into());
+//! let x: Outcome<(), (), Error> = x.wrap_failure("Failed to read config");
+//! assert_eq!(x.unwrap_failure().to_string(), "Failed to read config");
+//! ```
+//!
+//! [`Context`]: ::anyhow::Context
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Aberration::into_anyhow`]: crate::prelude::Aberration::into_anyhow
+//! [`anyhow`]: https://crates.io/crates/anyhow
+extern crate std;
+
+use crate::prelude::*;
+
+#[doc(no_inline)]
+pub use crate::backend::{Section, WrapFailure, WrapMistake};
+#[doc(no_inline)]
+pub use ::anyhow::{Error, Result};
+
+impl<M: Into<Error>, F: Into<Error>> Aberration<M, F> {
+  /// Converts the contained value into an [`Error`].
+  ///
+  /// When both `M` and `F` implement [`std::error::Error`], an `Aberration`
+  /// can already be converted with [`Error::from`], but the [`Error`] will
+  /// then contain the `Aberration` itself, rather than its value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::anyhow::Error;
+  /// use std::io::ErrorKind;
+  ///
+  /// let x: Aberration<std::io::Error, Error> =
+  ///   Aberration::Mistake(ErrorKind::WouldBlock.into());
+  /// assert!(x.into_anyhow().is::<std::io::Error>());
+  /// ```
+  #[inline]
+  pub fn into_anyhow(self) -> Error {
+    match self {
+      Self::Mistake(m) => m.into(),
+      Self::Failure(f) => f.into(),
+    }
+  }
+}
@@ -0,0 +1,628 @@
+//! A common interface over error reporting crates.
+//!
+//! The operations shared by error reporting crates (creating a report from an
+//! error, wrapping it with further context, and attaching notes to it) are
+//! described by the [`ReportBackend`] trait, which is implemented for
+//! [`eyre::Report`], [`miette::Report`], and [`anyhow::Error`] when the
+//! corresponding feature is enabled.
+//!
+//! The [`WrapFailure`], [`WrapMistake`], and [`Section`] traits are generic
+//! over any [`ReportBackend`], and are re-exported by the `report`,
+//! `diagnostic`, and `anyhow` modules. The report type is inferred from how
+//! the wrapped value is used, so the same traits serve every backend.
+//!
+//! [`ReportBackend`] is not sealed. A project with its own report type may
+//! implement it, and then use the traits above in the same way as with the
+//! report types `outcome` knows of.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::backend::{ReportBackend, WrapFailure};
+//! use std::fmt::{self, Display, Formatter};
+//!
+//! #[derive(Debug)]
+//! struct Breadcrumbs(Vec<String>);
+//!
+//! impl Display for Breadcrumbs {
+//!   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//!     f.write_str(&self.0.join(": "))
+//!   }
+//! }
+//!
+//! impl ReportBackend for Breadcrumbs {
+//!   fn from_error<E>(error: E) -> Self
+//!   where
+//!     E: std::error::Error + Send + Sync + 'static,
+//!   {
+//!     Self(vec![error.to_string()])
+//!   }
+//!
+//!   fn wrap<D>(mut self, message: D) -> Self
+//!   where
+//!     D: Display + Send + Sync + 'static,
+//!   {
+//!     self.0.insert(0, message.to_string());
+//!     self
+//!   }
+//! }
+//!
+//! let error = std::io::Error::other("disk is full");
+//! let x: Outcome<(), (), _> = Failure(Breadcrumbs::from_error(error));
+//! let x: Outcome<(), (), Breadcrumbs> = x.wrap_failure("Failed to save");
+//! assert_eq!(x.unwrap_failure().to_string(), "Failed to save: disk is full");
+//! ```
+//!
+//! [`eyre::Report`]: https://docs.rs/eyre
+//! [`miette::Report`]: https://docs.rs/miette
+//! [`anyhow::Error`]: https://docs.rs/anyhow
+extern crate std;
+
+#[cfg(feature = "diagnostic")]
+use std::{boxed::Box, string::ToString};
+use std::{
+  error::Error,
+  fmt::{Debug, Display},
+};
+
+use crate::prelude::*;
+
+/// A report type that errors can be converted into, and wrapped with further
+/// context.
+///
+/// See the [module documentation](crate::backend) for details.
+pub trait ReportBackend:
+  Debug + Display + Send + Sync + Sized + 'static
+{
+  /// Creates a report from an error.
+  fn from_error<E>(error: E) -> Self
+  where
+    E: Error + Send + Sync + 'static;
+
+  /// Wraps the report with a new message, describing the context in which
+  /// it occurred.
+  #[must_use]
+  fn wrap<D>(self, message: D) -> Self
+  where
+    D: Display + Send + Sync + 'static;
+
+  /// Attaches a note to the report, giving the reader further information.
+  ///
+  /// Unlike [`wrap`](ReportBackend::wrap), a note does not describe the
+  /// context in which the error occurred. A `miette::Report` shows the
+  /// note as help text. Backends without a separate concept of notes, such
+  /// as `eyre::Report` and `anyhow::Error`, add the note as another
+  /// layer of context, which is what the default implementation does.
+  #[must_use]
+  fn attach_note<D>(self, note: D) -> Self
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.wrap(note)
+  }
+}
+
+/* Every `WrapFailure` implementation funnels through this function. Failures
+ * wrapped inside a `context::scope` pick up the active context stack as the
+ * outermost message of their report, and the failure hook sees the result.
+ */
+#[track_caller]
+pub(crate) fn wrap_failure<R, D>(report: R, message: D) -> R
+where
+  R: ReportBackend,
+  D: Display + Send + Sync + 'static,
+{
+  let report = crate::private::contextualize(report.wrap(message));
+  crate::hooks::failure(&report);
+  report
+}
+
+/// Wraps the failure value of an [`Outcome`], [`Aberration`], or [`Result`]
+/// with a new message, converting it into a [`ReportBackend`].
+///
+/// This trait is the `outcome` analogue of `eyre::WrapErr`, `miette::WrapErr`,
+/// and `anyhow::Context`, and is re-exported by the `report`, `diagnostic`,
+/// and `anyhow` modules. To stay in line with `outcome`'s naming convention,
+/// instances of `err` have been replaced with `failure`. The report type `R`
+/// is inferred from the type the result is used as, such as the return type
+/// of the enclosing function.
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Aberration`]: crate::prelude::Aberration
+pub trait WrapFailure<R: ReportBackend>:
+  crate::private::Sealed + Sized
+{
+  /// The expected return type for an `impl`.
+  ///
+  /// This will always be the same enumeration type, but with `R` in the error
+  /// or failure position.
+  type Return;
+
+  /// Wrap the failure value with a new adhoc error that is evaluated lazily
+  /// only once an error does occur.
+  fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D;
+
+  /// Wrap the failure value with a new adhoc error.
+  fn wrap_failure<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static;
+
+  /// Compatibility re-export of [`wrap_failure_with`] for interop with
+  /// [`anyhow`] and [`eyre`].
+  ///
+  /// [`wrap_failure_with`]: WrapFailure::wrap_failure_with
+  /// [`anyhow`]: https://crates.io/crates/anyhow
+  /// [`eyre`]: https://crates.io/crates/eyre
+  #[track_caller]
+  #[inline]
+  fn with_context<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.wrap_failure_with(message)
+  }
+
+  /// Compatibility re-export of [`wrap_failure`] for interop with
+  /// [`anyhow`] and [`eyre`].
+  ///
+  /// [`wrap_failure`]: WrapFailure::wrap_failure
+  /// [`anyhow`]: https://crates.io/crates/anyhow
+  /// [`eyre`]: https://crates.io/crates/eyre
+  #[track_caller]
+  #[inline]
+  fn context<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.wrap_failure(message)
+  }
+}
+
+/// The [`Mistake`] analogue of [`WrapFailure`].
+///
+/// Wrapping a mistake allows it to carry context as it is propagated, before
+/// it is retried or escalated into a failure.
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+pub trait WrapMistake<R: ReportBackend>:
+  crate::private::Sealed + Sized
+{
+  /// The expected return type for an `impl`.
+  ///
+  /// This will always be the same enumeration type, but with `R` in the
+  /// mistake position.
+  type Return;
+
+  /// Wrap the mistake value with a new adhoc error that is evaluated lazily
+  /// only once a mistake does occur.
+  fn wrap_mistake_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D;
+
+  /// Wrap the mistake value with a new adhoc error.
+  fn wrap_mistake<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static;
+}
+
+/// Attaches a note to the failure value of an [`Outcome`], [`Aberration`], or
+/// [`Result`], converting it into a [`ReportBackend`].
+///
+/// See [`ReportBackend::attach_note`] for how each backend presents a note.
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Aberration`]: crate::prelude::Aberration
+pub trait Section<R: ReportBackend>: crate::private::Sealed + Sized {
+  /// The expected return type for an `impl`.
+  ///
+  /// This will always be the same enumeration type, but with `R` in the error
+  /// or failure position.
+  type Return;
+
+  /// Attach a note to the failure value.
+  fn attach_note<D>(self, note: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static;
+
+  /// Attach a note to the failure value, that is evaluated lazily only once
+  /// a failure does occur.
+  fn attach_note_with<D, F>(self, note: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D;
+}
+
+impl<S, M, E, R> WrapFailure<R> for Outcome<S, M, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Outcome<S, M, R>;
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_failure(|f| wrap_failure(f.into(), message()))
+  }
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_failure(|f| wrap_failure(f.into(), message))
+  }
+}
+
+impl<M, E, R> WrapFailure<R> for Aberration<M, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Aberration<M, R>;
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_failure(|f| wrap_failure(f.into(), message()))
+  }
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_failure(|f| wrap_failure(f.into(), message))
+  }
+}
+
+/// The failure hook and the active context are reserved for failures held by
+/// an [`Outcome`] or [`Aberration`]. An error held by a `Result` is only
+/// wrapped, in the same way as the `WrapErr` or `Context` traits would.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Aberration`]: crate::prelude::Aberration
+impl<T, E, R> WrapFailure<R> for Result<T, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Result<T, R>;
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_err(|e| e.into().wrap(message()))
+  }
+
+  #[track_caller]
+  #[inline]
+  fn wrap_failure<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_err(|e| e.into().wrap(message))
+  }
+}
+
+impl<S, E, G, R> WrapMistake<R> for Outcome<S, E, G>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Outcome<S, R, G>;
+
+  #[track_caller]
+  #[inline]
+  fn wrap_mistake_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_mistake(|m| m.into().wrap(message()))
+  }
+
+  #[track_caller]
+  #[inline]
+  fn wrap_mistake<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_mistake(|m| m.into().wrap(message))
+  }
+}
+
+impl<E, G, R> WrapMistake<R> for Aberration<E, G>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Aberration<R, G>;
+
+  #[track_caller]
+  #[inline]
+  fn wrap_mistake_with<D, F>(self, message: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_mistake(|m| m.into().wrap(message()))
+  }
+
+  #[track_caller]
+  #[inline]
+  fn wrap_mistake<D>(self, message: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_mistake(|m| m.into().wrap(message))
+  }
+}
+
+impl<S, M, E, R> Section<R> for Outcome<S, M, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Outcome<S, M, R>;
+
+  #[inline]
+  fn attach_note<D>(self, note: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_failure(|f| f.into().attach_note(note))
+  }
+
+  #[inline]
+  fn attach_note_with<D, F>(self, note: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_failure(|f| f.into().attach_note(note()))
+  }
+}
+
+impl<M, E, R> Section<R> for Aberration<M, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Aberration<M, R>;
+
+  #[inline]
+  fn attach_note<D>(self, note: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_failure(|f| f.into().attach_note(note))
+  }
+
+  #[inline]
+  fn attach_note_with<D, F>(self, note: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_failure(|f| f.into().attach_note(note()))
+  }
+}
+
+impl<T, E, R> Section<R> for Result<T, E>
+where
+  E: Into<R>,
+  R: ReportBackend,
+{
+  type Return = Result<T, R>;
+
+  #[inline]
+  fn attach_note<D>(self, note: D) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.map_err(|e| e.into().attach_note(note))
+  }
+
+  #[inline]
+  fn attach_note_with<D, F>(self, note: F) -> Self::Return
+  where
+    D: Display + Send + Sync + 'static,
+    F: FnOnce() -> D,
+  {
+    self.map_err(|e| e.into().attach_note(note()))
+  }
+}
+
+#[cfg(feature = "report")]
+impl ReportBackend for ::eyre::Report {
+  #[inline]
+  fn from_error<E>(error: E) -> Self
+  where
+    E: Error + Send + Sync + 'static,
+  {
+    Self::new(error)
+  }
+
+  #[inline]
+  fn wrap<D>(self, message: D) -> Self
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.wrap_err(message)
+  }
+}
+
+#[cfg(feature = "diagnostic")]
+impl ReportBackend for ::miette::Report {
+  #[inline]
+  fn from_error<E>(error: E) -> Self
+  where
+    E: Error + Send + Sync + 'static,
+  {
+    Self::from_err(error)
+  }
+
+  #[inline]
+  fn wrap<D>(self, message: D) -> Self
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.wrap_err(message)
+  }
+
+  #[inline]
+  fn attach_note<D>(self, note: D) -> Self
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    Self::new(Noted {
+      report: self,
+      note: note.to_string(),
+    })
+  }
+}
+
+/* miette has no notes of its own, so a note is attached as the help text of
+ * a diagnostic that is otherwise transparent over the report it holds. Any
+ * help text the report already has is kept above the note.
+ */
+#[cfg(feature = "diagnostic")]
+#[derive(Debug)]
+struct Noted {
+  report: ::miette::Report,
+  note: std::string::String,
+}
+
+#[cfg(feature = "diagnostic")]
+impl Display for Noted {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Display::fmt(&self.report, f)
+  }
+}
+
+#[cfg(feature = "diagnostic")]
+impl Error for Noted {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    self.report.source()
+  }
+}
+
+#[cfg(feature = "diagnostic")]
+impl ::miette::Diagnostic for Noted {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.report.code()
+  }
+
+  fn severity(&self) -> Option<::miette::Severity> {
+    self.report.severity()
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    Some(match self.report.help() {
+      Some(help) => Box::new(std::format!("{help}\n{}", self.note)),
+      None => Box::new(&self.note),
+    })
+  }
+
+  fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    self.report.url()
+  }
+
+  fn source_code(&self) -> Option<&dyn ::miette::SourceCode> {
+    self.report.source_code()
+  }
+
+  fn labels(
+    &self,
+  ) -> Option<Box<dyn Iterator<Item = ::miette::LabeledSpan> + '_>> {
+    self.report.labels()
+  }
+
+  fn related<'a>(
+    &'a self,
+  ) -> Option<Box<dyn Iterator<Item = &'a dyn ::miette::Diagnostic> + 'a>> {
+    self.report.related()
+  }
+
+  fn diagnostic_source(&self) -> Option<&dyn ::miette::Diagnostic> {
+    self.report.diagnostic_source()
+  }
+}
+
+#[cfg(feature = "anyhow")]
+impl ReportBackend for ::anyhow::Error {
+  #[inline]
+  fn from_error<E>(error: E) -> Self
+  where
+    E: Error + Send + Sync + 'static,
+  {
+    Self::new(error)
+  }
+
+  #[inline]
+  fn wrap<D>(self, message: D) -> Self
+  where
+    D: Display + Send + Sync + 'static,
+  {
+    self.context(message)
+  }
+}
+
+#[cfg(all(test, any(feature = "report", feature = "diagnostic")))]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[cfg(feature = "diagnostic")]
+  #[test]
+  fn notes_are_help_text() {
+    use ::miette::{MietteDiagnostic, Report};
+    let diagnostic =
+      MietteDiagnostic::new("link is down").with_help("check the cable");
+    let x: Outcome<(), (), Report> = Failure(Report::new(diagnostic));
+    let x: Outcome<(), (), Report> = x.attach_note("port 3");
+    let report = x.unwrap_failure();
+    assert_eq!(report.to_string(), "link is down");
+    let help = report.help().unwrap().to_string();
+    assert_eq!(help, "check the cable\nport 3");
+  }
+
+  #[cfg(feature = "report")]
+  #[test]
+  fn notes_are_context_by_default() {
+    let x: Result<(), std::io::Error> =
+      Err(std::io::Error::other("link is down"));
+    let x: Result<(), ::eyre::Report> = x.attach_note("port 3");
+    assert_eq!(x.unwrap_err().to_string(), "port 3");
+  }
+}
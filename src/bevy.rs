@@ -0,0 +1,104 @@
+//! Integration with the [Bevy] game engine.
+//!
+//! Systems returning an `Outcome<(), M, F>` can be [piped] into [`handle`],
+//! which removes the need to match on the outcome in every system. A
+//! [`Mistake`] is logged as a warning. As systems in the `Update` schedule run
+//! every frame, the system is naturally retried on the next frame. A
+//! [`Failure`] is logged as an error and, depending on the [`FailureAction`]
+//! inserted by the [`OutcomePlugin`], may also request that the app exit.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use bevy_app::{App, AppExit, Update};
+//! use bevy_ecs::prelude::*;
+//! use outcome::bevy::{handle, FailureAction, OutcomePlugin};
+//!
+//! fn load_level() -> Outcome<(), &'static str, &'static str> {
+//!   Failure("level data is corrupt")
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(OutcomePlugin::new(FailureAction::Exit));
+//! app.add_systems(Update, load_level.pipe(handle));
+//! app.update();
+//! assert_eq!(app.should_exit(), Some(AppExit::error()));
+//! ```
+//!
+//! [Bevy]: https://bevyengine.org
+//! [piped]: bevy_ecs::system::IntoSystem::pipe
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use core::fmt::Debug;
+
+use bevy_app::{App, AppExit, Plugin};
+use bevy_ecs::{
+  message::MessageWriter,
+  resource::Resource,
+  system::{In, Res},
+};
+
+use crate::prelude::*;
+
+/// Determines what [`handle`] does when a system returns a [`Failure`].
+///
+/// [`Failure`]: crate::prelude::Failure
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub enum FailureAction {
+  /// The failure is logged as an error, and the app continues to run.
+  #[default]
+  Log,
+  /// The failure is logged as an error, and [`AppExit::error`] is written.
+  Exit,
+}
+
+/// A [`Plugin`] configuring how [`handle`] treats a [`Failure`].
+///
+/// Without this plugin, [`handle`] behaves as if [`FailureAction::Log`] was
+/// configured.
+///
+/// [`Failure`]: crate::prelude::Failure
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct OutcomePlugin {
+  on_failure: FailureAction,
+}
+
+impl OutcomePlugin {
+  /// Creates a new plugin with the given [`FailureAction`].
+  #[must_use]
+  #[inline]
+  pub const fn new(on_failure: FailureAction) -> Self {
+    Self { on_failure }
+  }
+}
+
+impl Plugin for OutcomePlugin {
+  fn build(&self, app: &mut App) {
+    app.insert_resource(self.on_failure);
+  }
+}
+
+/// Handles the [`Outcome`] returned by a system.
+///
+/// This is meant to be used with [`pipe`]. See the [module
+/// documentation](crate::bevy) for details.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`pipe`]: bevy_ecs::system::IntoSystem::pipe
+pub fn handle<M: Debug, F: Debug>(
+  In(outcome): In<Outcome<(), M, F>>,
+  action: Option<Res<'_, FailureAction>>,
+  mut exit: MessageWriter<'_, AppExit>,
+) {
+  match outcome {
+    Success(()) => {}
+    Mistake(m) => bevy_log::warn!("system returned a mistake: {m:?}"),
+    Failure(f) => {
+      bevy_log::error!("system returned a failure: {f:?}");
+      if action.is_some_and(|action| *action == FailureAction::Exit) {
+        exit.write(AppExit::error());
+      }
+    }
+  }
+}
@@ -0,0 +1,223 @@
+//! Time windowed error budgets.
+//!
+//! An [`ErrorBudget`] counts the number of [`Mistake`]s and [`Failure`]s that
+//! occurred within a sliding window of time. While the budget has not been
+//! exhausted, a [`Mistake`] is passed through as is, as retrying it is still
+//! worthwhile. Once too many errors have occurred, [`ErrorBudget::apply`]
+//! escalates every further [`Mistake`] into a [`Failure`], so that callers stop
+//! retrying and apply backpressure until the window moves past the burst of
+//! errors.
+//!
+//! An [`ErrorBudget`] is cheap to clone, and every clone shares the same
+//! counters. Recording an error only requires atomic operations.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::budget::ErrorBudget;
+//! use std::time::Duration;
+//!
+//! let budget = ErrorBudget::new(2, Duration::from_secs(60));
+//! let results: Vec<Outcome<u32, &str, &str>> = [
+//!   Success(1),
+//!   Mistake("busy"),
+//!   Mistake("busy"),
+//!   Mistake("busy"),
+//! ]
+//! .into_iter()
+//! .map(|outcome| budget.apply(outcome))
+//! .collect();
+//!
+//! assert_eq!(
+//!   results,
+//!   [Success(1), Mistake("busy"), Mistake("busy"), Failure("busy")]
+//! );
+//! assert!(budget.is_exhausted());
+//! ```
+//!
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use core::{
+  sync::atomic::{AtomicU32, AtomicU64, Ordering},
+  time::Duration,
+};
+use std::{sync::Arc, time::Instant};
+
+use crate::prelude::*;
+
+/* The window is divided into a fixed number of buckets, each of which
+ * remembers the epoch (the number of bucket widths since the budget was
+ * created) it was last written in. A bucket whose epoch has fallen out of the
+ * window is reset the next time it is written, and ignored when read. This
+ * trades a small amount of precision at the edge of the window for a lock
+ * free implementation.
+ */
+const BUCKETS: usize = 16;
+
+#[derive(Debug, Default)]
+struct Bucket {
+  epoch: AtomicU64,
+  count: AtomicU32,
+}
+
+#[derive(Debug)]
+struct Inner {
+  limit: u32,
+  width: Duration,
+  start: Instant,
+  buckets: [Bucket; BUCKETS],
+}
+
+/// A shareable tracker of errors within a sliding window of time.
+///
+/// See the [module documentation](crate::budget) for details.
+#[derive(Clone, Debug)]
+pub struct ErrorBudget {
+  inner: Arc<Inner>,
+}
+
+impl ErrorBudget {
+  /// Creates a budget that is exhausted once `limit` errors have been recorded
+  /// within `window`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `window` is zero.
+  #[must_use]
+  pub fn new(limit: u32, window: Duration) -> Self {
+    assert!(!window.is_zero(), "error budget window must not be zero");
+    #[allow(clippy::cast_possible_truncation)]
+    let width = window / BUCKETS as u32;
+    Self {
+      inner: Arc::new(Inner {
+        limit,
+        width: width.max(Duration::from_nanos(1)),
+        start: Instant::now(),
+        buckets: Default::default(),
+      }),
+    }
+  }
+
+  /// Returns the number of errors permitted within the window.
+  #[must_use]
+  #[inline]
+  pub fn limit(&self) -> u32 {
+    self.inner.limit
+  }
+
+  /* Epochs start at 1, so that a freshly created bucket (epoch 0) is never
+   * considered part of the window.
+   */
+  fn epoch(&self) -> u64 {
+    let elapsed = self.inner.start.elapsed().as_nanos();
+    let width = self.inner.width.as_nanos();
+    u64::try_from(elapsed / width).unwrap_or(u64::MAX - 1) + 1
+  }
+
+  /// Returns the number of errors recorded within the window.
+  #[must_use]
+  pub fn spent(&self) -> u32 {
+    let epoch = self.epoch();
+    let oldest = epoch.saturating_sub(BUCKETS as u64 - 1);
+    self
+      .inner
+      .buckets
+      .iter()
+      .filter(|bucket| bucket.epoch.load(Ordering::Acquire) >= oldest)
+      .map(|bucket| bucket.count.load(Ordering::Relaxed))
+      .fold(0, u32::saturating_add)
+  }
+
+  /// Returns the number of errors that may still occur before the budget is
+  /// exhausted.
+  #[must_use]
+  #[inline]
+  pub fn remaining(&self) -> u32 {
+    self.limit().saturating_sub(self.spent())
+  }
+
+  /// Returns `true` if no errors may occur without exceeding the budget.
+  #[must_use]
+  #[inline]
+  pub fn is_exhausted(&self) -> bool {
+    self.remaining() == 0
+  }
+
+  /// Records a single error.
+  pub fn spend(&self) {
+    let epoch = self.epoch();
+    #[allow(clippy::cast_possible_truncation)]
+    let bucket = &self.inner.buckets[(epoch % BUCKETS as u64) as usize];
+    let previous = bucket.epoch.load(Ordering::Acquire);
+    if previous != epoch
+      && bucket
+        .epoch
+        .compare_exchange(previous, epoch, Ordering::AcqRel, Ordering::Acquire)
+        .is_ok()
+    {
+      bucket.count.store(1, Ordering::Relaxed);
+      return;
+    }
+    bucket.count.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Records `outcome` if it is a [`Mistake`] or [`Failure`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn record<S, M, F>(&self, outcome: &Outcome<S, M, F>) {
+    if outcome.is_error() {
+      self.spend();
+    }
+  }
+
+  /// Records `outcome`, escalating a [`Mistake`] into a [`Failure`] if the
+  /// budget was already exhausted.
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub fn apply<S, M, F>(&self, outcome: Outcome<S, M, F>) -> Outcome<S, M, F>
+  where
+    M: Into<F>,
+  {
+    let exhausted = outcome.is_mistake() && self.is_exhausted();
+    self.record(&outcome);
+    match outcome {
+      Mistake(m) if exhausted => Failure(m.into()),
+      outcome => outcome,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn shared_between_clones() {
+    let budget = ErrorBudget::new(3, Duration::from_secs(60));
+    let clone = budget.clone();
+    budget.spend();
+    clone.record(&Outcome::<(), (), ()>::Failure(()));
+    clone.record(&Outcome::<(), (), ()>::Success(()));
+    assert_eq!(budget.spent(), 2);
+    assert_eq!(clone.remaining(), 1);
+  }
+
+  #[test]
+  fn window_expires() {
+    let budget = ErrorBudget::new(1, Duration::from_millis(16));
+    budget.spend();
+    assert!(budget.is_exhausted());
+    std::thread::sleep(Duration::from_millis(40));
+    assert!(!budget.is_exhausted());
+    assert_eq!(
+      budget.apply(Outcome::<(), (), ()>::Mistake(())),
+      Mistake(())
+    );
+  }
+}
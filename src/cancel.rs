@@ -0,0 +1,124 @@
+//! Support for cancellation with [`tokio`].
+//!
+//! Cancelling an operation, such as during a graceful shutdown, is not an
+//! error in the same way a crashed task is. This module surfaces cancellation
+//! through the [`Mistake`] channel, so that it can be distinguished from a
+//! [`Failure`] by downstream handling:
+//!
+//!  - [`until_cancelled`] runs a future until it completes or a
+//!    [`CancellationToken`] is cancelled, producing a [`Cancelled`] mistake in
+//!    the latter case.
+//!  - [`Classify`] is implemented for [`JoinError`], treating a cancelled
+//!    (aborted) task as a [`Mistake`] and a panicked task as a [`Failure`].
+//!    [`join`] awaits a [`JoinHandle`] using this classification.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::cancel::{until_cancelled, Cancelled};
+//! use tokio_util::sync::CancellationToken;
+//!
+//! async fn work() -> Outcome<u32, Cancelled, &'static str> {
+//!   std::future::pending().await
+//! }
+//!
+//! let token = CancellationToken::new();
+//! token.cancel();
+//! let outcome = futures::executor::block_on(until_cancelled(&token, work()));
+//! assert_eq!(outcome, Mistake(Cancelled));
+//! ```
+//!
+//! [`tokio`]: https://tokio.rs
+//! [`CancellationToken`]: tokio_util::sync::CancellationToken
+//! [`JoinError`]: tokio::task::JoinError
+//! [`JoinHandle`]: tokio::task::JoinHandle
+//! [`Classify`]: crate::ext::Classify
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use core::{
+  fmt::{self, Display, Formatter},
+  future::Future,
+};
+
+use ::tokio::task::{JoinError, JoinHandle};
+use ::tokio_util::sync::CancellationToken;
+
+use crate::{
+  ext::{Classify, ResultExt},
+  prelude::*,
+};
+
+/// The error produced when an operation is cancelled.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("operation was cancelled")
+  }
+}
+
+impl std::error::Error for Cancelled {}
+
+impl Classify for Cancelled {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    true
+  }
+}
+
+impl Classify for JoinError {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    self.is_cancelled()
+  }
+}
+
+/// Runs `future` until it completes, or until `token` is cancelled.
+///
+/// If `token` is cancelled first, `future` is dropped and a [`Cancelled`]
+/// value is returned as a [`Mistake`].
+///
+/// [`Mistake`]: crate::prelude::Mistake
+pub async fn until_cancelled<Fut, S, M, F>(
+  token: &CancellationToken,
+  future: Fut,
+) -> Outcome<S, M, F>
+where
+  Fut: Future<Output = Outcome<S, M, F>>,
+  M: From<Cancelled>,
+{
+  match token.run_until_cancelled(future).await {
+    Some(outcome) => outcome,
+    None => Mistake(Cancelled.into()),
+  }
+}
+
+/// Awaits the task behind `handle`.
+///
+/// If the task was cancelled, the [`JoinError`] is returned as a [`Mistake`].
+/// If the task panicked, it is returned as a [`Failure`].
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+/// let outcome = runtime.block_on(async {
+///   let handle = tokio::spawn(std::future::pending::<()>());
+///   handle.abort();
+///   outcome::cancel::join(handle).await
+/// });
+/// assert!(outcome.unwrap_mistake().is_cancelled());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [`JoinError`]: tokio::task::JoinError
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+pub async fn join<T>(
+  handle: JoinHandle<T>,
+) -> Outcome<T, JoinError, JoinError> {
+  handle.await.into_outcome()
+}
@@ -0,0 +1,322 @@
+//! Lazily initialized cells driven by an [`Outcome`].
+//!
+//! The types in this module are the lazy initialization analogue of the
+//! [`Outcome`] itself. An initializer returning a [`Success`] stores its value
+//! permanently. A [`Mistake`] is handed back to the caller and *nothing* is
+//! stored, so the next call will attempt the initialization again. A
+//! [`Failure`] is stored permanently and *poisons* the cell, so that every
+//! subsequent call observes the same failure.
+//!
+//! Two flavors are provided:
+//!
+//!  - [`OnceOutcome`], which is not thread-safe and is available in `no_std`.
+//!  - [`OnceOutcomeLock`], which is thread-safe and requires the `std`
+//!    feature.
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::{cell::OnceCell, fmt::Debug};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::prelude::*;
+
+/// A cell that is initialized at most once by an initializer returning an
+/// [`Outcome`].
+///
+/// See the [module documentation](crate::cell) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::cell::OnceOutcome;
+///
+/// let cell: OnceOutcome<u32, &str> = OnceOutcome::new();
+///
+/// let mut attempts = 0;
+/// let mut connect = || {
+///   attempts += 1;
+///   match attempts {
+///     1 => Mistake("server is busy"),
+///     _ => Success(47),
+///   }
+/// };
+///
+/// assert_eq!(cell.get_or_attempt(&mut connect), Mistake::<&u32, _, &&str>("server is busy"));
+/// assert_eq!(cell.get_or_attempt(&mut connect), Success(&47));
+/// assert_eq!(cell.get_or_attempt(&mut connect), Success(&47));
+/// assert_eq!(attempts, 2);
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+pub struct OnceOutcome<S, F> {
+  inner: OnceCell<Result<S, F>>,
+}
+
+/// A thread-safe cell that is initialized at most once by an initializer
+/// returning an [`Outcome`].
+///
+/// Only one thread runs the initializer at a time. Threads that call
+/// [`get_or_attempt`] while an initialization is in progress will block
+/// until it has completed, and then either observe the stored value or
+/// attempt the initialization themselves if the previous attempt was a
+/// [`Mistake`].
+///
+/// See the [module documentation](crate::cell) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::cell::OnceOutcomeLock;
+///
+/// static CONFIG: OnceOutcomeLock<String, &str> = OnceOutcomeLock::new();
+///
+/// std::thread::spawn(|| {
+///   let config = CONFIG.get_or_attempt(|| Success::<_, (), _>("loaded".into()));
+///   assert_eq!(config, Success(&"loaded".to_string()));
+/// }).join().unwrap();
+///
+/// assert_eq!(CONFIG.get().map(String::as_str), Some("loaded"));
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`get_or_attempt`]: OnceOutcomeLock::get_or_attempt
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub struct OnceOutcomeLock<S, F> {
+  inner: OnceLock<Result<S, F>>,
+  init: Mutex<()>,
+}
+
+impl<S, F> OnceOutcome<S, F> {
+  /// Creates a new, uninitialized cell.
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      inner: OnceCell::new(),
+    }
+  }
+
+  /// Returns a reference to the stored [`Success`] value, if any.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[inline]
+  pub fn get(&self) -> Option<&S> {
+    self.inner.get()?.as_ref().ok()
+  }
+
+  /// Returns a reference to the [`Failure`] value that poisoned this cell, if
+  /// any.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn failure(&self) -> Option<&F> {
+    self.inner.get()?.as_ref().err()
+  }
+
+  /// Returns `true` if a previous initialization resulted in a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn is_poisoned(&self) -> bool {
+    self.failure().is_some()
+  }
+
+  /// Returns the stored value, or attempts to initialize the cell with
+  /// `initializer`.
+  ///
+  /// If the cell already holds a [`Success`] or [`Failure`], `initializer`
+  /// is not called. Otherwise, a [`Success`] or [`Failure`] returned by
+  /// `initializer` is stored, while a [`Mistake`] is returned to the caller
+  /// and leaves the cell uninitialized.
+  ///
+  /// If `initializer` reentrantly initializes the cell, the value stored by
+  /// the reentrant call is kept.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub fn get_or_attempt<M, C>(&self, initializer: C) -> Outcome<&S, M, &F>
+  where
+    C: FnOnce() -> Outcome<S, M, F>,
+  {
+    if let Some(value) = self.inner.get() {
+      return stored(value);
+    }
+    let value = match initializer() {
+      Success(s) => Ok(s),
+      Mistake(m) => return Mistake(m),
+      Failure(f) => Err(f),
+    };
+    stored(self.inner.get_or_init(|| value))
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S, F> OnceOutcomeLock<S, F> {
+  /// Creates a new, uninitialized cell.
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      inner: OnceLock::new(),
+      init: Mutex::new(()),
+    }
+  }
+
+  /// Returns a reference to the stored [`Success`] value, if any.
+  ///
+  /// This does not block.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[inline]
+  pub fn get(&self) -> Option<&S> {
+    self.inner.get()?.as_ref().ok()
+  }
+
+  /// Returns a reference to the [`Failure`] value that poisoned this cell, if
+  /// any.
+  ///
+  /// This does not block.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn failure(&self) -> Option<&F> {
+    self.inner.get()?.as_ref().err()
+  }
+
+  /// Returns `true` if a previous initialization resulted in a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn is_poisoned(&self) -> bool {
+    self.failure().is_some()
+  }
+
+  /// Returns the stored value, or attempts to initialize the cell with
+  /// `initializer`.
+  ///
+  /// If the cell already holds a [`Success`] or [`Failure`], `initializer`
+  /// is not called. Otherwise, a [`Success`] or [`Failure`] returned by
+  /// `initializer` is stored, while a [`Mistake`] is returned to the caller
+  /// and leaves the cell uninitialized.
+  ///
+  /// Calling `get_or_attempt` on the same cell from within `initializer`
+  /// will deadlock.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub fn get_or_attempt<M, C>(&self, initializer: C) -> Outcome<&S, M, &F>
+  where
+    C: FnOnce() -> Outcome<S, M, F>,
+  {
+    if let Some(value) = self.inner.get() {
+      return stored(value);
+    }
+    let _guard = self.init.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(value) = self.inner.get() {
+      return stored(value);
+    }
+    let value = match initializer() {
+      Success(s) => Ok(s),
+      Mistake(m) => return Mistake(m),
+      Failure(f) => Err(f),
+    };
+    stored(self.inner.get_or_init(|| value))
+  }
+}
+
+#[inline]
+fn stored<S, M, F>(value: &Result<S, F>) -> Outcome<&S, M, &F> {
+  match value {
+    Ok(s) => Success(s),
+    Err(f) => Failure(f),
+  }
+}
+
+impl<S, F> Default for OnceOutcome<S, F> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S, F> Default for OnceOutcomeLock<S, F> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<S: Debug, F: Debug> Debug for OnceOutcome<S, F> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_tuple("OnceOutcome")
+      .field(&self.inner.get())
+      .finish()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S: Debug, F: Debug> Debug for OnceOutcomeLock<S, F> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_tuple("OnceOutcomeLock")
+      .field(&self.inner.get())
+      .finish()
+  }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+  extern crate std;
+  use super::*;
+  use std::{sync::Arc, thread, vec::Vec};
+
+  #[test]
+  fn failure_poisons() {
+    let cell: OnceOutcome<u32, &str> = OnceOutcome::new();
+    let outcome = cell.get_or_attempt(|| Failure::<_, (), _>("broken"));
+    assert_eq!(outcome, Failure(&"broken"));
+    assert!(cell.is_poisoned());
+    let outcome = cell.get_or_attempt(|| Success::<_, (), _>(47));
+    assert_eq!(outcome, Failure(&"broken"));
+    assert_eq!(cell.get(), None);
+  }
+
+  #[test]
+  fn retry_across_threads() {
+    let cell: Arc<OnceOutcomeLock<u32, ()>> = Arc::default();
+    let handles: Vec<_> = (0..8)
+      .map(|n| {
+        let cell = Arc::clone(&cell);
+        thread::spawn(move || {
+          cell
+            .get_or_attempt(|| if n % 2 == 0 { Mistake(n) } else { Success(n) })
+            .map(|s| *s)
+            .map_failure(|f| *f)
+        })
+      })
+      .collect();
+    let outcomes: Vec<_> =
+      handles.into_iter().map(|h| h.join().unwrap()).collect();
+    let stored = *cell.get().unwrap();
+    assert_eq!(stored % 2, 1);
+    assert!(outcomes.iter().all(|o| match o {
+      Success(s) => *s == stored,
+      Mistake(m) => m % 2 == 0,
+      Failure(()) => false,
+    }));
+  }
+}
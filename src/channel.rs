@@ -0,0 +1,153 @@
+//! Retryable sends on bounded channels.
+//!
+//! Sending a value on a bounded channel that is full does not lose the value:
+//! it is handed back to the caller, who may try to send it again later. A
+//! channel whose receiver has been dropped hands the value back as well, but
+//! no amount of retrying will deliver it. This module maps these two errors
+//! onto the [`Mistake`] and [`Failure`] channels:
+//!
+//!  - [`Full`] is a [`Mistake`], carrying the unsent value back for a retry.
+//!  - [`Disconnected`] is a [`Failure`], carrying the unsent value back so it
+//!    is not silently dropped.
+//!
+//! Both [`std::sync::mpsc::TrySendError`] and, when the `crossbeam` feature
+//! is enabled, [`crossbeam_channel::TrySendError`] convert into an
+//! [`Aberration<Full<T>, Disconnected<T>>`][`Aberration`], and the result of
+//! a `try_send` converts into an [`Outcome`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::channel::{Disconnected, Full};
+//! use std::sync::mpsc::sync_channel;
+//!
+//! let (tx, rx) = sync_channel(1);
+//! assert_eq!(Outcome::from(tx.try_send(1)), Success(()));
+//! assert_eq!(Outcome::from(tx.try_send(2)), Mistake(Full(2)));
+//!
+//! drop(rx);
+//! assert_eq!(Outcome::from(tx.try_send(3)), Failure(Disconnected(3)));
+//! ```
+//!
+//! [`crossbeam_channel::TrySendError`]: https://docs.rs/crossbeam-channel
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use std::sync::mpsc::TrySendError;
+
+use crate::prelude::*;
+
+/// The [`Mistake`] produced when sending on a full channel, containing the
+/// value that was not sent.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Full<T>(pub T);
+
+/// The [`Failure`] produced when sending on a channel whose receiver has been
+/// dropped, containing the value that was not sent.
+///
+/// [`Failure`]: crate::prelude::Failure
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Disconnected<T>(pub T);
+
+impl<T> Full<T> {
+  /// Consumes the error, returning the value that was not sent.
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> Disconnected<T> {
+  /// Consumes the error, returning the value that was not sent.
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> Display for Full<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("sending on a full channel")
+  }
+}
+
+impl<T> Display for Disconnected<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str("sending on a disconnected channel")
+  }
+}
+
+impl<T: Debug> std::error::Error for Full<T> {}
+impl<T: Debug> std::error::Error for Disconnected<T> {}
+
+impl<T> From<TrySendError<T>> for Aberration<Full<T>, Disconnected<T>> {
+  #[inline]
+  fn from(error: TrySendError<T>) -> Self {
+    match error {
+      TrySendError::Full(value) => Self::Mistake(Full(value)),
+      TrySendError::Disconnected(value) => Self::Failure(Disconnected(value)),
+    }
+  }
+}
+
+impl<T> From<Result<(), TrySendError<T>>>
+  for Outcome<(), Full<T>, Disconnected<T>>
+{
+  #[inline]
+  fn from(result: Result<(), TrySendError<T>>) -> Self {
+    match result {
+      Ok(()) => Success(()),
+      Err(TrySendError::Full(value)) => Mistake(Full(value)),
+      Err(TrySendError::Disconnected(value)) => Failure(Disconnected(value)),
+    }
+  }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<T> From<::crossbeam_channel::TrySendError<T>>
+  for Aberration<Full<T>, Disconnected<T>>
+{
+  #[inline]
+  fn from(error: ::crossbeam_channel::TrySendError<T>) -> Self {
+    use ::crossbeam_channel::TrySendError;
+    match error {
+      TrySendError::Full(value) => Self::Mistake(Full(value)),
+      TrySendError::Disconnected(value) => Self::Failure(Disconnected(value)),
+    }
+  }
+}
+
+/// Converts the result of [`crossbeam_channel::Sender::try_send`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::channel::Full;
+///
+/// let (tx, _rx) = crossbeam_channel::bounded(0);
+/// assert_eq!(Outcome::from(tx.try_send("hello")), Mistake(Full("hello")));
+/// ```
+///
+/// [`crossbeam_channel::Sender::try_send`]: https://docs.rs/crossbeam-channel
+#[cfg(feature = "crossbeam")]
+impl<T> From<Result<(), ::crossbeam_channel::TrySendError<T>>>
+  for Outcome<(), Full<T>, Disconnected<T>>
+{
+  #[inline]
+  fn from(result: Result<(), ::crossbeam_channel::TrySendError<T>>) -> Self {
+    use ::crossbeam_channel::TrySendError;
+    match result {
+      Ok(()) => Success(()),
+      Err(TrySendError::Full(value)) => Mistake(Full(value)),
+      Err(TrySendError::Disconnected(value)) => Failure(Disconnected(value)),
+    }
+  }
+}
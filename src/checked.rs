@@ -0,0 +1,212 @@
+use core::{cell::Cell, fmt, panic::Location};
+
+use crate::prelude::*;
+
+/// A guard ensuring that an [`Outcome`] in an error state is not forgotten.
+///
+/// `#[must_use]` only warns when an [`Outcome`] is discarded immediately. An
+/// outcome stored in a struct, a collection, or a channel can still be
+/// dropped without anybody looking at it. A `Checked` outcome records where
+/// it was created, and if it is dropped while holding a [`Mistake`] or
+/// [`Failure`] that was never inspected, it reports the location. In debug
+/// builds this is a panic, while in release builds a message is printed to
+/// standard error.
+///
+/// Calling any of the `is_*` predicates, [`as_ref`], or [`as_mut`] counts as
+/// an inspection. Calling [`into_inner`] or [`discard`] consumes the guard
+/// without reporting anything.
+///
+/// This type is created by [`Outcome::must_handle`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let x: Outcome<(), &str, ()> = Mistake("busy");
+/// let checked = x.must_handle();
+/// if checked.is_mistake() {
+///   // handled, dropping `checked` is now fine
+/// }
+/// ```
+///
+/// ```should_panic
+/// # use outcome::prelude::*;
+/// use outcome::Checked;
+///
+/// struct Job {
+///   result: Checked<(), (), &'static str>,
+/// }
+///
+/// let job = Job { result: Failure("disk full").must_handle() };
+/// drop(job); // panics in debug builds, as the failure was never inspected
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`as_ref`]: Checked::as_ref
+/// [`as_mut`]: Checked::as_mut
+/// [`into_inner`]: Checked::into_inner
+/// [`discard`]: Checked::discard
+#[must_use = "This `Checked` will report an unhandled error when dropped"]
+pub struct Checked<S, M, F> {
+  outcome: Option<Outcome<S, M, F>>,
+  location: &'static Location<'static>,
+  inspected: Cell<bool>,
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Wraps this outcome in a [`Checked`] guard, recording the caller's
+  /// location.
+  ///
+  /// See [`Checked`] for details.
+  #[track_caller]
+  #[inline]
+  pub fn must_handle(self) -> Checked<S, M, F> {
+    Checked {
+      outcome: Some(self),
+      location: Location::caller(),
+      inspected: Cell::new(false),
+    }
+  }
+}
+
+impl<S, M, F> Checked<S, M, F> {
+  #[inline]
+  fn get(&self) -> &Outcome<S, M, F> {
+    self.inspected.set(true);
+    match &self.outcome {
+      Some(outcome) => outcome,
+      None => unreachable!(),
+    }
+  }
+
+  /// Returns the location at which this guard was created.
+  #[must_use]
+  #[inline]
+  pub fn location(&self) -> &'static Location<'static> {
+    self.location
+  }
+
+  /// Returns `true` if the outcome is a [`Success`].
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[must_use]
+  #[inline]
+  pub fn is_success(&self) -> bool {
+    self.get().is_success()
+  }
+
+  /// Returns `true` if the outcome is a [`Mistake`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub fn is_mistake(&self) -> bool {
+    self.get().is_mistake()
+  }
+
+  /// Returns `true` if the outcome is a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn is_failure(&self) -> bool {
+    self.get().is_failure()
+  }
+
+  /// Returns `true` if the outcome is a [`Mistake`] or [`Failure`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn is_error(&self) -> bool {
+    self.get().is_error()
+  }
+
+  /// Converts from `&Checked<S, M, F>` to `Outcome<&S, &M, &F>`.
+  #[inline]
+  pub fn as_ref(&self) -> Outcome<&S, &M, &F> {
+    self.get().as_ref()
+  }
+
+  /// Converts from `&mut Checked<S, M, F>` to `Outcome<&mut S, &mut M, &mut F>`.
+  #[inline]
+  pub fn as_mut(&mut self) -> Outcome<&mut S, &mut M, &mut F> {
+    self.inspected.set(true);
+    match &mut self.outcome {
+      Some(outcome) => outcome.as_mut(),
+      None => unreachable!(),
+    }
+  }
+
+  /// Consumes the guard, returning the outcome.
+  #[inline]
+  pub fn into_inner(mut self) -> Outcome<S, M, F> {
+    match self.outcome.take() {
+      Some(outcome) => outcome,
+      None => unreachable!(),
+    }
+  }
+
+  /// Consumes the guard, explicitly ignoring the outcome.
+  #[inline]
+  pub fn discard(self) {
+    drop(self.into_inner());
+  }
+}
+
+impl<S, M, F> Drop for Checked<S, M, F> {
+  fn drop(&mut self) {
+    let variant = match &self.outcome {
+      Some(_) if self.inspected.get() => return,
+      Some(Mistake(_)) => "Mistake",
+      Some(Failure(_)) => "Failure",
+      Some(Success(_)) | None => return,
+    };
+    crate::private::unhandled(variant, self.location);
+  }
+}
+
+impl<S, M, F> From<Checked<S, M, F>> for Outcome<S, M, F> {
+  #[inline]
+  fn from(checked: Checked<S, M, F>) -> Self {
+    checked.into_inner()
+  }
+}
+
+impl<S: fmt::Debug, M: fmt::Debug, F: fmt::Debug> fmt::Debug
+  for Checked<S, M, F>
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Checked")
+      .field("outcome", &self.outcome)
+      .field("location", &self.location)
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn success_is_never_reported() {
+    drop(Success::<(), (), ()>(()).must_handle());
+  }
+
+  #[test]
+  fn into_inner_defuses() {
+    let checked = Failure::<(), (), _>(1).must_handle();
+    assert_eq!(checked.location().line(), line!() - 1);
+    assert_eq!(Outcome::from(checked), Failure(1));
+  }
+
+  #[cfg(debug_assertions)]
+  #[test]
+  #[should_panic(expected = "unhandled `Mistake`")]
+  fn unhandled_mistake() {
+    drop(Mistake::<(), _, ()>(1).must_handle());
+  }
+}
@@ -0,0 +1,321 @@
+//! Runtime configurable error classification.
+//!
+//! The [`Classify`] trait decides at compile time whether an error is a
+//! [`Mistake`] or a [`Failure`]. Sometimes this decision depends on the
+//! deployment instead: a flaky backend might return a `500` that is worth
+//! retrying, even though a `500` is usually not. A [`ClassificationTable`]
+//! holds a list of [`Rule`]s that override [`Classify`], and can be passed to
+//! [`ResultExt::into_outcome_with`].
+//!
+//! Each rule may match an error by its [`kind`], its [`code`], and a pattern
+//! found in its [`Display`] output. A rule matches only when every criterion
+//! it specifies matches, and the first matching rule wins. When no rule
+//! matches, the error's own [`Classify`] implementation is used.
+//!
+//! When the `serde` feature is enabled, a [`ClassificationTable`] can be
+//! deserialized, so that it can be loaded from a configuration file.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::classify::{Classification, ClassificationTable, Rule};
+//! use outcome::ext::{Classify, ResultExt};
+//! use std::fmt;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Status(u16);
+//!
+//! impl fmt::Display for Status {
+//!   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!     write!(f, "status {}", self.0)
+//!   }
+//! }
+//!
+//! impl Classify for Status {
+//!   fn is_mistake(&self) -> bool {
+//!     self.0 == 503
+//!   }
+//!
+//!   fn code(&self) -> Option<i64> {
+//!     Some(self.0.into())
+//!   }
+//! }
+//!
+//! let table = ClassificationTable::new()
+//!   .with(Rule::new(Classification::Mistake).code(500));
+//!
+//! let x: Result<(), Status> = Err(Status(500));
+//! assert_eq!(x.into_outcome_with(&table), Mistake(Status(500)));
+//!
+//! let x: Result<(), Status> = Err(Status(404));
+//! assert_eq!(x.into_outcome_with(&table), Failure(Status(404)));
+//! ```
+//!
+//! With the `serde` feature, the same table can be loaded from a configuration
+//! file:
+//!
+#![cfg_attr(feature = "serde", doc = "```")]
+#![cfg_attr(not(feature = "serde"), doc = "```ignore")]
+//! use outcome::classify::{Classification, ClassificationTable, Rule};
+//!
+//! let table: ClassificationTable = serde_json::from_str(
+//!   r#"[{ "code": 500, "class": "mistake" }, { "kind": "X", "class": "failure" }]"#,
+//! )?;
+//! assert_eq!(
+//!   table.rules(),
+//!   [
+//!     Rule::new(Classification::Mistake).code(500),
+//!     Rule::new(Classification::Failure).kind("X"),
+//!   ]
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Classify`]: crate::ext::Classify
+//! [`ResultExt::into_outcome_with`]: crate::ext::ResultExt::into_outcome_with
+//! [`kind`]: crate::ext::Classify::kind
+//! [`code`]: crate::ext::Classify::code
+//! [`Display`]: core::fmt::Display
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use alloc::{string::String, vec::Vec};
+use core::fmt::Display;
+
+use crate::ext::Classify;
+
+/// The state an error is classified as.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "lowercase")
+)]
+pub enum Classification {
+  /// The error is a [`Mistake`](crate::prelude::Mistake).
+  Mistake,
+  /// The error is a [`Failure`](crate::prelude::Failure).
+  Failure,
+}
+
+/// A single entry in a [`ClassificationTable`].
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule {
+  #[cfg_attr(feature = "serde", serde(default))]
+  kind: Option<String>,
+  #[cfg_attr(feature = "serde", serde(default))]
+  code: Option<i64>,
+  #[cfg_attr(feature = "serde", serde(default))]
+  pattern: Option<String>,
+  class: Classification,
+}
+
+impl Rule {
+  /// Creates a rule that classifies every error as `class`.
+  #[must_use]
+  #[inline]
+  pub const fn new(class: Classification) -> Self {
+    Self {
+      kind: None,
+      code: None,
+      pattern: None,
+      class,
+    }
+  }
+
+  /// Restricts this rule to errors whose [`kind`] is `kind`.
+  ///
+  /// [`kind`]: crate::ext::Classify::kind
+  #[must_use]
+  #[inline]
+  pub fn kind(mut self, kind: impl Into<String>) -> Self {
+    self.kind = Some(kind.into());
+    self
+  }
+
+  /// Restricts this rule to errors whose [`code`] is `code`.
+  ///
+  /// [`code`]: crate::ext::Classify::code
+  #[must_use]
+  #[inline]
+  pub const fn code(mut self, code: i64) -> Self {
+    self.code = Some(code);
+    self
+  }
+
+  /// Restricts this rule to errors whose [`Display`] output contains
+  /// `pattern`.
+  ///
+  /// [`Display`]: core::fmt::Display
+  #[must_use]
+  #[inline]
+  pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+    self.pattern = Some(pattern.into());
+    self
+  }
+
+  /// Returns the classification applied when this rule matches.
+  #[must_use]
+  #[inline]
+  pub const fn class(&self) -> Classification {
+    self.class
+  }
+
+  /// Returns `true` if every criterion of this rule matches `error`.
+  pub fn matches<E>(&self, error: &E) -> bool
+  where
+    E: Classify + Display + ?Sized,
+  {
+    use alloc::string::ToString;
+    self
+      .kind
+      .as_deref()
+      .is_none_or(|kind| error.kind() == Some(kind))
+      && self.code.is_none_or(|code| error.code() == Some(code))
+      && self
+        .pattern
+        .as_deref()
+        .is_none_or(|pattern| error.to_string().contains(pattern))
+  }
+}
+
+/// An ordered list of [`Rule`]s overriding [`Classify`].
+///
+/// See the [module documentation](crate::classify) for details.
+///
+/// [`Classify`]: crate::ext::Classify
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(transparent)
+)]
+pub struct ClassificationTable {
+  rules: Vec<Rule>,
+}
+
+impl ClassificationTable {
+  /// Creates an empty table, which defers every error to [`Classify`].
+  ///
+  /// [`Classify`]: crate::ext::Classify
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self { rules: Vec::new() }
+  }
+
+  /// Appends `rule` to the table, returning the table.
+  #[must_use]
+  #[inline]
+  pub fn with(mut self, rule: Rule) -> Self {
+    self.push(rule);
+    self
+  }
+
+  /// Appends `rule` to the table.
+  #[inline]
+  pub fn push(&mut self, rule: Rule) {
+    self.rules.push(rule);
+  }
+
+  /// Returns the rules of this table, in the order they are consulted.
+  #[must_use]
+  #[inline]
+  pub fn rules(&self) -> &[Rule] {
+    &self.rules
+  }
+
+  /// Returns the classification of the first rule matching `error`, if any.
+  pub fn lookup<E>(&self, error: &E) -> Option<Classification>
+  where
+    E: Classify + Display + ?Sized,
+  {
+    self
+      .rules
+      .iter()
+      .find(|rule| rule.matches(error))
+      .map(Rule::class)
+  }
+
+  /// Returns `true` if `error` should be treated as a
+  /// [`Mistake`](crate::prelude::Mistake).
+  ///
+  /// When no rule matches, this defers to [`Classify::is_mistake`].
+  ///
+  /// [`Classify::is_mistake`]: crate::ext::Classify::is_mistake
+  pub fn is_mistake<E>(&self, error: &E) -> bool
+  where
+    E: Classify + Display + ?Sized,
+  {
+    self.lookup(error).map_or_else(
+      || error.is_mistake(),
+      |class| class == Classification::Mistake,
+    )
+  }
+}
+
+impl FromIterator<Rule> for ClassificationTable {
+  fn from_iter<I: IntoIterator<Item = Rule>>(iter: I) -> Self {
+    Self {
+      rules: iter.into_iter().collect(),
+    }
+  }
+}
+
+impl Extend<Rule> for ClassificationTable {
+  fn extend<I: IntoIterator<Item = Rule>>(&mut self, iter: I) {
+    self.rules.extend(iter);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Error(&'static str, i64);
+
+  impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+      f.write_str(self.0)
+    }
+  }
+
+  impl Classify for Error {
+    fn is_mistake(&self) -> bool {
+      false
+    }
+
+    fn kind(&self) -> Option<&str> {
+      Some("Backend")
+    }
+
+    fn code(&self) -> Option<i64> {
+      Some(self.1)
+    }
+  }
+
+  #[test]
+  fn every_criterion_must_match() {
+    let rule = Rule::new(Classification::Mistake)
+      .kind("Backend")
+      .code(500)
+      .pattern("flaky");
+    assert!(rule.matches(&Error("flaky backend", 500)));
+    assert!(!rule.matches(&Error("flaky backend", 501)));
+    assert!(!rule.matches(&Error("broken backend", 500)));
+  }
+
+  #[test]
+  fn first_match_wins() {
+    let table: ClassificationTable = [
+      Rule::new(Classification::Failure).pattern("fatal"),
+      Rule::new(Classification::Mistake).code(500),
+    ]
+    .into_iter()
+    .collect();
+    assert!(!table.is_mistake(&Error("fatal", 500)));
+    assert!(table.is_mistake(&Error("oops", 500)));
+    assert_eq!(table.lookup(&Error("oops", 404)), None);
+  }
+}
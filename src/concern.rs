@@ -1,9 +1,9 @@
 use core::{
-  fmt::Debug,
+  fmt::{self, Debug, Display, Formatter},
   ops::{Deref, DerefMut},
 };
 
-use crate::{iter::*, private::panic};
+use crate::{iter::*, private::panic, severity::Severity};
 
 /// `Concern` is a type that can represent a [`Success`], or [`Mistake`].
 ///
@@ -40,7 +40,7 @@ impl<S, M> Concern<S, M> {
   /// assert_eq!(x.as_ref(), Concern::Mistake(&47));
   /// ```
   #[inline]
-  pub fn as_ref(&self) -> Concern<&S, &M> {
+  pub const fn as_ref(&self) -> Concern<&S, &M> {
     match *self {
       Self::Success(ref value) => Concern::Success(value),
       Self::Mistake(ref value) => Concern::Mistake(value),
@@ -69,7 +69,7 @@ impl<S, M> Concern<S, M> {
   /// assert_eq!(x.unwrap_mistake(), 19);
   /// ```
   #[inline]
-  pub fn as_mut(&mut self) -> Concern<&mut S, &mut M> {
+  pub const fn as_mut(&mut self) -> Concern<&mut S, &mut M> {
     match *self {
       Self::Success(ref mut value) => Concern::Success(value),
       Self::Mistake(ref mut value) => Concern::Mistake(value),
@@ -145,7 +145,7 @@ impl<S, M> Concern<S, M> {
   /// [`Success`]: Concern::Success
   #[must_use = "if you intended to assert a success, consider `.unwrap()` instead"]
   #[inline]
-  pub fn is_success(&self) -> bool {
+  pub const fn is_success(&self) -> bool {
     if let Self::Success(_) = self {
       return true;
     }
@@ -168,13 +168,98 @@ impl<S, M> Concern<S, M> {
   /// [`Mistake`]: Concern::Mistake
   #[must_use = "if you intended to assert a mistake, consider `.unwrap_mistake()` instead"]
   #[inline]
-  pub fn is_mistake(&self) -> bool {
+  pub const fn is_mistake(&self) -> bool {
     if let Self::Mistake(_) = self {
       return true;
     }
     false
   }
 
+  /// Returns the [`Severity`] of the concern, which is never
+  /// [`Severity::Failure`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::Severity;
+  ///
+  /// let x: Concern<u32, i32> = Concern::Success(1);
+  /// assert_eq!(x.severity(), Severity::Success);
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn severity(&self) -> Severity {
+    match self {
+      Self::Success(_) => Severity::Success,
+      Self::Mistake(_) => Severity::Mistake,
+    }
+  }
+
+  /// Returns the contained [`Success`] value, consuming the `self` value,
+  /// without checking that the value is not a [`Mistake`].
+  ///
+  /// # Safety
+  ///
+  /// Calling this method on a [`Mistake`] is *[undefined behavior]*
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, f32> = Concern::Success(47);
+  /// assert_eq!(unsafe { x.unwrap_unchecked() }, 47);
+  /// ```
+  ///
+  /// ```no_run
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, f32> = Concern::Mistake(0.0f32);
+  /// unsafe { x.unwrap_unchecked(); } // Undefined Behavior!
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+  #[inline]
+  #[track_caller]
+  #[allow(unsafe_code)]
+  pub unsafe fn unwrap_unchecked(self) -> S {
+    debug_assert!(self.is_success());
+    if let Self::Success(value) = self {
+      return value;
+    }
+    core::hint::unreachable_unchecked();
+  }
+
+  /// Returns the contained [`Mistake`] value, consuming the `self` value,
+  /// without checking that the value is not a [`Success`].
+  ///
+  /// # Safety
+  ///
+  /// Calling this method on a [`Success`] is *[undefined behavior]*
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, i32> = Concern::Mistake(47);
+  /// assert_eq!(unsafe { x.unwrap_mistake_unchecked() }, 47);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+  #[inline]
+  #[track_caller]
+  #[allow(unsafe_code)]
+  pub unsafe fn unwrap_mistake_unchecked(self) -> M {
+    debug_assert!(self.is_mistake());
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    core::hint::unreachable_unchecked();
+  }
+
   /// Converts from `Concern<S, M>` to [`Option<S>`]
   ///
   /// Converts `self` into an [`Option<S>`], consuming `self`, and discarding
@@ -221,6 +306,31 @@ impl<S, M> Concern<S, M> {
     None
   }
 
+  /// Returns a `Result<S, M>`, which allows a user to still rely on the `?`
+  /// operator until [`Try`] has been stabilized.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn invoke(x: Concern<u32, &'static str>) -> Result<u32, &'static str> {
+  ///   let success = x.acclimate()?;
+  ///   Ok(success + 1)
+  /// }
+  ///
+  /// assert_eq!(invoke(Concern::Success(1)), Ok(2));
+  /// assert_eq!(invoke(Concern::Mistake("busy")), Err("busy"));
+  /// ```
+  ///
+  /// [`Try`]: core::ops::Try
+  #[inline]
+  pub fn acclimate(self) -> Result<S, M> {
+    match self {
+      Self::Success(value) => Ok(value),
+      Self::Mistake(value) => Err(value),
+    }
+  }
+
   /// Maps a `Concern<S, M>` to `Concern<T, F>` by applying a function to a
   /// contained [`Success`] value, leaving any [`Mistake`] value untouched.
   ///
@@ -275,6 +385,275 @@ impl<S, M> Concern<S, M> {
       Self::Mistake(value) => Concern::Mistake(callable(value)),
     }
   }
+
+  /// Returns the provided default (if [`Mistake`]), or applies a function to
+  /// the contained value (if [`Success`]).
+  ///
+  /// Arguments passed to `map_or` are eagerly evaluated; if you are passing
+  /// the result of a function call, it is recommended to use [`map_or_else`],
+  /// which is lazily evaluated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<&str, &str> = Concern::Success("foo");
+  /// assert_eq!(x.map_or(47, |v| v.len()), 3);
+  ///
+  /// let x: Concern<&str, &str> = Concern::Mistake("bar");
+  /// assert_eq!(x.map_or(47, |v| v.len()), 47);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  /// [`map_or_else`]: Concern::map_or_else
+  #[inline]
+  pub fn map_or<T, C>(self, default: T, callable: C) -> T
+  where
+    C: FnOnce(S) -> T,
+  {
+    match self {
+      Self::Success(value) => callable(value),
+      Self::Mistake(_) => default,
+    }
+  }
+
+  /// Maps a `Concern<S, M>` to `T` by applying a fallback function to a
+  /// contained [`Mistake`] value, or a function to a contained [`Success`]
+  /// value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<&str, u32> = Concern::Success("foo");
+  /// assert_eq!(x.map_or_else(|m| m * 2, |v| v.len() as u32), 3);
+  ///
+  /// let x: Concern<&str, u32> = Concern::Mistake(21);
+  /// assert_eq!(x.map_or_else(|m| m * 2, |v| v.len() as u32), 42);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn map_or_else<T, D, C>(self, default: D, callable: C) -> T
+  where
+    D: FnOnce(M) -> T,
+    C: FnOnce(S) -> T,
+  {
+    match self {
+      Self::Success(value) => callable(value),
+      Self::Mistake(value) => default(value),
+    }
+  }
+
+  /// Returns the [`Success`] value or a provided default.
+  ///
+  /// Arguments passed to `unwrap_or` are eagerly evaluated; if you are passing
+  /// the result of a function call, it is recommended to use
+  /// [`unwrap_or_else`], which is lazily evaluated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, &str> = Concern::Success(2);
+  /// assert_eq!(x.unwrap_or(3), 2);
+  ///
+  /// let x: Concern<u32, &str> = Concern::Mistake("mistaken");
+  /// assert_eq!(x.unwrap_or(3), 3);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`unwrap_or_else`]: Concern::unwrap_or_else
+  #[inline]
+  pub fn unwrap_or(self, default: S) -> S {
+    if let Self::Success(value) = self {
+      return value;
+    }
+    default
+  }
+
+  /// Returns the contained [`Success`] value or computes it from the
+  /// contained [`Mistake`] value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<usize, &str> = Concern::Success(2);
+  /// assert_eq!(x.unwrap_or_else(str::len), 2);
+  ///
+  /// let x: Concern<usize, &str> = Concern::Mistake("mistaken");
+  /// assert_eq!(x.unwrap_or_else(str::len), 8);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn unwrap_or_else<C>(self, callable: C) -> S
+  where
+    C: FnOnce(M) -> S,
+  {
+    match self {
+      Self::Success(value) => value,
+      Self::Mistake(value) => callable(value),
+    }
+  }
+
+  /// Returns the contained [`Mistake`] value or a provided default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, &str> = Concern::Success(2);
+  /// assert_eq!(x.unwrap_mistake_or("fine"), "fine");
+  /// ```
+  ///
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn unwrap_mistake_or(self, default: M) -> M {
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    default
+  }
+
+  /// Returns the contained [`Mistake`] value or computes it from the
+  /// contained [`Success`] value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, String> = Concern::Success(2);
+  /// assert_eq!(x.unwrap_mistake_or_else(|n| n.to_string()), "2");
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn unwrap_mistake_or_else<C>(self, callable: C) -> M
+  where
+    C: FnOnce(S) -> M,
+  {
+    match self {
+      Self::Success(value) => callable(value),
+      Self::Mistake(value) => value,
+    }
+  }
+
+  /// Calls `callable` with a reference to the contained [`Success`] value,
+  /// if any, and returns the `Concern` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Concern<u32, &str> = Concern::Success(4);
+  /// assert_eq!(x.inspect(|value| seen = Some(*value)), Concern::Success(4));
+  /// assert_eq!(seen, Some(4));
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  #[inline]
+  pub fn inspect<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&S),
+  {
+    if let Self::Success(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Calls `callable` with a reference to the contained [`Mistake`] value,
+  /// if any, and returns the `Concern` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Concern<u32, &str> = Concern::Mistake("busy");
+  /// let x = x.inspect_mistake(|value| seen = Some(*value));
+  /// assert_eq!(x, Concern::Mistake("busy"));
+  /// assert_eq!(seen, Some("busy"));
+  /// ```
+  ///
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn inspect_mistake<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&M),
+  {
+    if let Self::Mistake(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Transforms the `Concern<S, M>` into a [`Result<S, E>`], mapping
+  /// [`Success(s)`] to [`Ok(s)`] and [`Mistake`] to [`Err(error)`].
+  ///
+  /// The mistake value is discarded. Arguments passed to `success_or` are
+  /// eagerly evaluated; if passing the result of a function call, it is
+  /// recommended to use [`success_or_else`], which is lazily evaluated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, &str> = Concern::Success(42);
+  /// assert_eq!(x.success_or(0), Ok(42));
+  ///
+  /// let x: Concern<u32, &str> = Concern::Mistake("hello");
+  /// assert_eq!(x.success_or(0), Err(0));
+  /// ```
+  ///
+  /// [`Success(s)`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  /// [`Ok(s)`]: Ok
+  /// [`Err(error)`]: Err
+  /// [`success_or_else`]: Concern::success_or_else
+  #[inline]
+  pub fn success_or<E>(self, error: E) -> Result<S, E> {
+    match self {
+      Self::Success(value) => Ok(value),
+      Self::Mistake(_) => Err(error),
+    }
+  }
+
+  /// Transforms the `Concern<S, M>` into a [`Result<S, E>`], mapping
+  /// [`Success(s)`] to [`Ok(s)`] and [`Mistake(m)`] to [`Err(callable(m))`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, &str> = Concern::Success(42);
+  /// assert_eq!(x.success_or_else(str::len), Ok(42));
+  ///
+  /// let x: Concern<u32, &str> = Concern::Mistake("hello");
+  /// assert_eq!(x.success_or_else(str::len), Err(5));
+  /// ```
+  ///
+  /// [`Success(s)`]: Concern::Success
+  /// [`Mistake(m)`]: Concern::Mistake
+  /// [`Ok(s)`]: Ok
+  /// [`Err(callable(m))`]: Err
+  #[inline]
+  pub fn success_or_else<E, C>(self, callable: C) -> Result<S, E>
+  where
+    C: FnOnce(M) -> E,
+  {
+    match self {
+      Self::Success(value) => Ok(value),
+      Self::Mistake(value) => Err(callable(value)),
+    }
+  }
 }
 
 impl<S, M: Debug> Concern<S, M> {
@@ -345,6 +724,56 @@ impl<S: Debug, M> Concern<S, M> {
   }
 }
 
+impl<S: Default, M> Concern<S, M> {
+  /// Returns the contained [`Success`] value or a default.
+  ///
+  /// Consumes the `self` argument then, if [`Success`], returns the contained
+  /// value, otherwise if the concern is a [`Mistake`], returns the default
+  /// value for [`Success`]
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, &str> = Concern::Success(2);
+  /// assert_eq!(x.unwrap_or_default(), 2);
+  ///
+  /// let x: Concern<u32, &str> = Concern::Mistake("mistaken");
+  /// assert_eq!(x.unwrap_or_default(), 0);
+  /// ```
+  ///
+  /// [`Success`]: Concern::Success
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn unwrap_or_default(self) -> S {
+    if let Self::Success(value) = self {
+      return value;
+    }
+    S::default()
+  }
+}
+
+impl<S, M: Default> Concern<S, M> {
+  /// Returns the contained [`Mistake`] value or a default.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, String> = Concern::Success(2);
+  /// assert_eq!(x.mistake_or_default(), "");
+  /// ```
+  ///
+  /// [`Mistake`]: Concern::Mistake
+  #[inline]
+  pub fn mistake_or_default(self) -> M {
+    if let Self::Mistake(value) = self {
+      return value;
+    }
+    M::default()
+  }
+}
+
 impl<S: Deref, M> Concern<S, M> {
   /// Converts from `Concern<S, M>` (or `&Concern<S, M>`) to `Concern<&<S as
   /// Deref>::Target, M>`.
@@ -392,6 +821,134 @@ impl<S: DerefMut, M> Concern<S, M> {
   }
 }
 
+impl<S, M> Concern<S, M> {
+  /// Converts the contained value into a `T`, regardless of the variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u8, u32> = Concern::Success(47);
+  /// assert_eq!(x.converge::<u64>(), 47);
+  /// ```
+  #[inline]
+  pub fn converge<T>(self) -> T
+  where
+    S: Into<T>,
+    M: Into<T>,
+  {
+    match self {
+      Self::Success(value) => value.into(),
+      Self::Mistake(value) => value.into(),
+    }
+  }
+}
+
+impl<T> Concern<T, T> {
+  /// Returns the contained value, regardless of the variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, u32> = Concern::Mistake(47);
+  /// assert_eq!(x.into_inner(), 47);
+  /// ```
+  #[inline]
+  pub fn into_inner(self) -> T {
+    match self {
+      Self::Success(value) | Self::Mistake(value) => value,
+    }
+  }
+}
+
+impl<S: Clone, M: Clone> Concern<&S, &M> {
+  /// Maps a `Concern<&S, &M>` to a `Concern<S, M>` by cloning the
+  /// contained value, whatever its variant.
+  ///
+  /// This is the equivalent of [`Outcome::cloned_all`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let concerns: Vec<Concern<String, String>> =
+  ///   vec![Concern::Success("a".into()), Concern::Mistake("b".into())];
+  /// let owned: Vec<_> =
+  ///   concerns.iter().map(|x| x.as_ref().cloned_all()).collect();
+  /// assert_eq!(owned, concerns);
+  /// ```
+  ///
+  /// [`Outcome::cloned_all`]: crate::prelude::Outcome::cloned_all
+  #[inline]
+  pub fn cloned_all(self) -> Concern<S, M> {
+    match self {
+      Self::Success(value) => Concern::Success(value.clone()),
+      Self::Mistake(value) => Concern::Mistake(value.clone()),
+    }
+  }
+}
+
+impl<S: Clone, M: Clone> Concern<&mut S, &mut M> {
+  /// Maps a `Concern<&mut S, &mut M>` to a `Concern<S, M>` by cloning the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Concern<u32, String> = Concern::Mistake("busy".into());
+  /// assert_eq!(x.as_mut().cloned_all(), Concern::Mistake("busy".to_string()));
+  /// ```
+  #[inline]
+  pub fn cloned_all(self) -> Concern<S, M> {
+    match self {
+      Self::Success(value) => Concern::Success(value.clone()),
+      Self::Mistake(value) => Concern::Mistake(value.clone()),
+    }
+  }
+}
+
+impl<S: Copy, M: Copy> Concern<&S, &M> {
+  /// Maps a `Concern<&S, &M>` to a `Concern<S, M>` by copying the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Concern<u32, u8> = Concern::Success(47);
+  /// assert_eq!(x.as_ref().copied_all(), Concern::Success(47));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Concern<S, M> {
+    match self {
+      Self::Success(&value) => Concern::Success(value),
+      Self::Mistake(&value) => Concern::Mistake(value),
+    }
+  }
+}
+
+impl<S: Copy, M: Copy> Concern<&mut S, &mut M> {
+  /// Maps a `Concern<&mut S, &mut M>` to a `Concern<S, M>` by copying the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Concern<u32, u8> = Concern::Mistake(3);
+  /// assert_eq!(x.as_mut().copied_all(), Concern::Mistake(3));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Concern<S, M> {
+    match self {
+      Self::Success(&mut value) => Concern::Success(value),
+      Self::Mistake(&mut value) => Concern::Mistake(value),
+    }
+  }
+}
+
 impl<S: Clone, M: Clone> Clone for Concern<S, M> {
   #[inline]
   fn clone(&self) -> Self {
@@ -410,3 +967,21 @@ impl<S: Clone, M: Clone> Clone for Concern<S, M> {
     }
   }
 }
+
+/// Formats the contained value, prefixed with the name of its variant.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let x: Concern<u32, &str> = Concern::Mistake("try again");
+/// assert_eq!(x.to_string(), "Mistake: try again");
+/// ```
+impl<S: Display, M: Display> Display for Concern<S, M> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Success(value) => write!(f, "Success: {value}"),
+      Self::Mistake(value) => write!(f, "Mistake: {value}"),
+    }
+  }
+}
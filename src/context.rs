@@ -0,0 +1,293 @@
+//! Thread-local context attached to failures.
+//!
+//! Calling [`scope`] pushes a frame describing the current operation onto a
+//! thread-local stack for the duration of a closure. Failures created inside
+//! the scope pick up a snapshot of the stack automatically:
+//!
+//! * A [`Contextual`] captures the stack when it is created, whether by
+//!   [`Contextual::new`], [`Outcome::attach_context`], or the [`From`]
+//!   conversion used by the [`failure!`] family of macros and the `?`
+//!   operator. Its [`Display`] implementation prefixes the error with the
+//!   stack.
+//! * When the `report` or `diagnostic` feature is enabled, reports wrapped
+//!   with `WrapFailure`, or created by the `fail!` family of macros, have the
+//!   stack added as their outermost message. This provides context much like
+//!   [`eyre`]'s `wrap_err`, without needing to thread `wrap_failure` through
+//!   every call.
+//! * The [`hooks`](crate::hooks) receive the stack through
+//!   [`Event::context`](crate::hooks::Event::context), whatever the type of
+//!   the error.
+//!
+//! Other error types have nowhere to store the stack, so a plain `F` created
+//! with [`Failure`] or [`Outcome::failure_hooked`] does not carry it. With the
+//! `nightly` feature on a nightly compiler, a [`Contextual`] also provides its
+//! [`Context`] through [`Error::provide`], so that it can be requested from
+//! anywhere in an error chain. On stable, the [`Context`] is only reachable
+//! by downcasting to the [`Contextual`] itself.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::context::{self, Contextual};
+//!
+//! fn parse(input: &str) -> Outcome<u32, (), Contextual<&'static str>> {
+//!   context::scope("parsing header", || match input.parse() {
+//!     Ok(value) => Success(value),
+//!     Err(_) => Failure("not a number"),
+//!   })
+//!   .attach_context()
+//! }
+//!
+//! let failure = context::scope("loading config", || parse("x"));
+//! let failure = failure.unwrap_failure();
+//! assert_eq!(failure.context().frames(), ["loading config"]);
+//! assert_eq!(failure.to_string(), "loading config: not a number");
+//! assert!(context::current().is_none());
+//! ```
+//!
+//! Note that in the above example, `attach_context` is called *after* the
+//! innermost scope has exited, so only the outer frame is captured. Failing
+//! inside the innermost scope captures both frames:
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::{
+//!   context::{self, Contextual},
+//!   failure,
+//! };
+//!
+//! fn parse(input: &str) -> Outcome<u32, (), Contextual<&'static str>> {
+//!   context::scope("parsing header", || match input.parse() {
+//!     Ok(value) => Success(value),
+//!     Err(_) => failure!("not a number"),
+//!   })
+//! }
+//!
+//! let failure = context::scope("loading config", || parse("x"));
+//! assert_eq!(
+//!   failure.unwrap_failure().to_string(),
+//!   "loading config: parsing header: not a number"
+//! );
+//! ```
+//!
+//! [`Failure`]: crate::prelude::Failure
+//! [`failure!`]: crate::failure
+//! [`Error::provide`]: core::error::Error
+//! [`Outcome::failure_hooked`]: crate::prelude::Outcome::failure_hooked
+//!
+//! [`eyre`]: https://crates.io/crates/eyre
+extern crate std;
+
+use core::{
+  cell::RefCell,
+  fmt::{self, Display, Formatter},
+};
+use std::{borrow::Cow, error::Error, vec::Vec};
+
+use crate::prelude::*;
+
+std::thread_local! {
+  static STACK: RefCell<Vec<Cow<'static, str>>> = const {
+    RefCell::new(Vec::new())
+  };
+}
+
+struct Guard;
+
+impl Drop for Guard {
+  fn drop(&mut self) {
+    STACK.with_borrow_mut(Vec::pop);
+  }
+}
+
+/// Executes `callable` with `frame` pushed onto the current thread's context
+/// stack.
+///
+/// The frame is popped when `callable` returns, even if it panics.
+pub fn scope<R>(
+  frame: impl Into<Cow<'static, str>>,
+  callable: impl FnOnce() -> R,
+) -> R {
+  STACK.with_borrow_mut(|stack| stack.push(frame.into()));
+  let _guard = Guard;
+  callable()
+}
+
+/// Returns a snapshot of the current thread's context stack, or `None` if no
+/// [`scope`] is active.
+#[must_use]
+pub fn current() -> Option<Context> {
+  STACK.with_borrow(|stack| {
+    (!stack.is_empty()).then(|| Context {
+      frames: stack.clone(),
+    })
+  })
+}
+
+/// A snapshot of a thread's context stack, ordered from outermost to
+/// innermost frame.
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Context {
+  frames: Vec<Cow<'static, str>>,
+}
+
+impl Context {
+  /// Returns the captured frames, from outermost to innermost.
+  #[must_use]
+  #[inline]
+  pub fn frames(&self) -> &[Cow<'static, str>] {
+    &self.frames
+  }
+
+  /// Returns `true` if no frames were captured.
+  #[must_use]
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.frames.is_empty()
+  }
+}
+
+impl Display for Context {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    for (index, frame) in self.frames.iter().enumerate() {
+      if index > 0 {
+        f.write_str(": ")?;
+      }
+      f.write_str(frame)?;
+    }
+    Ok(())
+  }
+}
+
+/// An error paired with the [`Context`] active when it was created.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Contextual<E> {
+  error: E,
+  context: Context,
+}
+
+impl<E> Contextual<E> {
+  /// Pairs `error` with a snapshot of the current thread's context stack.
+  #[must_use]
+  pub fn new(error: E) -> Self {
+    Self {
+      error,
+      context: current().unwrap_or_default(),
+    }
+  }
+
+  /// Returns a reference to the error.
+  #[must_use]
+  #[inline]
+  pub fn error(&self) -> &E {
+    &self.error
+  }
+
+  /// Returns the captured context.
+  #[must_use]
+  #[inline]
+  pub fn context(&self) -> &Context {
+    &self.context
+  }
+
+  /// Consumes the `Contextual`, returning the error.
+  #[inline]
+  pub fn into_error(self) -> E {
+    self.error
+  }
+}
+
+impl<E: Display> Display for Contextual<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    if self.context.is_empty() {
+      Display::fmt(&self.error, f)
+    } else {
+      write!(f, "{}: {}", self.context, self.error)
+    }
+  }
+}
+
+/// Pairs `error` with a snapshot of the current thread's context stack, as
+/// [`Contextual::new`] does.
+impl<E> From<E> for Contextual<E> {
+  #[inline]
+  fn from(error: E) -> Self {
+    Self::new(error)
+  }
+}
+
+impl<E: Error + 'static> Error for Contextual<E> {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    Some(&self.error)
+  }
+
+  #[cfg(all(nightly, feature = "nightly"))]
+  fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+    request.provide_ref::<Context>(&self.context);
+    self.error.provide(request);
+  }
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Pairs a [`Failure`] with the current thread's context stack.
+  ///
+  /// See the [`context`](crate::context) module for details.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn attach_context(self) -> Outcome<S, M, Contextual<F>> {
+    self.map_failure(Contextual::new)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[test]
+  fn nested() {
+    scope("outer", || {
+      scope(std::format!("inner {}", 1), || {
+        let context = current().unwrap();
+        assert_eq!(context.frames(), ["outer", "inner 1"]);
+        assert_eq!(context.to_string(), "outer: inner 1");
+      });
+      assert_eq!(current().unwrap().frames(), ["outer"]);
+    });
+    assert!(current().is_none());
+  }
+
+  #[cfg(feature = "report")]
+  #[test]
+  fn wrapped_failure() {
+    use crate::report::{Report, WrapFailure};
+    let x: Outcome<(), (), std::io::Error> =
+      Failure(std::io::ErrorKind::NotFound.into());
+    let report: Report =
+      scope("loading config", || x.wrap_failure("opening file"))
+        .unwrap_failure();
+    let chain: Vec<_> = report.chain().map(ToString::to_string).collect();
+    assert_eq!(chain[..2], ["loading config", "opening file"]);
+  }
+
+  #[cfg(feature = "report")]
+  #[test]
+  fn failed_report() {
+    use crate::report::{fail, Report};
+    fn open() -> Outcome<(), (), Report> {
+      fail!("file not found");
+    }
+    let report = scope("loading config", open).unwrap_failure();
+    let chain: Vec<_> = report.chain().map(ToString::to_string).collect();
+    assert_eq!(chain, ["loading config", "file not found"]);
+  }
+
+  #[test]
+  fn popped_on_panic() {
+    let result = std::panic::catch_unwind(|| scope("panics", || panic!()));
+    assert!(result.is_err());
+    assert!(current().is_none());
+  }
+}
@@ -6,6 +6,24 @@
 //! traits are provided, [`AttemptFrom`] and [`AttemptInto`], which mirror
 //! [`TryFrom`] and [`TryInto`] respectively.
 //!
+//! Conversions that must wait on I/O (resolving a hostname, fetching metadata,
+//! etc.) can instead implement [`AsyncAttemptFrom`], whose
+//! [`AsyncAttemptInto`] counterpart is likewise provided for free.
+//!
+//! Existing [`TryFrom`] implementations can be reused by wrapping the value in
+//! a [`TryAdapter`], which maps the conversion error to a [`Failure`].
+//! Alternatively, the `try-into` feature provides [`AttemptFrom`] for every
+//! [`TryFrom`] implementation directly.
+//!
+//! When the `derive` feature is enabled, [`AttemptFrom`] can also be derived
+//! for newtypes and enums that wrap types already implementing it.
+//!
+//! Parsing is handled by [`AttemptFromStr`], the analogue to [`FromStr`].
+//! Parsers naturally distinguish between input that is merely incomplete,
+//! which can be retried once more input arrives, and input that is malformed.
+//! The [`AttemptParse`] extension trait provides `str::attempt_parse`, and
+//! implementations are provided for most primitive types.
+//!
 //! As a library author, you should always prefer implementing [`AttemptFrom`]
 //! over [`AttemptInto`], as [`AttemptFrom`] offers greater flexibility and
 //! offers an equivalent [`AttemptInto`] implementation for free, thanks to a
@@ -14,15 +32,41 @@
 //! # Generic Implementations
 //!
 //!  - [`AttemptFrom`]`<U> for T` implies [`AttemptInto`]`<T> for U`
+//!  - [`AsyncAttemptFrom`]`<U> for T` implies [`AsyncAttemptInto`]`<T> for U`
 //!
 //! [`AttemptFrom`]: crate::convert::AttemptFrom
 //! [`AttemptInto`]: crate::convert::AttemptInto
+//! [`AsyncAttemptFrom`]: crate::convert::AsyncAttemptFrom
+//! [`AsyncAttemptInto`]: crate::convert::AsyncAttemptInto
+//! [`TryAdapter`]: crate::convert::TryAdapter
+//! [`AttemptFromStr`]: crate::convert::AttemptFromStr
+//! [`AttemptParse`]: crate::convert::AttemptParse
+//! [`FromStr`]: core::str::FromStr
 //! [`TryFrom`]: core::convert::TryFrom
 //! [`TryInto`]: core::convert::TryInto
+//! [`Failure`]: crate::prelude::Failure
+
+#[cfg(feature = "std")]
+extern crate std;
 
-use core::convert::Infallible;
+use core::{
+  char::ParseCharError,
+  convert::Infallible,
+  fmt::{self, Display, Formatter},
+  future::{ready, Future},
+  net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+  num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    ParseFloatError, ParseIntError,
+  },
+  str::ParseBoolError,
+};
 
-use crate::prelude::{Outcome, Success};
+use crate::{
+  prelude::{Failure, Mistake, Outcome, Success},
+  private::Sealed,
+};
 
 /// Outcome's analogue to [`TryFrom`], and the reciprocal of [`TryInto`].
 ///
@@ -100,6 +144,79 @@ pub trait AttemptFrom<T>: Sized {
   fn attempt_from(value: T) -> Outcome<Self, Self::Mistake, Self::Failure>;
 }
 
+/// Derives [`AttemptFrom`] for newtypes and enum wrappers.
+///
+/// Each source type is listed with `#[outcome(from = Type)]`, which may be
+/// repeated. The [`Mistake`] and [`Failure`] types are set with
+/// `#[outcome(mistake = Type, failure = Type)]`, and the errors of every
+/// wrapped conversion are converted into them via [`Into`].
+///
+///  - A struct with a single field delegates to the [`AttemptFrom`]
+///    implementation of that field. When `mistake` or `failure` are omitted,
+///    the types of the wrapped conversion are used as is.
+///  - An enum whose variants each contain a single unnamed field attempts the
+///    conversion of every variant in order, returning the first success.
+///    Otherwise, the first mistake is returned, or the last failure should no
+///    variant produce a mistake. The source type must implement [`Clone`], and
+///    both `mistake` and `failure` are required.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::convert::{AttemptFrom, AttemptParse, Incomplete};
+/// use std::net::{AddrParseError, Ipv4Addr, Ipv6Addr};
+///
+/// #[derive(AttemptFrom, Debug, PartialEq)]
+/// #[outcome(from = Address<'_>, mistake = Incomplete, failure = AddrParseError)]
+/// enum Host {
+///   V4(Ipv4Addr),
+///   V6(Ipv6Addr),
+/// }
+///
+/// #[derive(Clone)]
+/// struct Address<'a>(&'a str);
+///
+/// impl AttemptFrom<Address<'_>> for Ipv4Addr {
+///   type Mistake = Incomplete;
+///   type Failure = AddrParseError;
+///
+///   fn attempt_from(value: Address<'_>) -> Outcome<Self, Incomplete, AddrParseError> {
+///     value.0.attempt_parse()
+///   }
+/// }
+///
+/// impl AttemptFrom<Address<'_>> for Ipv6Addr {
+///   type Mistake = Incomplete;
+///   type Failure = AddrParseError;
+///
+///   fn attempt_from(value: Address<'_>) -> Outcome<Self, Incomplete, AddrParseError> {
+///     value.0.attempt_parse()
+///   }
+/// }
+///
+/// #[derive(AttemptFrom, Debug, PartialEq)]
+/// #[outcome(from = Address<'_>)]
+/// struct Gateway(Host);
+///
+/// let gateway = Gateway::attempt_from(Address("10.0.0.1"));
+/// assert_eq!(gateway, Success(Gateway(Host::V4(Ipv4Addr::new(10, 0, 0, 1)))));
+///
+/// let host = Host::attempt_from(Address("::1"));
+/// assert_eq!(host, Success(Host::V6(Ipv6Addr::LOCALHOST)));
+///
+/// let host = Host::attempt_from(Address("10.0."));
+/// assert_eq!(host, Mistake(Incomplete::Truncated));
+///
+/// assert!(Host::attempt_from(Address("localhost")).is_failure());
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use outcome_macros::AttemptFrom;
+
 /// An attempted conversion that consumes `self`, which may or may not be
 /// expensive. Outcome's analogue to [`TryInto`].
 ///
@@ -184,6 +301,351 @@ pub trait AttemptInto<T>: Sized {
   fn attempt_into(self) -> Outcome<T, Self::Mistake, Self::Failure>;
 }
 
+/// An asynchronous analogue to [`AttemptFrom`].
+///
+/// This is useful when a conversion must wait on I/O before it can decide
+/// whether it succeeded, such as resolving a hostname or fetching metadata.
+/// As with [`AttemptFrom`], a [`Mistake`] informs the caller that they are
+/// free to retry the conversion.
+///
+/// Implementations may use `async fn` directly. The returned future is not
+/// required to be [`Send`], so that conversions may be driven by
+/// single-threaded and embedded executors.
+///
+/// # Examples
+///
+/// ```
+/// use core::future::Future;
+/// use core::pin::pin;
+/// use core::task::{Context, Poll, Waker};
+/// use outcome::convert::*;
+/// use outcome::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Address([u8; 4]);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum LookupError {
+///   Timeout,
+///   NotFound,
+/// }
+///
+/// async fn lookup(host: &str) -> Result<[u8; 4], LookupError> {
+///   match host {
+///     "localhost" => Ok([127, 0, 0, 1]),
+///     "slow.example" => Err(LookupError::Timeout),
+///     _ => Err(LookupError::NotFound),
+///   }
+/// }
+///
+/// impl AsyncAttemptFrom<&str> for Address {
+///   type Mistake = LookupError;
+///   type Failure = LookupError;
+///
+///   async fn attempt_from_async(host: &str) -> Outcome<Self, LookupError, LookupError> {
+///     match lookup(host).await {
+///       Ok(octets) => Success(Address(octets)),
+///       Err(LookupError::Timeout) => Mistake(LookupError::Timeout),
+///       Err(error) => Failure(error),
+///     }
+///   }
+/// }
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let mut resolve = |host: &str| pin!(Address::attempt_from_async(host)).poll(&mut cx);
+/// assert_eq!(resolve("localhost"), Poll::Ready(Success(Address([127, 0, 0, 1]))));
+/// assert_eq!(resolve("slow.example"), Poll::Ready(Mistake(LookupError::Timeout)));
+/// assert_eq!(resolve("nowhere"), Poll::Ready(Failure(LookupError::NotFound)));
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Send`]: core::marker::Send
+pub trait AsyncAttemptFrom<T>: Sized {
+  /// The *retryable* error type
+  type Mistake;
+  /// The *failure* error type
+  type Failure;
+
+  /// Performs the conversion
+  fn attempt_from_async(
+    value: T,
+  ) -> impl Future<Output = Outcome<Self, Self::Mistake, Self::Failure>>;
+}
+
+/// An asynchronous analogue to [`AttemptInto`].
+///
+/// Library writers should *usually* not implement this trait directly, but
+/// should prefer implementing the [`AsyncAttemptFrom`] trait, which provides
+/// an equivalent `AsyncAttemptInto` implementation for free.
+///
+/// # Examples
+///
+/// ```
+/// use core::future::Future;
+/// use core::pin::pin;
+/// use core::task::{Context, Poll, Waker};
+/// use outcome::convert::*;
+/// use outcome::prelude::*;
+///
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let future = AsyncAttemptInto::<u64>::attempt_into_async(47u32);
+/// assert_eq!(pin!(future).poll(&mut cx), Poll::Ready(Success(47u64)));
+/// ```
+pub trait AsyncAttemptInto<T>: Sized {
+  /// The type returned in the event of a conversion error where the caller
+  /// *may* retry the conversion.
+  type Mistake;
+  /// The type returned in the event of a conversion error where the caller
+  /// *may not* retry the conversion.
+  type Failure;
+
+  /// Performs the conversion.
+  fn attempt_into_async(
+    self,
+  ) -> impl Future<Output = Outcome<T, Self::Mistake, Self::Failure>>;
+}
+
+/// Adapts a [`TryInto`] conversion to return an [`Outcome`].
+///
+/// A blanket [`AttemptFrom`] implementation for every [`TryInto`]
+/// implementation would conflict with the one provided for [`Into`], so this
+/// wrapper offers an inherent [`attempt_into`] method instead. Any `Err`
+/// returned by the conversion is mapped to a [`Failure`], and the [`Mistake`]
+/// type is [`Infallible`].
+///
+/// Alternatively, the `try-into` feature replaces the blanket [`AttemptFrom`]
+/// implementation for [`Into`] with one for every [`TryInto`]
+/// implementation, which behaves the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::convert::Infallible;
+/// use core::num::TryFromIntError;
+/// use outcome::convert::TryAdapter;
+///
+/// let x: Outcome<u8, Infallible, TryFromIntError> = TryAdapter(47u32).attempt_into();
+/// assert_eq!(x, Success(47));
+///
+/// let x = TryAdapter(300u32).attempt_into::<u8>();
+/// assert!(x.is_failure());
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`TryInto`]: core::convert::TryInto
+/// [`Into`]: core::convert::Into
+/// [`attempt_into`]: TryAdapter::attempt_into
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Default)]
+#[repr(transparent)]
+pub struct TryAdapter<T>(pub T);
+
+impl<T> TryAdapter<T> {
+  /// Performs the conversion, mapping any error to a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn attempt_into<U>(self) -> Outcome<U, Infallible, T::Error>
+  where
+    T: TryInto<U>,
+  {
+    match self.0.try_into() {
+      Ok(s) => Success(s),
+      Err(f) => Failure(f),
+    }
+  }
+
+  /// Returns the wrapped value.
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+/// Outcome's analogue to [`FromStr`].
+///
+/// Parsing is the canonical case for distinguishing a [`Mistake`] from a
+/// [`Failure`]: input that is incomplete (such as a partially received line)
+/// may succeed once more input is available, while malformed input never
+/// will. This trait is usually used through [`AttemptParse::attempt_parse`].
+///
+/// Implementations are provided for integers, floats, [`bool`], [`char`], and
+/// the types found in [`core::net`]. These treat empty or whitespace-only
+/// input, as well as input that is clearly truncated (e.g., a lone `-`, or an
+/// address ending in `.` or `:`), as an [`Incomplete`] mistake, and anything
+/// else that fails to parse as a failure.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::convert::{AttemptFromStr, Incomplete};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Command(String);
+///
+/// impl AttemptFromStr for Command {
+///   type Mistake = Incomplete;
+///   type Failure = &'static str;
+///
+///   fn attempt_from_str(value: &str) -> Outcome<Self, Incomplete, &'static str> {
+///     match value.strip_suffix('\n') {
+///       None => Mistake(Incomplete::Truncated),
+///       Some(line) if line.is_ascii() => Success(Command(line.into())),
+///       Some(_) => Failure("commands must be ASCII"),
+///     }
+///   }
+/// }
+///
+/// assert_eq!(Command::attempt_from_str("GET"), Mistake(Incomplete::Truncated));
+/// assert_eq!(Command::attempt_from_str("GET\n"), Success(Command("GET".into())));
+/// ```
+///
+/// [`FromStr`]: core::str::FromStr
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+pub trait AttemptFromStr: Sized {
+  /// The *retryable* error type
+  type Mistake;
+  /// The *failure* error type
+  type Failure;
+
+  /// Parses a value from `value`
+  fn attempt_from_str(
+    value: &str,
+  ) -> Outcome<Self, Self::Mistake, Self::Failure>;
+}
+
+/// Provides [`attempt_parse`] for string slices.
+///
+/// This trait is sealed and cannot be implemented outside of `outcome`.
+///
+/// [`attempt_parse`]: AttemptParse::attempt_parse
+pub trait AttemptParse: Sealed {
+  /// Parses this string into another type, as with [`str::parse`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::convert::{AttemptParse, Incomplete};
+  ///
+  /// assert_eq!("42".attempt_parse::<u32>(), Success(42));
+  /// assert_eq!("  ".attempt_parse::<u32>(), Mistake(Incomplete::Empty));
+  /// assert_eq!("-".attempt_parse::<i8>(), Mistake(Incomplete::Truncated));
+  /// assert!("4x".attempt_parse::<u32>().is_failure());
+  ///
+  /// assert_eq!("tr".attempt_parse::<bool>(), Mistake(Incomplete::Truncated));
+  /// assert_eq!("10.0.0.".attempt_parse::<std::net::Ipv4Addr>(), Mistake(Incomplete::Truncated));
+  /// ```
+  fn attempt_parse<T: AttemptFromStr>(
+    &self,
+  ) -> Outcome<T, T::Mistake, T::Failure>;
+}
+
+/// The [`Mistake`] returned when parsing input that is not yet complete.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Incomplete {
+  /// The input was empty, or contained only whitespace.
+  Empty,
+  /// The input ended before a value could be parsed.
+  Truncated,
+}
+
+impl Display for Incomplete {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Empty => "input is empty",
+      Self::Truncated => "input is truncated",
+    })
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Incomplete {}
+
+impl AttemptParse for str {
+  #[inline]
+  fn attempt_parse<T: AttemptFromStr>(
+    &self,
+  ) -> Outcome<T, T::Mistake, T::Failure> {
+    T::attempt_from_str(self)
+  }
+}
+
+/* Every builtin `AttemptFromStr` implementation parses with `FromStr` first,
+ * and only inspects the input to decide between a mistake and failure once
+ * parsing has failed. `truncated` receives the trimmed, non-empty input.
+ */
+fn parse<T, E>(
+  value: &str,
+  truncated: fn(&str) -> bool,
+) -> Outcome<T, Incomplete, E>
+where
+  T: core::str::FromStr<Err = E>,
+{
+  match value.parse() {
+    Ok(value) => Success(value),
+    Err(_) if value.trim().is_empty() => Mistake(Incomplete::Empty),
+    Err(_) if truncated(value.trim()) => Mistake(Incomplete::Truncated),
+    Err(error) => Failure(error),
+  }
+}
+
+fn is_sign(value: &str) -> bool {
+  matches!(value, "-" | "+")
+}
+
+fn is_truncated_float(value: &str) -> bool {
+  let value = value.strip_prefix(['-', '+']).unwrap_or(value);
+  let value = value.strip_suffix(['-', '+']).unwrap_or(value);
+  match value.strip_suffix(['e', 'E']) {
+    Some(mantissa) => mantissa.ends_with(|c: char| c.is_ascii_digit()),
+    None => value.is_empty(),
+  }
+}
+
+fn is_truncated_bool(value: &str) -> bool {
+  "true".starts_with(value) || "false".starts_with(value)
+}
+
+fn is_truncated_address(value: &str) -> bool {
+  value.ends_with(['.', ':', '[', '%'])
+}
+
+macro_rules! attempt_from_str {
+  ($truncated:expr, $failure:ty => $($type:ty),+ $(,)?) => {$(
+    impl AttemptFromStr for $type {
+      type Mistake = Incomplete;
+      type Failure = $failure;
+
+      #[inline]
+      fn attempt_from_str(
+        value: &str,
+      ) -> Outcome<Self, Self::Mistake, Self::Failure> {
+        parse(value, $truncated)
+      }
+    }
+  )+};
+}
+
+attempt_from_str!(is_sign, ParseIntError =>
+  i8, i16, i32, i64, i128, isize,
+  u8, u16, u32, u64, u128, usize,
+  NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+  NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+);
+attempt_from_str!(is_truncated_float, ParseFloatError => f32, f64);
+attempt_from_str!(is_truncated_bool, ParseBoolError => bool);
+attempt_from_str!(|_| false, ParseCharError => char);
+attempt_from_str!(is_truncated_address, AddrParseError =>
+  IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr,
+);
+
 /* Blanket Trait Implementations */
 impl<T, U> AttemptInto<U> for T
 where
@@ -197,6 +659,7 @@ where
   }
 }
 
+#[cfg(not(feature = "try-into"))]
 impl<T, U> AttemptFrom<U> for T
 where
   U: Into<Self>,
@@ -209,32 +672,94 @@ where
   }
 }
 
-// Reflexive implementation for all [`TryInto`] implementations.
-//
-// # Notes
-//
-// If a [`TryInto`] implementation exists because of an [`Into`]
-// implementation, the type returned by [`AttemptFrom`] will be an `Outcome<T,
-// !, !>`. If the [`unstable` feature](crate#features) is enabled, users can
-// then call [`Outcome::into_success`], which will never panic.
-//
-// ```compile_fail
-// # use outcome::prelude::*;
-// # use core::convert::Infallible;
-// let x: Outcome<u16, Infallible, Infallible> = 1u8.attempt_into();
-// assert_eq!(x.into_success(), 1);
-// ```
-//impl<T, U> AttemptFrom<U> for T
-//where
-//  U: TryInto<Self>,
-//{
-//  type Mistake = Infallible;
-//  type Failure = <U as TryInto<Self>>::Error;
-//
-//  fn attempt_from(value: U) -> Outcome<Self, Self::Mistake, Self::Failure> {
-//    match value.try_into() {
-//      Ok(s) => Success(s),
-//      Err(f) => Failure(f),
-//    }
-//  }
-//}
+impl<T, U> AsyncAttemptInto<U> for T
+where
+  U: AsyncAttemptFrom<Self>,
+{
+  type Mistake = U::Mistake;
+  type Failure = U::Failure;
+
+  fn attempt_into_async(
+    self,
+  ) -> impl Future<Output = Outcome<U, Self::Mistake, Self::Failure>> {
+    U::attempt_from_async(self)
+  }
+}
+
+impl<T, U> AsyncAttemptFrom<U> for T
+where
+  U: Into<Self>,
+{
+  type Mistake = Infallible;
+  type Failure = Infallible;
+
+  fn attempt_from_async(
+    value: U,
+  ) -> impl Future<Output = Outcome<Self, Self::Mistake, Self::Failure>> {
+    ready(Success(value.into()))
+  }
+}
+
+/* With the `try-into` feature, every `TryInto` implementation is reused as
+ * an `AttemptFrom` implementation, mapping `Err` to a `Failure`. As `Into`
+ * implies `TryInto` (with an `Infallible` error), this replaces the `Into`
+ * implementation above rather than conflicting with it.
+ */
+#[cfg(feature = "try-into")]
+impl<T, U> AttemptFrom<U> for T
+where
+  U: TryInto<Self>,
+{
+  type Mistake = Infallible;
+  type Failure = <U as TryInto<Self>>::Error;
+
+  fn attempt_from(value: U) -> Outcome<Self, Self::Mistake, Self::Failure> {
+    match value.try_into() {
+      Ok(s) => Success(s),
+      Err(f) => Failure(f),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(feature = "try-into")]
+  #[test]
+  fn try_into_blanket() {
+    let x: Outcome<u8, Infallible, _> = 47u32.attempt_into();
+    assert_eq!(x, Success(47));
+    assert!(u8::attempt_from(300u32).is_failure());
+    assert_eq!(u16::attempt_from(1u8), Success(1));
+  }
+
+  #[test]
+  fn truncated_floats() {
+    assert_eq!("1e".attempt_parse::<f64>(), Mistake(Incomplete::Truncated));
+    assert_eq!(
+      "-2.5E-".attempt_parse::<f32>(),
+      Mistake(Incomplete::Truncated)
+    );
+    assert_eq!("+".attempt_parse::<f64>(), Mistake(Incomplete::Truncated));
+    assert!("infinite".attempt_parse::<f64>().is_failure());
+    assert_eq!("1e3".attempt_parse::<f64>(), Success(1000.0));
+  }
+
+  #[test]
+  fn addresses() {
+    assert_eq!(
+      "127.0.0.1:".attempt_parse::<SocketAddr>(),
+      Mistake(Incomplete::Truncated)
+    );
+    assert!("::".attempt_parse::<Ipv6Addr>().is_success());
+    assert!("localhost".attempt_parse::<IpAddr>().is_failure());
+  }
+
+  #[test]
+  fn chars() {
+    assert_eq!("".attempt_parse::<char>(), Mistake(Incomplete::Empty));
+    assert_eq!(" ".attempt_parse::<char>(), Success(' '));
+    assert!("ab".attempt_parse::<char>().is_failure());
+  }
+}
@@ -1,22 +1,337 @@
 //! Support for the [`miette`] crate.
 //!
-//! This module re-exports `miette`'s [`Report`] as well as [`Result`], and
-//! provides a trait, [`WrapFailure`], as a mirror to the [`WrapErr`] trait.
-//! Additionally, [`WrapFailure`] is also implemented for [`Result<T, E>`].
-//! Lastly, to stay in line with behavior from [`miette`], the [`WrapFailure`]
-//! trait is *also* sealed.
+//! This module re-exports `miette`'s [`Report`] as well as [`Result`], and the
+//! [`WrapFailure`] trait, as a mirror to the [`WrapErr`] trait. Additionally,
+//! [`WrapFailure`] is also implemented for [`Result<T, E>`]. Lastly, to stay
+//! in line with behavior from [`miette`], the [`WrapFailure`] trait is *also*
+//! sealed. These traits are shared with the `report` and `anyhow` modules
+//! (see the [`backend`](crate::backend) module).
+//!
+//! Notes attached with the [`Section`] trait are shown as the help text of the
+//! [`Report`]:
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::diagnostic::{Report, Section};
+//!
+//! let x: Outcome<(), (), Report> = Failure(Report::msg("connection refused"));
+//! let x: Outcome<(), (), Report> = x.attach_note("Is the server running?");
+//! let report = x.unwrap_failure();
+//! assert_eq!(report.to_string(), "connection refused");
+//! assert_eq!(report.help().unwrap().to_string(), "Is the server running?");
+//! ```
+//!
+//! Several failures can be joined into a single [`Report`] with
+//! [`aggregate_reports`], which exposes each failure as a related
+//! diagnostic.
+//!
+//! A function returning an `Outcome` can exit early with a new [`Report`]
+//! through the [`fail!`] and [`retry!`] macros, which produce a [`Failure`]
+//! and a [`Mistake`] respectively. The [`ensure!`] and [`ensure_or_mistake!`]
+//! macros do the same when a condition does not hold.
+//!
+//! Mistakes can be given context as well, with the [`WrapMistake`] trait:
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use miette::MietteDiagnostic;
+//! use outcome::diagnostic::{Report, WrapMistake};
+//!
+//! let x: Aberration<MietteDiagnostic, ()> =
+//!   Aberration::Mistake(MietteDiagnostic::new("connection refused"));
+//! let x: Aberration<Report, ()> =
+//!   x.wrap_mistake_with(|| "Failed to reach server");
+//! assert_eq!(x.unwrap_mistake().to_string(), "Failed to reach server");
+//! ```
+//!
+//! An [`Aberration`] whose values are both diagnostics is itself a
+//! [`Diagnostic`], reporting a [`Mistake`] as a warning and a [`Failure`] as
+//! an error, unless the contained diagnostic specifies its own severity.
 //!
 //! [`WrapErr`]: miette::WrapErr
 //! [`miette`]: https://crates.io/crates/miette
+//! [`Diagnostic`]: miette::Diagnostic
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Mistake`]: crate::prelude::Aberration::Mistake
+//! [`Failure`]: crate::prelude::Aberration::Failure
 extern crate std;
 
 use crate::prelude::*;
-use miette::Diagnostic;
-use std::fmt::Display;
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+use std::{boxed::Box, fmt::Display};
 
+#[doc(no_inline)]
+pub use crate::backend::{Section, WrapFailure, WrapMistake};
 #[doc(no_inline)]
 pub use miette::{Report, Result};
 
-crate::wrap::r#trait!(Diagnostic);
-crate::wrap::r#impl!(Diagnostic);
-crate::wrap::result!(miette);
+crate::wrap::aggregate!(diagnostic);
+
+/// Each joined failure is exposed as a related diagnostic.
+impl Diagnostic for AggregateFailure {
+  fn related<'a>(
+    &'a self,
+  ) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+    Some(Box::new(self.failures.iter().map(AsRef::as_ref)))
+  }
+}
+
+/* Each method is forwarded to whichever diagnostic the aberration contains */
+macro_rules! forward {
+  ($self:ident.$method:ident()) => {
+    match $self {
+      Aberration::Mistake(value) => value.$method(),
+      Aberration::Failure(value) => value.$method(),
+    }
+  };
+}
+
+/// Forwards every method to the contained diagnostic.
+///
+/// If the contained diagnostic does not specify a [`Severity`], a
+/// [`Mistake`] is reported as a [`Severity::Warning`], and a [`Failure`] as a
+/// [`Severity::Error`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use miette::{Diagnostic, MietteDiagnostic, Severity};
+///
+/// let x: Aberration<MietteDiagnostic, MietteDiagnostic> =
+///   Aberration::Mistake(MietteDiagnostic::new("disk is almost full"));
+/// assert_eq!(Diagnostic::severity(&x), Some(Severity::Warning));
+///
+/// let x: Aberration<MietteDiagnostic, MietteDiagnostic> =
+///   Aberration::Failure(MietteDiagnostic::new("disk is full"));
+/// assert_eq!(Diagnostic::severity(&x), Some(Severity::Error));
+///
+/// let x: Aberration<MietteDiagnostic, MietteDiagnostic> = Aberration::Mistake(
+///   MietteDiagnostic::new("disk is full").with_severity(Severity::Advice),
+/// );
+/// assert_eq!(Diagnostic::severity(&x), Some(Severity::Advice));
+/// ```
+///
+/// [`Severity`]: miette::Severity
+/// [`Severity::Warning`]: miette::Severity::Warning
+/// [`Severity::Error`]: miette::Severity::Error
+/// [`Mistake`]: crate::prelude::Aberration::Mistake
+/// [`Failure`]: crate::prelude::Aberration::Failure
+impl<M: Diagnostic, F: Diagnostic> Diagnostic for Aberration<M, F> {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    forward!(self.code())
+  }
+
+  fn severity(&self) -> Option<Severity> {
+    match self {
+      Self::Mistake(value) => value.severity().or(Some(Severity::Warning)),
+      Self::Failure(value) => value.severity().or(Some(Severity::Error)),
+    }
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    forward!(self.help())
+  }
+
+  fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    forward!(self.url())
+  }
+
+  fn source_code(&self) -> Option<&dyn SourceCode> {
+    forward!(self.source_code())
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+    forward!(self.labels())
+  }
+
+  fn related<'a>(
+    &'a self,
+  ) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+    forward!(self.related())
+  }
+
+  fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+    forward!(self.diagnostic_source())
+  }
+}
+
+/// Returns a [`Failure`] from the enclosing function, holding a [`Report`].
+///
+/// This is the `outcome` analogue of [`miette::bail!`], and takes the same
+/// arguments as [`miette::miette!`]. The [`Report`] is converted with [`From`],
+/// so the function must return an `Outcome<_, _, Report>`, or any failure type
+/// that a [`Report`] converts into. The registered failure hook is invoked, as
+/// with [`Outcome::failure_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::diagnostic::{fail, Report};
+///
+/// fn parse(input: &str) -> Outcome<u32, (), Report> {
+///   if input.is_empty() {
+///     fail!("no input given");
+///   }
+///   match input.parse() {
+///     Ok(value) => Success(value),
+///     Err(error) => fail!("invalid input {input:?}: {error}"),
+///   }
+/// }
+///
+/// assert_eq!(parse("47").unwrap(), 47);
+/// assert_eq!(parse("").unwrap_failure().to_string(), "no input given");
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`miette::bail!`]: ::miette::bail
+/// [`miette::miette!`]: ::miette::miette
+/// [`From`]: core::convert::From
+/// [`Outcome::failure_hooked`]: crate::prelude::Outcome::failure_hooked
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diagnostic_fail {
+  ($($arguments:tt)+) => {
+    return $crate::__private::failure(::core::convert::From::from(
+      $crate::__private::contextualize(
+        $crate::__private::miette::miette!($($arguments)+),
+      ),
+    ))
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function, holding a [`Report`].
+///
+/// This is [`fail!`], for conditions that are worth retrying. The [`Report`]
+/// is converted with [`From`], so the function must return an
+/// `Outcome<_, Report, _>`, or any mistake type that a [`Report`] converts
+/// into. The registered mistake hook is invoked, as with
+/// [`Outcome::mistake_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::diagnostic::{retry, Report};
+///
+/// fn acquire(locked: bool) -> Outcome<(), Report, Report> {
+///   if locked {
+///     retry!("resource is locked");
+///   }
+///   Success(())
+/// }
+///
+/// assert!(acquire(false).is_success());
+/// assert_eq!(acquire(true).unwrap_mistake().to_string(), "resource is locked");
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`fail!`]: crate::diagnostic::fail
+/// [`From`]: core::convert::From
+/// [`Outcome::mistake_hooked`]: crate::prelude::Outcome::mistake_hooked
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diagnostic_retry {
+  ($($arguments:tt)+) => {
+    return $crate::__private::mistake(::core::convert::From::from(
+      $crate::__private::contextualize(
+        $crate::__private::miette::miette!($($arguments)+),
+      ),
+    ))
+  };
+}
+
+/// Returns a [`Failure`] from the enclosing function, holding a [`Report`], if
+/// a condition does not hold.
+///
+/// This is the `outcome` analogue of [`miette::ensure!`]. Without a message,
+/// the [`Report`] describes the condition that failed.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::diagnostic::{ensure, Report};
+///
+/// fn resize(width: u32, height: u32) -> Outcome<u32, (), Report> {
+///   ensure!(width > 0);
+///   ensure!(height <= 4096, "height {height} is too large");
+///   Success(width * height)
+/// }
+///
+/// assert_eq!(resize(2, 3).unwrap(), 6);
+/// let failure = resize(0, 3).unwrap_failure();
+/// assert_eq!(failure.to_string(), "Condition failed: `width > 0`");
+/// let failure = resize(2, 8192).unwrap_failure();
+/// assert_eq!(failure.to_string(), "height 8192 is too large");
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`miette::ensure!`]: ::miette::ensure
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diagnostic_ensure {
+  ($condition:expr $(,)?) => {
+    if !$condition {
+      $crate::__diagnostic_fail!(::core::concat!(
+        "Condition failed: `",
+        ::core::stringify!($condition),
+        "`",
+      ));
+    }
+  };
+  ($condition:expr, $($arguments:tt)+) => {
+    if !$condition {
+      $crate::__diagnostic_fail!($($arguments)+);
+    }
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function, holding a [`Report`], if
+/// a condition does not hold.
+///
+/// This is [`ensure!`], for conditions that are worth retrying.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::diagnostic::{ensure_or_mistake, Report};
+///
+/// fn poll(ready: bool) -> Outcome<(), Report, ()> {
+///   ensure_or_mistake!(ready, "device is not ready");
+///   Success(())
+/// }
+///
+/// assert!(poll(true).is_success());
+/// assert_eq!(poll(false).unwrap_mistake().to_string(), "device is not ready");
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`ensure!`]: crate::diagnostic::ensure
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diagnostic_ensure_or_mistake {
+  ($condition:expr $(,)?) => {
+    if !$condition {
+      $crate::__diagnostic_retry!(::core::concat!(
+        "Condition failed: `",
+        ::core::stringify!($condition),
+        "`",
+      ));
+    }
+  };
+  ($condition:expr, $($arguments:tt)+) => {
+    if !$condition {
+      $crate::__diagnostic_retry!($($arguments)+);
+    }
+  };
+}
+
+#[doc(inline)]
+pub use crate::{
+  __diagnostic_ensure as ensure,
+  __diagnostic_ensure_or_mistake as ensure_or_mistake,
+  __diagnostic_fail as fail, __diagnostic_retry as retry,
+};
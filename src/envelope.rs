@@ -0,0 +1,184 @@
+//! A [`serde`] representation of [`Outcome`] for web APIs.
+//!
+//! Many REST APIs wrap every response in a "status envelope", where a `status`
+//! field describes whether the request succeeded, and the payload is found in
+//! either a `data` or `detail` field. This module maps an [`Outcome`] onto
+//! that format:
+//!
+//! | Variant      | Representation                                  |
+//! |--------------|-------------------------------------------------|
+//! | [`Success`]  | `{ "status": "success", "data": ... }`          |
+//! | [`Mistake`]  | `{ "status": "warning", "detail": ... }`        |
+//! | [`Failure`]  | `{ "status": "error", "detail": ... }`          |
+//!
+//! The module can be used with `#[serde(with = "outcome::envelope")]` on a
+//! field, or the [`Envelope`] wrapper can be serialized directly.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::envelope::Envelope;
+//!
+//! let response: Envelope<u32, &str, &str> = Envelope(Mistake("rate limited"));
+//! let json = serde_json::to_string(&response)?;
+//! assert_eq!(json, r#"{"status":"warning","detail":"rate limited"}"#);
+//!
+//! let response: Envelope<u32, String, String> =
+//!   serde_json::from_str(r#"{"data":47,"status":"success"}"#)?;
+//! assert_eq!(response.0, Success(47));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`serde`]: https://serde.rs
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status")]
+enum Repr<S, M, F> {
+  #[serde(rename = "success")]
+  Success { data: S },
+  #[serde(rename = "warning")]
+  Mistake { detail: M },
+  #[serde(rename = "error")]
+  Failure { detail: F },
+}
+
+/// Serializes `outcome` as a status envelope.
+///
+/// # Errors
+///
+/// Returns an error if the contained value fails to serialize.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Response {
+///   #[serde(with = "outcome::envelope")]
+///   result: Outcome<u32, u32, u32>,
+/// }
+///
+/// for result in [Success(1), Mistake(2), Failure(3)] {
+///   let response = Response { result };
+///   let json = serde_json::to_string(&response)?;
+///   assert_eq!(serde_json::from_str::<Response>(&json)?, response);
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn serialize<S, M, F, Z>(
+  outcome: &Outcome<S, M, F>,
+  serializer: Z,
+) -> Result<Z::Ok, Z::Error>
+where
+  S: Serialize,
+  M: Serialize,
+  F: Serialize,
+  Z: Serializer,
+{
+  match outcome {
+    Success(data) => Repr::<_, (), ()>::Success { data }.serialize(serializer),
+    Mistake(detail) => {
+      Repr::<(), _, ()>::Mistake { detail }.serialize(serializer)
+    }
+    Failure(detail) => {
+      Repr::<(), (), _>::Failure { detail }.serialize(serializer)
+    }
+  }
+}
+
+/// Deserializes an [`Outcome`] from a status envelope.
+///
+/// # Errors
+///
+/// Returns an error if the `status` field is missing or unknown, or if the
+/// payload fails to deserialize.
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::envelope::Envelope;
+///
+/// let json = r#"{ "status": "pending" }"#;
+/// assert!(serde_json::from_str::<Envelope<(), (), ()>>(json).is_err());
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+pub fn deserialize<'de, S, M, F, D>(
+  deserializer: D,
+) -> Result<Outcome<S, M, F>, D::Error>
+where
+  S: Deserialize<'de>,
+  M: Deserialize<'de>,
+  F: Deserialize<'de>,
+  D: Deserializer<'de>,
+{
+  Ok(match Repr::deserialize(deserializer)? {
+    Repr::Success { data } => Success(data),
+    Repr::Mistake { detail } => Mistake(detail),
+    Repr::Failure { detail } => Failure(detail),
+  })
+}
+
+/// An [`Outcome`] that is (de)serialized as a status envelope.
+///
+/// See the [module documentation](crate::envelope) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::envelope::Envelope;
+/// use serde_json::json;
+///
+/// let json = serde_json::to_value(Envelope::<(), (), _>(Failure("down")))?;
+/// assert_eq!(json, json!({ "status": "error", "detail": "down" }));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Envelope<S, M, F>(pub Outcome<S, M, F>);
+
+impl<S, M, F> From<Outcome<S, M, F>> for Envelope<S, M, F> {
+  #[inline]
+  fn from(outcome: Outcome<S, M, F>) -> Self {
+    Self(outcome)
+  }
+}
+
+impl<S, M, F> From<Envelope<S, M, F>> for Outcome<S, M, F> {
+  #[inline]
+  fn from(envelope: Envelope<S, M, F>) -> Self {
+    envelope.0
+  }
+}
+
+impl<S: Serialize, M: Serialize, F: Serialize> Serialize for Envelope<S, M, F> {
+  #[inline]
+  fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+    serialize(&self.0, serializer)
+  }
+}
+
+impl<'de, S, M, F> Deserialize<'de> for Envelope<S, M, F>
+where
+  S: Deserialize<'de>,
+  M: Deserialize<'de>,
+  F: Deserialize<'de>,
+{
+  #[inline]
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserialize(deserializer).map(Self)
+  }
+}
@@ -0,0 +1,314 @@
+//! Extension traits for classifying errors from other libraries.
+//!
+//! Many error types already know whether the operation that produced them is
+//! worth retrying (a timeout, a conflict, a rate limit, etc.), but a
+//! [`Result`] cannot express this. The [`Classify`] trait lets an error type
+//! describe whether it is a [`Mistake`] or a [`Failure`], and
+//! [`ResultExt::into_outcome`] uses it to convert a [`Result`] into an
+//! [`Outcome`].
+//!
+//! When the `std` feature is enabled, [`Classify`] is implemented for
+//! [`io::Error`], treating [`WouldBlock`], [`Interrupted`], and [`TimedOut`]
+//! errors as a [`Mistake`]. It is also implemented for [`VarError`], treating
+//! a missing variable as a [`Mistake`], and for [`TryRecvError`], treating an
+//! empty channel as a [`Mistake`]. A [`Result`] holding any of these errors
+//! can also be converted into an [`Outcome`] with [`From`]. `outcome` also
+//! implements [`Classify`] for several third party error types behind
+//! optional features.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::ext::{Classify, ResultExt};
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum FetchError {
+//!   Busy,
+//!   NotFound,
+//! }
+//!
+//! impl Classify for FetchError {
+//!   fn is_mistake(&self) -> bool {
+//!     matches!(self, Self::Busy)
+//!   }
+//! }
+//!
+//! let x: Result<u32, FetchError> = Err(FetchError::Busy);
+//! assert_eq!(x.into_outcome(), Mistake(FetchError::Busy));
+//!
+//! let x: Result<u32, FetchError> = Err(FetchError::NotFound);
+//! assert_eq!(x.into_outcome(), Failure(FetchError::NotFound));
+//! ```
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+//! [`Result`]: core::result::Result
+//! [`io::Error`]: std::io::Error
+//! [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+//! [`Interrupted`]: std::io::ErrorKind::Interrupted
+//! [`TimedOut`]: std::io::ErrorKind::TimedOut
+//! [`VarError`]: std::env::VarError
+//! [`TryRecvError`]: std::sync::mpsc::TryRecvError
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::{
+  env::VarError,
+  io::{Error, ErrorKind},
+  sync::mpsc::TryRecvError,
+};
+
+#[cfg(feature = "alloc")]
+use crate::classify::ClassificationTable;
+use crate::prelude::*;
+
+/// Describes whether an error should be treated as a [`Mistake`] or a
+/// [`Failure`].
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+pub trait Classify {
+  /// Returns `true` if the operation that produced this error may be retried.
+  fn is_mistake(&self) -> bool;
+
+  /// Returns the name of this error's kind, if it has one.
+  ///
+  /// This is used by a [`ClassificationTable`] to match errors by kind.
+  ///
+  /// [`ClassificationTable`]: crate::classify::ClassificationTable
+  #[inline]
+  fn kind(&self) -> Option<&str> {
+    None
+  }
+
+  /// Returns the numeric code of this error, such as an HTTP status or an OS
+  /// error number, if it has one.
+  ///
+  /// This is used by a [`ClassificationTable`] to match errors by code.
+  ///
+  /// [`ClassificationTable`]: crate::classify::ClassificationTable
+  #[inline]
+  fn code(&self) -> Option<i64> {
+    None
+  }
+
+  /// Converts this error into an [`Aberration`], according to
+  /// [`is_mistake`](Classify::is_mistake).
+  ///
+  /// [`Aberration`]: crate::prelude::Aberration
+  #[inline]
+  fn classify(self) -> Aberration<Self, Self>
+  where
+    Self: Sized,
+  {
+    if self.is_mistake() {
+      Aberration::Mistake(self)
+    } else {
+      Aberration::Failure(self)
+    }
+  }
+}
+
+/// Extension trait for converting a [`Result`] into an [`Outcome`].
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Result`]: core::result::Result
+pub trait ResultExt<T, E>: crate::private::Sealed {
+  /// Converts this [`Result`] into an [`Outcome`], using [`Classify`] to
+  /// decide whether an error is a [`Mistake`] or a [`Failure`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  /// [`Result`]: core::result::Result
+  fn into_outcome(self) -> Outcome<T, E, E>
+  where
+    E: Classify;
+
+  /// Converts this [`Result`] into an [`Outcome`], consulting `table` before
+  /// falling back to [`Classify`].
+  ///
+  /// See [`ClassificationTable`] for details.
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Result`]: core::result::Result
+  /// [`ClassificationTable`]: crate::classify::ClassificationTable
+  #[cfg(feature = "alloc")]
+  fn into_outcome_with(self, table: &ClassificationTable) -> Outcome<T, E, E>
+  where
+    E: Classify + Display;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+  #[inline]
+  fn into_outcome(self) -> Outcome<T, E, E>
+  where
+    E: Classify,
+  {
+    match self {
+      Ok(value) => Success(value),
+      Err(error) => match error.classify() {
+        Aberration::Mistake(m) => Mistake(m),
+        Aberration::Failure(f) => Failure(f),
+      },
+    }
+  }
+
+  #[cfg(feature = "alloc")]
+  #[inline]
+  fn into_outcome_with(self, table: &ClassificationTable) -> Outcome<T, E, E>
+  where
+    E: Classify + Display,
+  {
+    match self {
+      Ok(value) => Success(value),
+      Err(error) if table.is_mistake(&error) => Mistake(error),
+      Err(error) => Failure(error),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl Classify for Error {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    matches!(
+      self.kind(),
+      ErrorKind::WouldBlock | ErrorKind::Interrupted | ErrorKind::TimedOut
+    )
+  }
+
+  fn kind(&self) -> Option<&str> {
+    Some(match self.kind() {
+      ErrorKind::NotFound => "NotFound",
+      ErrorKind::PermissionDenied => "PermissionDenied",
+      ErrorKind::ConnectionRefused => "ConnectionRefused",
+      ErrorKind::ConnectionReset => "ConnectionReset",
+      ErrorKind::ConnectionAborted => "ConnectionAborted",
+      ErrorKind::NotConnected => "NotConnected",
+      ErrorKind::AddrInUse => "AddrInUse",
+      ErrorKind::AddrNotAvailable => "AddrNotAvailable",
+      ErrorKind::BrokenPipe => "BrokenPipe",
+      ErrorKind::AlreadyExists => "AlreadyExists",
+      ErrorKind::WouldBlock => "WouldBlock",
+      ErrorKind::InvalidInput => "InvalidInput",
+      ErrorKind::InvalidData => "InvalidData",
+      ErrorKind::TimedOut => "TimedOut",
+      ErrorKind::WriteZero => "WriteZero",
+      ErrorKind::Interrupted => "Interrupted",
+      ErrorKind::Unsupported => "Unsupported",
+      ErrorKind::UnexpectedEof => "UnexpectedEof",
+      ErrorKind::OutOfMemory => "OutOfMemory",
+      ErrorKind::Other => "Other",
+      _ => return None,
+    })
+  }
+
+  #[inline]
+  fn code(&self) -> Option<i64> {
+    self.raw_os_error().map(i64::from)
+  }
+}
+
+#[cfg(feature = "std")]
+impl Classify for VarError {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    matches!(self, Self::NotPresent)
+  }
+
+  fn kind(&self) -> Option<&str> {
+    Some(match self {
+      Self::NotPresent => "NotPresent",
+      Self::NotUnicode(_) => "NotUnicode",
+    })
+  }
+}
+
+#[cfg(feature = "std")]
+impl Classify for TryRecvError {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    matches!(self, Self::Empty)
+  }
+
+  fn kind(&self) -> Option<&str> {
+    Some(match self {
+      Self::Empty => "Empty",
+      Self::Disconnected => "Disconnected",
+    })
+  }
+}
+
+/// Converts the result of [`io`] operations with [`ResultExt::into_outcome`].
+///
+/// [`io`]: std::io
+#[cfg(feature = "std")]
+impl<T> From<Result<T, Error>> for Outcome<T, Error, Error> {
+  #[inline]
+  fn from(result: Result<T, Error>) -> Self {
+    result.into_outcome()
+  }
+}
+
+/// Converts the result of [`env::var`] with [`ResultExt::into_outcome`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use std::env::{self, VarError};
+///
+/// let x = Outcome::from(env::var("OUTCOME_DOES_NOT_EXIST"));
+/// assert_eq!(x, Mistake(VarError::NotPresent));
+/// ```
+///
+/// [`env::var`]: std::env::var
+#[cfg(feature = "std")]
+impl<T> From<Result<T, VarError>> for Outcome<T, VarError, VarError> {
+  #[inline]
+  fn from(result: Result<T, VarError>) -> Self {
+    result.into_outcome()
+  }
+}
+
+/// Converts the result of [`Receiver::try_recv`] with
+/// [`ResultExt::into_outcome`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use std::sync::mpsc::{channel, TryRecvError};
+///
+/// let (tx, rx) = channel::<u32>();
+/// assert_eq!(Outcome::from(rx.try_recv()), Mistake(TryRecvError::Empty));
+///
+/// tx.send(47).unwrap();
+/// assert_eq!(Outcome::from(rx.try_recv()), Success(47));
+///
+/// drop(tx);
+/// assert_eq!(
+///   Outcome::from(rx.try_recv()),
+///   Failure(TryRecvError::Disconnected)
+/// );
+/// ```
+///
+/// [`Receiver::try_recv`]: std::sync::mpsc::Receiver::try_recv
+#[cfg(feature = "std")]
+impl<T> From<Result<T, TryRecvError>>
+  for Outcome<T, TryRecvError, TryRecvError>
+{
+  #[inline]
+  fn from(result: Result<T, TryRecvError>) -> Self {
+    result.into_outcome()
+  }
+}
@@ -0,0 +1,264 @@
+//! Typed extensions attached to failures.
+//!
+//! Middleware often wants to decorate a failure with additional data, such as
+//! a request ID, the time an operation took, or a hint for when to retry.
+//! Adding a field for each of these to every failure type is impractical.
+//! Instead, [`Outcome::attach`] wraps a [`Failure`] in an [`Extended`], which
+//! stores any number of values keyed by their type. These values can later be
+//! retrieved with [`Outcome::failure_extension`] or [`Extended::get`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! #[derive(Debug, PartialEq)]
+//! struct RequestId(u64);
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Attempts(u32);
+//!
+//! let x: Outcome<(), (), &str> = Failure("connection reset");
+//! let x = x
+//!   .attach(RequestId(47))
+//!   .map_failure(|failure| failure.with(Attempts(3)));
+//!
+//! assert_eq!(x.failure_extension::<RequestId>(), Some(&RequestId(47)));
+//! assert_eq!(x.failure_extension::<Attempts>(), Some(&Attempts(3)));
+//! assert_eq!(x.failure_extension::<u8>(), None);
+//! assert_eq!(*x.unwrap_failure().error(), "connection reset");
+//! ```
+//!
+//! [`Outcome::attach`]: crate::prelude::Outcome::attach
+//! [`Outcome::failure_extension`]: crate::prelude::Outcome::failure_extension
+//! [`Failure`]: crate::prelude::Failure
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+  any::{Any, TypeId},
+  fmt::{self, Debug, Display, Formatter},
+};
+
+use crate::prelude::*;
+
+/// A map of values keyed by their type.
+///
+/// At most one value of each type can be stored at a time.
+#[derive(Default)]
+pub struct Extensions {
+  values: BTreeMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+  /// Creates an empty `Extensions`.
+  #[must_use]
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Inserts `value`, returning the previously stored value of the same type,
+  /// if any.
+  pub fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+    self
+      .values
+      .insert(TypeId::of::<T>(), Box::new(value))
+      .and_then(|previous| previous.downcast().ok())
+      .map(|previous| *previous)
+  }
+
+  /// Returns a reference to the stored value of type `T`, if any.
+  #[must_use]
+  pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+    self
+      .values
+      .get(&TypeId::of::<T>())
+      .and_then(|value| value.downcast_ref())
+  }
+
+  /// Returns a mutable reference to the stored value of type `T`, if any.
+  #[must_use]
+  pub fn get_mut<T: Any + Send + Sync>(&mut self) -> Option<&mut T> {
+    self
+      .values
+      .get_mut(&TypeId::of::<T>())
+      .and_then(|value| value.downcast_mut())
+  }
+
+  /// Removes and returns the stored value of type `T`, if any.
+  pub fn remove<T: Any + Send + Sync>(&mut self) -> Option<T> {
+    self
+      .values
+      .remove(&TypeId::of::<T>())
+      .and_then(|value| value.downcast().ok())
+      .map(|value| *value)
+  }
+
+  /// Returns `true` if a value of type `T` is stored.
+  #[must_use]
+  #[inline]
+  pub fn contains<T: Any + Send + Sync>(&self) -> bool {
+    self.values.contains_key(&TypeId::of::<T>())
+  }
+
+  /// Returns the number of stored values.
+  #[must_use]
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.values.len()
+  }
+
+  /// Returns `true` if no values are stored.
+  #[must_use]
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.values.is_empty()
+  }
+}
+
+impl Debug for Extensions {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Extensions")
+      .field("len", &self.values.len())
+      .finish_non_exhaustive()
+  }
+}
+
+/// An error paired with a set of typed [`Extensions`].
+///
+/// `Extended` is transparent: it displays as the wrapped error, and forwards
+/// [`Error::source`] to it.
+///
+/// [`Error::source`]: std::error::Error::source
+#[derive(Debug)]
+pub struct Extended<E> {
+  error: E,
+  extensions: Extensions,
+}
+
+impl<E> Extended<E> {
+  /// Wraps `error` with an empty set of extensions.
+  #[must_use]
+  #[inline]
+  pub fn new(error: E) -> Self {
+    Self {
+      error,
+      extensions: Extensions::new(),
+    }
+  }
+
+  /// Inserts `value` into the extensions, replacing any value of the same
+  /// type, and returns `self`.
+  #[must_use]
+  pub fn with<T: Any + Send + Sync>(mut self, value: T) -> Self {
+    self.extensions.insert(value);
+    self
+  }
+
+  /// Returns a reference to the stored value of type `T`, if any.
+  #[must_use]
+  #[inline]
+  pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+    self.extensions.get()
+  }
+
+  /// Returns a reference to the error.
+  #[must_use]
+  #[inline]
+  pub fn error(&self) -> &E {
+    &self.error
+  }
+
+  /// Returns a reference to the extensions.
+  #[must_use]
+  #[inline]
+  pub fn extensions(&self) -> &Extensions {
+    &self.extensions
+  }
+
+  /// Returns a mutable reference to the extensions.
+  #[must_use]
+  #[inline]
+  pub fn extensions_mut(&mut self) -> &mut Extensions {
+    &mut self.extensions
+  }
+
+  /// Consumes the `Extended`, returning the error.
+  #[inline]
+  pub fn into_error(self) -> E {
+    self.error
+  }
+
+  /// Consumes the `Extended`, returning the error and its extensions.
+  #[inline]
+  pub fn into_parts(self) -> (E, Extensions) {
+    (self.error, self.extensions)
+  }
+}
+
+impl<E: Display> Display for Extended<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    Display::fmt(&self.error, f)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> std::error::Error for Extended<E> {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    self.error.source()
+  }
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Wraps a [`Failure`] in an [`Extended`], attaching `value` to it.
+  ///
+  /// Further values can be attached with [`Extended::with`].
+  ///
+  /// See the [`extensions`](crate::extensions) module for details.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn attach<T: Any + Send + Sync>(
+    self,
+    value: T,
+  ) -> Outcome<S, M, Extended<F>> {
+    self.map_failure(|failure| Extended::new(failure).with(value))
+  }
+}
+
+impl<S, M, F> Outcome<S, M, Extended<F>> {
+  /// Returns the value of type `T` attached to a [`Failure`], if any.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn failure_extension<T: Any + Send + Sync>(&self) -> Option<&T> {
+    match self {
+      Failure(failure) => failure.get(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn insert_replaces() {
+    let mut extensions = Extensions::new();
+    assert_eq!(extensions.insert(1u8), None);
+    assert_eq!(extensions.insert(2u8), Some(1));
+    assert_eq!(extensions.insert("x"), None);
+    assert_eq!(extensions.len(), 2);
+    assert_eq!(extensions.remove::<u8>(), Some(2));
+    assert!(!extensions.contains::<u8>());
+  }
+
+  #[test]
+  fn success_has_no_extensions() {
+    let x: Outcome<(), (), ()> = Success(());
+    assert_eq!(x.attach(1u8).failure_extension::<u8>(), None);
+  }
+}
@@ -0,0 +1,108 @@
+//! Support for exposing outcomes through [`UniFFI`] generated bindings.
+//!
+//! `UniFFI` cannot export generic types, so an [`Outcome`] cannot be handed to
+//! foreign code as is. Instead, the [`uniffi_outcome!`] macro declares a
+//! concrete enumeration with `Success`, `Mistake`, and `Failure` cases for a
+//! given set of types, and derives [`uniffi::Enum`] for it. This is exposed
+//! to Kotlin as a sealed class, and to Swift as an enum with associated
+//! values, allowing application code to distinguish retryable errors from
+//! fatal ones.
+//!
+//! [`From`] conversions are generated in both directions, so exported
+//! functions can continue to use [`Outcome`] internally.
+//!
+//! **NOTE**: The crate invoking [`uniffi_outcome!`] must depend on `uniffi`
+//! directly, and call [`uniffi::setup_scaffolding!`], as with any other type
+//! deriving [`uniffi::Enum`].
+//!
+//! # Examples
+//!
+//! ```
+//! use outcome::prelude::*;
+//! use outcome::uniffi_outcome;
+//!
+//! uniffi::setup_scaffolding!();
+//!
+//! uniffi_outcome! {
+//!   /// The result of a synchronization attempt
+//!   pub enum SyncOutcome<u64, String, String>;
+//! }
+//!
+//! #[uniffi::export]
+//! pub fn synchronize() -> SyncOutcome {
+//!   let outcome: Outcome<u64, String, String> = Mistake("offline".into());
+//!   outcome.into()
+//! }
+//!
+//! # fn main() {
+//! assert!(matches!(synchronize(), SyncOutcome::Mistake { .. }));
+//! # }
+//! ```
+//!
+//! [`UniFFI`]: https://mozilla.github.io/uniffi-rs/
+//! [`uniffi_outcome!`]: crate::uniffi_outcome
+//! [`Outcome`]: crate::prelude::Outcome
+
+/// Declares a `UniFFI` enumeration mirroring an [`Outcome`].
+///
+/// The declared type has the cases `Success { value }`, `Mistake { value }`,
+/// and `Failure { value }`, and can be converted to and from an
+/// `Outcome<S, M, F>` with [`From`].
+///
+/// See the [module documentation](crate::ffi) for details.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "uniffi")))]
+#[macro_export]
+macro_rules! uniffi_outcome {
+  (
+    $(#[$meta:meta])*
+    $vis:vis enum $name:ident<$success:ty, $mistake:ty, $failure:ty>;
+  ) => {
+    $(#[$meta])*
+    #[derive(::uniffi::Enum)]
+    $vis enum $name {
+      /// Contains the success value
+      Success {
+        /// The success value
+        value: $success,
+      },
+      /// Contains the mistake value
+      Mistake {
+        /// The mistake value
+        value: $mistake,
+      },
+      /// Contains the failure value
+      Failure {
+        /// The failure value
+        value: $failure,
+      },
+    }
+
+    impl ::core::convert::From<$crate::Outcome<$success, $mistake, $failure>>
+      for $name
+    {
+      #[inline]
+      fn from(outcome: $crate::Outcome<$success, $mistake, $failure>) -> Self {
+        match outcome {
+          $crate::Outcome::Success(value) => Self::Success { value },
+          $crate::Outcome::Mistake(value) => Self::Mistake { value },
+          $crate::Outcome::Failure(value) => Self::Failure { value },
+        }
+      }
+    }
+
+    impl ::core::convert::From<$name>
+      for $crate::Outcome<$success, $mistake, $failure>
+    {
+      #[inline]
+      fn from(value: $name) -> Self {
+        match value {
+          $name::Success { value } => Self::Success(value),
+          $name::Mistake { value } => Self::Mistake(value),
+          $name::Failure { value } => Self::Failure(value),
+        }
+      }
+    }
+  };
+}
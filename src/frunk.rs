@@ -0,0 +1,159 @@
+//! Integration with the [`frunk`] functional programming toolkit.
+//!
+//! [`Semigroup`] and [`Monoid`] are implemented for [`Outcome`], [`Concern`],
+//! and [`Aberration`], so that code written against these abstractions can
+//! accept them directly. Combining two values keeps the most severe variant,
+//! and combines the contained values when both share the same variant:
+//!
+//!  - Two [`Success`] values are combined into a [`Success`].
+//!  - A [`Mistake`] takes priority over a [`Success`], and two [`Mistake`]
+//!    values are combined.
+//!  - A [`Failure`] takes priority over everything else, and two [`Failure`]
+//!    values are combined.
+//!
+//! The identity of [`Outcome`] and [`Concern`] is a [`Success`] of the empty
+//! value, while the identity of [`Aberration`] is a [`Mistake`] of the empty
+//! value.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use frunk::{monoid::combine_all, Semigroup};
+//!
+//! let x: Outcome<u32, u32, u32> = Success(1);
+//! assert_eq!(x.combine(&Success(2)), Success(3));
+//! assert_eq!(x.combine(&Mistake(4)), Mistake(4));
+//!
+//! let outcomes: [Outcome<u32, u32, u32>; 4] =
+//!   [Success(1), Mistake(2), Failure(3), Failure(4)];
+//! assert_eq!(combine_all(&outcomes), Failure(7));
+//! ```
+//!
+//! [`frunk`]: https://docs.rs/frunk
+//! [`Semigroup`]: ::frunk::Semigroup
+//! [`Monoid`]: ::frunk::Monoid
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Concern`]: crate::prelude::Concern
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use ::frunk::{Monoid, Semigroup};
+
+use crate::prelude::*;
+
+impl<S, M, F> Semigroup for Outcome<S, M, F>
+where
+  S: Semigroup,
+  M: Semigroup + Clone,
+  F: Semigroup + Clone,
+{
+  fn combine(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Failure(left), Failure(right)) => Failure(left.combine(right)),
+      (Failure(value), _) | (_, Failure(value)) => Failure(value.clone()),
+      (Mistake(left), Mistake(right)) => Mistake(left.combine(right)),
+      (Mistake(value), _) | (_, Mistake(value)) => Mistake(value.clone()),
+      (Success(left), Success(right)) => Success(left.combine(right)),
+    }
+  }
+}
+
+impl<S, M, F> Monoid for Outcome<S, M, F>
+where
+  S: Monoid,
+  M: Semigroup + Clone,
+  F: Semigroup + Clone,
+{
+  #[inline]
+  fn empty() -> Self {
+    Success(S::empty())
+  }
+}
+
+impl<S, M> Semigroup for Concern<S, M>
+where
+  S: Semigroup,
+  M: Semigroup + Clone,
+{
+  fn combine(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Self::Mistake(left), Self::Mistake(right)) => {
+        Self::Mistake(left.combine(right))
+      }
+      (Self::Mistake(value), _) | (_, Self::Mistake(value)) => {
+        Self::Mistake(value.clone())
+      }
+      (Self::Success(left), Self::Success(right)) => {
+        Self::Success(left.combine(right))
+      }
+    }
+  }
+}
+
+impl<S, M> Monoid for Concern<S, M>
+where
+  S: Monoid,
+  M: Semigroup + Clone,
+{
+  #[inline]
+  fn empty() -> Self {
+    Self::Success(S::empty())
+  }
+}
+
+impl<M, F> Semigroup for Aberration<M, F>
+where
+  M: Semigroup,
+  F: Semigroup + Clone,
+{
+  fn combine(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Self::Failure(left), Self::Failure(right)) => {
+        Self::Failure(left.combine(right))
+      }
+      (Self::Failure(value), _) | (_, Self::Failure(value)) => {
+        Self::Failure(value.clone())
+      }
+      (Self::Mistake(left), Self::Mistake(right)) => {
+        Self::Mistake(left.combine(right))
+      }
+    }
+  }
+}
+
+impl<M, F> Monoid for Aberration<M, F>
+where
+  M: Monoid,
+  F: Semigroup + Clone,
+{
+  #[inline]
+  fn empty() -> Self {
+    Self::Mistake(M::empty())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn identity() {
+    let values: [Outcome<u8, u8, u8>; 3] = [Success(1), Mistake(2), Failure(3)];
+    for value in values {
+      assert_eq!(Outcome::empty().combine(&value), value);
+      assert_eq!(value.combine(&Outcome::empty()), value);
+    }
+  }
+
+  #[test]
+  fn severity() {
+    let x: Concern<u8, u8> = Concern::Success(1);
+    assert_eq!(x.combine(&Concern::Mistake(2)), Concern::Mistake(2));
+
+    let x: Aberration<u8, u8> = Aberration::Mistake(1);
+    assert_eq!(x.combine(&Aberration::Mistake(2)), Aberration::Mistake(3));
+    assert_eq!(x.combine(&Aberration::Failure(2)), Aberration::Failure(2));
+  }
+}
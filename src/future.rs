@@ -0,0 +1,504 @@
+//! Support for asynchronous code producing an [`Outcome`].
+//!
+//! Everything in this module is built on [`core::future`] and
+//! [`futures-core`], and does not require `std` or an allocator. This allows
+//! embedded executors (such as [Embassy] or [RTIC]) to drive the futures and
+//! streams found within, as well as any retry machinery built on top of them.
+//! The only exception is [`collect_unordered_outcomes`], which requires the
+//! `alloc` feature.
+//!
+//! The [`OutcomeFuture`] and [`OutcomeStream`] traits are the [`Outcome`]
+//! equivalents of [`TryFuture`] and [`TryStream`]. They are implemented for
+//! every [`Future`] and [`Stream`] yielding an [`Outcome`], and are used to
+//! name the [`Success`], [`Mistake`], and [`Failure`] types of such a future
+//! in generic code. The [`OutcomeFutureExt`] trait provides combinators for
+//! such futures, much like `TryFutureExt`.
+//!
+//! When an [`Outcome`] is already in hand, but the continuation is
+//! asynchronous, [`Outcome::map_async`] and [`Outcome::and_then_async`] can be
+//! used in place of their synchronous counterparts.
+//!
+//! Waiting is abstracted by the [`Sleeper`] trait, so that no particular
+//! timer or runtime is assumed. Any closure returning a future from a
+//! [`Duration`] is a [`Sleeper`], which can then be used to retry
+//! asynchronous operations with [`retry_async`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use core::future::{ready, Future};
+//! use core::pin::pin;
+//! use core::task::{Context, Poll, Waker};
+//! use outcome::future::OutcomeFuture;
+//!
+//! fn poll_once<F: OutcomeFuture>(future: F) -> Poll<Outcome<F::Success, F::Mistake, F::Failure>> {
+//!   let mut cx = Context::from_waker(Waker::noop());
+//!   pin!(future).try_poll_outcome(&mut cx)
+//! }
+//!
+//! let future = ready(Mistake::<u32, _, ()>("busy"));
+//! assert_eq!(poll_once(future), Poll::Ready(Mistake("busy")));
+//! ```
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Outcome::map_async`]: crate::prelude::Outcome::map_async
+//! [`Outcome::and_then_async`]: crate::prelude::Outcome::and_then_async
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+//! [`retry_async`]: crate::retry::retry_async
+//! [`futures-core`]: https://crates.io/crates/futures-core
+//! [`TryFuture`]: futures_core::TryFuture
+//! [`TryStream`]: futures_core::TryStream
+//! [`Stream`]: futures_core::Stream
+//! [Embassy]: https://embassy.dev
+//! [RTIC]: https://rtic.rs
+use core::{
+  future::Future,
+  pin::Pin,
+  task::{Context, Poll},
+  time::Duration,
+};
+
+use futures_core::Stream;
+
+use crate::prelude::*;
+#[cfg(feature = "alloc")]
+use crate::Outcomes;
+
+/// A [`Future`] that resolves to an [`Outcome`].
+///
+/// This trait is implemented for every [`Future`] whose output is an
+/// [`Outcome`].
+///
+/// [`Outcome`]: crate::prelude::Outcome
+pub trait OutcomeFuture:
+  Future<Output = Outcome<Self::Success, Self::Mistake, Self::Failure>>
+{
+  /// The [`Success`] type of the resolved outcome
+  ///
+  /// [`Success`]: crate::prelude::Success
+  type Success;
+  /// The [`Mistake`] type of the resolved outcome
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  type Mistake;
+  /// The [`Failure`] type of the resolved outcome
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  type Failure;
+
+  /// Polls this future, as with [`Future::poll`], without requiring the
+  /// caller to name the [`Outcome`] type.
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  fn try_poll_outcome(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Outcome<Self::Success, Self::Mistake, Self::Failure>>;
+}
+
+/// A [`Stream`] of [`Outcome`]s.
+///
+/// This trait is implemented for every [`Stream`] whose items are an
+/// [`Outcome`].
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Stream`]: futures_core::Stream
+pub trait OutcomeStream:
+  Stream<Item = Outcome<Self::Success, Self::Mistake, Self::Failure>>
+{
+  /// The [`Success`] type of each item
+  ///
+  /// [`Success`]: crate::prelude::Success
+  type Success;
+  /// The [`Mistake`] type of each item
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  type Mistake;
+  /// The [`Failure`] type of each item
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  type Failure;
+
+  /// Polls this stream for its next item, as with [`Stream::poll_next`],
+  /// without requiring the caller to name the [`Outcome`] type.
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Stream::poll_next`]: futures_core::Stream::poll_next
+  #[allow(clippy::type_complexity)]
+  fn try_poll_next_outcome(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Outcome<Self::Success, Self::Mistake, Self::Failure>>>;
+}
+
+/// Adapters for an [`OutcomeFuture`], analogous to [`TryFutureExt`].
+///
+/// This trait is implemented for every [`OutcomeFuture`]. Each adapter
+/// returns a new future that applies a closure once the original future has
+/// resolved.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::future::ready;
+/// use futures::executor::block_on;
+/// use outcome::future::OutcomeFutureExt;
+///
+/// let future = ready(Success::<u32, &str, &str>(2))
+///   .map_success(|value| value * 2)
+///   .and_then(|value| ready(Mistake(if value > 3 { "too large" } else { "ok" })))
+///   .map_mistake(str::len);
+///
+/// assert_eq!(block_on(future), Mistake::<u32, _, &str>(9));
+/// ```
+///
+/// [`TryFutureExt`]: https://docs.rs/futures/latest/futures/future/trait.TryFutureExt.html
+pub trait OutcomeFutureExt: OutcomeFuture + Sized {
+  /// Maps the [`Success`] value of this future's outcome.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  fn map_success<T, C>(
+    self,
+    callable: C,
+  ) -> impl Future<Output = Outcome<T, Self::Mistake, Self::Failure>>
+  where
+    C: FnOnce(Self::Success) -> T,
+  {
+    async move { self.await.map(callable) }
+  }
+
+  /// Maps the [`Mistake`] value of this future's outcome.
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  fn map_mistake<N, C>(
+    self,
+    callable: C,
+  ) -> impl Future<Output = Outcome<Self::Success, N, Self::Failure>>
+  where
+    C: FnOnce(Self::Mistake) -> N,
+  {
+    async move { self.await.map_mistake(callable) }
+  }
+
+  /// Maps the [`Failure`] value of this future's outcome.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  fn map_failure<G, C>(
+    self,
+    callable: C,
+  ) -> impl Future<Output = Outcome<Self::Success, Self::Mistake, G>>
+  where
+    C: FnOnce(Self::Failure) -> G,
+  {
+    async move { self.await.map_failure(callable) }
+  }
+
+  /// Chains a computation onto a [`Success`] value of this future's outcome,
+  /// awaiting the future returned by `callable`.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  fn and_then<T, C, Fut>(
+    self,
+    callable: C,
+  ) -> impl Future<Output = Outcome<T, Self::Mistake, Self::Failure>>
+  where
+    C: FnOnce(Self::Success) -> Fut,
+    Fut: Future<Output = Outcome<T, Self::Mistake, Self::Failure>>,
+  {
+    async move { self.await.and_then_async(callable).await }
+  }
+
+  /// Chains a computation onto a [`Mistake`] or [`Failure`] value of this
+  /// future's outcome, awaiting the future returned by `callable`.
+  ///
+  /// This can be used to recover from an error, such as by retrying after a
+  /// [`Mistake`].
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::future::ready;
+  /// use futures::executor::block_on;
+  /// use outcome::future::OutcomeFutureExt;
+  ///
+  /// let future = ready(Mistake::<u32, &str, ()>("busy")).or_else(|error| {
+  ///   ready(match error {
+  ///     Aberration::Mistake(_) => Success(0),
+  ///     Aberration::Failure(f) => Failure::<u32, (), ()>(f),
+  ///   })
+  /// });
+  /// assert_eq!(block_on(future), Success(0));
+  /// ```
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn or_else<N, G, C, Fut>(
+    self,
+    callable: C,
+  ) -> impl Future<Output = Outcome<Self::Success, N, G>>
+  where
+    C: FnOnce(Aberration<Self::Mistake, Self::Failure>) -> Fut,
+    Fut: Future<Output = Outcome<Self::Success, N, G>>,
+  {
+    async move {
+      match self.await {
+        Success(value) => Success(value),
+        Mistake(value) => callable(Aberration::Mistake(value)).await,
+        Failure(value) => callable(Aberration::Failure(value)).await,
+      }
+    }
+  }
+}
+
+/// An asynchronous timer, used to wait between attempts.
+///
+/// Implementations are expected to return a future that completes once
+/// `duration` has elapsed. This trait is implemented for any closure
+/// returning a [`Future`] from a [`Duration`], which is usually the easiest
+/// way to adapt an existing runtime's timer.
+///
+/// # Examples
+///
+/// ```
+/// use core::future::{ready, Ready};
+/// use core::time::Duration;
+/// use outcome::future::Sleeper;
+///
+/// // A sleeper that never waits, useful in tests.
+/// struct Immediate;
+///
+/// impl Sleeper for Immediate {
+///   type Sleep = Ready<()>;
+///
+///   fn sleep(&self, _: Duration) -> Self::Sleep {
+///     ready(())
+///   }
+/// }
+///
+/// // A closure works just as well, e.g., `|d| tokio::time::sleep(d)`
+/// fn assert_sleeper<T: Sleeper>(_: T) {}
+/// assert_sleeper(Immediate);
+/// assert_sleeper(|_: Duration| ready(()));
+/// ```
+pub trait Sleeper {
+  /// The future returned by [`sleep`](Sleeper::sleep)
+  type Sleep: Future<Output = ()>;
+
+  /// Returns a future that completes after `duration` has elapsed.
+  fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+impl<S, M, F, T> OutcomeFuture for T
+where
+  T: Future<Output = Outcome<S, M, F>> + ?Sized,
+{
+  type Success = S;
+  type Mistake = M;
+  type Failure = F;
+
+  #[inline]
+  fn try_poll_outcome(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Outcome<S, M, F>> {
+    self.poll(cx)
+  }
+}
+
+impl<S, M, F, T> OutcomeStream for T
+where
+  T: Stream<Item = Outcome<S, M, F>> + ?Sized,
+{
+  type Success = S;
+  type Mistake = M;
+  type Failure = F;
+
+  #[inline]
+  fn try_poll_next_outcome(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Outcome<S, M, F>>> {
+    self.poll_next(cx)
+  }
+}
+
+impl<T: OutcomeFuture> OutcomeFutureExt for T {}
+
+impl<C, R> Sleeper for C
+where
+  C: Fn(Duration) -> R,
+  R: Future<Output = ()>,
+{
+  type Sleep = R;
+
+  #[inline]
+  fn sleep(&self, duration: Duration) -> R {
+    self(duration)
+  }
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Maps an `Outcome<S, M, F>` to `Outcome<T, M, F>` by awaiting the future
+  /// returned by `callable` on a contained [`Success`] value, leaving any
+  /// [`Mistake`] or [`Failure`] value untouched.
+  ///
+  /// This is the asynchronous equivalent of [`Outcome::map`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use futures::executor::block_on;
+  ///
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// let y = block_on(x.map_async(|value| async move { value * 2 }));
+  /// assert_eq!(y, Success(4));
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub async fn map_async<T, C, Fut>(self, callable: C) -> Outcome<T, M, F>
+  where
+    C: FnOnce(S) -> Fut,
+    Fut: Future<Output = T>,
+  {
+    match self {
+      Success(value) => Success(callable(value).await),
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Awaits the future returned by `callable` if the outcome is a
+  /// [`Success`], otherwise returns the [`Mistake`] or [`Failure`] value.
+  ///
+  /// This is the asynchronous equivalent of [`Outcome::and_then`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use futures::executor::block_on;
+  ///
+  /// async fn fetch(id: u32) -> Outcome<&'static str, &'static str, ()> {
+  ///   match id {
+  ///     0 => Success("root"),
+  ///     _ => Mistake("not cached"),
+  ///   }
+  /// }
+  ///
+  /// let x: Outcome<u32, &str, ()> = Success(1);
+  /// assert_eq!(block_on(x.and_then_async(fetch)), Mistake("not cached"));
+  ///
+  /// let x: Outcome<u32, &str, ()> = Failure(());
+  /// assert_eq!(block_on(x.and_then_async(fetch)), Failure(()));
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub async fn and_then_async<T, C, Fut>(self, callable: C) -> Outcome<T, M, F>
+  where
+    C: FnOnce(S) -> Fut,
+    Fut: Future<Output = Outcome<T, M, F>>,
+  {
+    match self {
+      Success(value) => callable(value).await,
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(value),
+    }
+  }
+}
+
+/// Determines when [`collect_unordered_outcomes`] stops driving its futures.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub enum CollectPolicy {
+  /// Every future is driven to completion.
+  #[default]
+  All,
+  /// Once any future resolves to a [`Failure`], the remaining futures are
+  /// dropped without being completed.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  StopOnFailure,
+}
+
+/// Drives `futures` concurrently, collecting their [`Outcome`]s in the order
+/// they complete.
+///
+/// This is the fan-out/fan-in pattern: many independent requests are started
+/// at once, and every result is gathered into an [`Outcomes`]. Depending on
+/// `policy`, the first [`Failure`] may cancel the futures that are still
+/// pending.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::future::ready;
+/// use futures::executor::block_on;
+/// use outcome::future::{collect_unordered_outcomes, CollectPolicy};
+///
+/// let requests = (0..5).map(|n| {
+///   ready(match n {
+///     2 => Mistake("rate limited"),
+///     _ => Success::<u32, _, ()>(n),
+///   })
+/// });
+///
+/// let outcomes = block_on(collect_unordered_outcomes(requests, CollectPolicy::All));
+/// assert_eq!(outcomes.successes.len(), 4);
+/// assert_eq!(outcomes.mistakes, ["rate limited"]);
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Outcomes`]: crate::Outcomes
+/// [`Failure`]: crate::prelude::Failure
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub async fn collect_unordered_outcomes<I, S, M, F>(
+  futures: I,
+  policy: CollectPolicy,
+) -> Outcomes<S, M, F>
+where
+  I: IntoIterator,
+  I::Item: Future<Output = Outcome<S, M, F>>,
+{
+  use futures_util::stream::{FuturesUnordered, StreamExt};
+
+  let mut pending: FuturesUnordered<_> = futures.into_iter().collect();
+  let mut outcomes = Outcomes::new();
+  while let Some(outcome) = pending.next().await {
+    let stop = policy == CollectPolicy::StopOnFailure && outcome.is_failure();
+    outcomes.push(outcome);
+    if stop {
+      break;
+    }
+  }
+  outcomes
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+  use super::*;
+  use core::future::{pending, ready};
+  use futures_util::{future::Either, FutureExt};
+
+  #[test]
+  fn stop_on_failure() {
+    let futures = [
+      Either::Left(ready(Failure::<(), (), _>("fatal"))),
+      Either::Right(pending()),
+    ];
+    let outcomes =
+      collect_unordered_outcomes(futures, CollectPolicy::StopOnFailure)
+        .now_or_never()
+        .unwrap();
+    assert_eq!(outcomes.failures, ["fatal"]);
+    assert_eq!(outcomes.len(), 1);
+  }
+}
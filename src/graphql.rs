@@ -0,0 +1,106 @@
+//! Integration with [`async-graphql`].
+//!
+//! [`Outcome`] implements [`OutputType`], so resolvers can return an
+//! [`Outcome`] directly. A [`Success`] is resolved as usual, while a
+//! [`Mistake`] or [`Failure`] is reported as a GraphQL error, whose
+//! extensions record the state of the outcome:
+//!
+//! ```json
+//! { "message": "...", "extensions": { "state": "mistake", "retryable": true } }
+//! ```
+//!
+//! [`Aberration`] implements [`ErrorExtensions`] with the same extensions, for
+//! resolvers that must return an [`Error`].
+//!
+//! **NOTE**: The `boxed-trait` feature of `async-graphql` is not supported.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use async_graphql::{value, EmptyMutation, EmptySubscription, Object, Schema};
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!   async fn inventory(&self) -> Outcome<u32, String, String> {
+//!     Mistake("warehouse is busy".into())
+//!   }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let response = futures::executor::block_on(schema.execute("{ inventory }"));
+//! let error = &response.errors[0];
+//! assert_eq!(error.message, "warehouse is busy");
+//! let extensions = error.extensions.as_ref().unwrap();
+//! assert_eq!(extensions.get("retryable"), Some(&value!(true)));
+//! ```
+//!
+//! [`async-graphql`]: https://crates.io/crates/async-graphql
+//! [`OutputType`]: async_graphql::OutputType
+//! [`ErrorExtensions`]: async_graphql::ErrorExtensions
+//! [`Error`]: async_graphql::Error
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use core::fmt::Display;
+use std::{
+  borrow::Cow,
+  string::{String, ToString},
+};
+
+use async_graphql::{
+  parser::types::Field, registry::Registry, ContextSelectionSet, Error,
+  ErrorExtensions, OutputType, Positioned, ServerResult, Value,
+};
+
+use crate::prelude::*;
+
+fn error(message: &dyn Display, state: &str, retryable: bool) -> Error {
+  Error::new(message.to_string()).extend_with(|_, extensions| {
+    extensions.set("state", state);
+    extensions.set("retryable", retryable);
+  })
+}
+
+impl<M: Display, F: Display> ErrorExtensions for Aberration<M, F> {
+  fn extend(&self) -> Error {
+    match self {
+      Self::Mistake(m) => error(m, "mistake", true),
+      Self::Failure(f) => error(f, "failure", false),
+    }
+  }
+}
+
+impl<S, M, F> OutputType for Outcome<S, M, F>
+where
+  S: OutputType + Sync,
+  M: Display + Send + Sync,
+  F: Display + Send + Sync,
+{
+  fn type_name() -> Cow<'static, str> {
+    S::type_name()
+  }
+
+  fn create_type_info(registry: &mut Registry) -> String {
+    S::create_type_info(registry)
+  }
+
+  async fn resolve(
+    &self,
+    ctx: &ContextSelectionSet<'_>,
+    field: &Positioned<Field>,
+  ) -> ServerResult<Value> {
+    let error = match self {
+      Success(s) => return s.resolve(ctx, field).await,
+      Mistake(m) => error(m, "mistake", true),
+      Failure(f) => error(f, "failure", false),
+    };
+    Err(ctx.set_error_path(error.into_server_error(field.pos)))
+  }
+}
@@ -0,0 +1,263 @@
+//! Integration with the [`higher`] functional programming library.
+//!
+//! [`Functor`], [`Apply`], [`Pure`], and [`Bind`] (and therefore the
+//! blanket `Applicative` and `Monad` traits) are implemented for [`Outcome`]
+//! and [`Concern`] over their [`Success`] value, and for [`Aberration`] over
+//! its [`Mistake`] value, in the same way that `higher` implements them for
+//! [`Result`] over its `Ok` value. When two values are combined with
+//! [`Apply::apply`] or [`Bind::bind`], the first one that is not a
+//! [`Success`] (or for an [`Aberration`], not a [`Mistake`]) is returned.
+//!
+//! [`Bifunctor`] is implemented for [`Concern`] and [`Aberration`], which have
+//! exactly two type parameters. An [`Outcome`] has three, so it can instead
+//! be mapped with [`Outcome::map_all`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use higher::{apply::f, Apply, Bind, Functor, Pure};
+//!
+//! let x: Outcome<u32, &str, &str> = Outcome::pure(2);
+//! assert_eq!(x.fmap(|x| x * 3), Success(6));
+//! assert_eq!(x.bind(|_| Mistake("busy")), Mistake::<u32, _, _>("busy"));
+//! assert_eq!(x.apply(Success(f(|x: u32| x + 1))), Success(3));
+//! assert_eq!(x.apply::<u32>(Failure("lost")), Failure("lost"));
+//! ```
+//!
+//! [`higher`]: https://docs.rs/higher
+//! [`Functor`]: ::higher::Functor
+//! [`Bifunctor`]: ::higher::Bifunctor
+//! [`Apply`]: ::higher::Apply
+//! [`Apply::apply`]: ::higher::Apply::apply
+//! [`Pure`]: ::higher::Pure
+//! [`Bind`]: ::higher::Bind
+//! [`Bind::bind`]: ::higher::Bind::bind
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Outcome::map_all`]: crate::prelude::Outcome::map_all
+//! [`Concern`]: crate::prelude::Concern
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+use ::higher::{apply::ApplyFn, Apply, Bifunctor, Bind, Functor, Pure};
+
+use crate::prelude::*;
+
+impl<S, M, F> Functor<'_, S> for Outcome<S, M, F> {
+  type Target<T> = Outcome<T, M, F>;
+
+  #[inline]
+  fn fmap<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(S) -> B,
+  {
+    self.map(callable)
+  }
+}
+
+impl<'a, S: 'a, M, F> Apply<'a, S> for Outcome<S, M, F> {
+  type Target<T>
+    = Outcome<T, M, F>
+  where
+    T: 'a;
+
+  fn apply<B>(
+    self,
+    f: <Self as Apply<'a, S>>::Target<ApplyFn<'a, S, B>>,
+  ) -> <Self as Apply<'a, S>>::Target<B>
+  where
+    B: 'a,
+  {
+    self.and_then(|value| f.map(|f| f.apply(value)))
+  }
+}
+
+impl<S, M, F> Pure<S> for Outcome<S, M, F> {
+  #[inline]
+  fn pure(value: S) -> Self {
+    Success(value)
+  }
+}
+
+impl<S, M, F> Bind<'_, S> for Outcome<S, M, F> {
+  type Target<T> = Outcome<T, M, F>;
+
+  #[inline]
+  fn bind<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(S) -> Self::Target<B>,
+  {
+    self.and_then(callable)
+  }
+}
+
+impl<S, M> Functor<'_, S> for Concern<S, M> {
+  type Target<T> = Concern<T, M>;
+
+  #[inline]
+  fn fmap<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(S) -> B,
+  {
+    self.map(callable)
+  }
+}
+
+impl<S, M> Bifunctor<'_, S, M> for Concern<S, M> {
+  type Target<T, U> = Concern<T, U>;
+
+  fn bimap<T, U, L, R>(self, left: L, right: R) -> Self::Target<T, U>
+  where
+    L: Fn(S) -> T,
+    R: Fn(M) -> U,
+  {
+    match self {
+      Self::Success(value) => Concern::Success(left(value)),
+      Self::Mistake(value) => Concern::Mistake(right(value)),
+    }
+  }
+}
+
+impl<'a, S: 'a, M> Apply<'a, S> for Concern<S, M> {
+  type Target<T>
+    = Concern<T, M>
+  where
+    T: 'a;
+
+  fn apply<B>(
+    self,
+    f: <Self as Apply<'a, S>>::Target<ApplyFn<'a, S, B>>,
+  ) -> <Self as Apply<'a, S>>::Target<B>
+  where
+    B: 'a,
+  {
+    match self {
+      Self::Success(value) => f.map(|f| f.apply(value)),
+      Self::Mistake(value) => Concern::Mistake(value),
+    }
+  }
+}
+
+impl<S, M> Pure<S> for Concern<S, M> {
+  #[inline]
+  fn pure(value: S) -> Self {
+    Self::Success(value)
+  }
+}
+
+impl<S, M> Bind<'_, S> for Concern<S, M> {
+  type Target<T> = Concern<T, M>;
+
+  fn bind<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(S) -> Self::Target<B>,
+  {
+    match self {
+      Self::Success(value) => callable(value),
+      Self::Mistake(value) => Concern::Mistake(value),
+    }
+  }
+}
+
+impl<M, F> Functor<'_, M> for Aberration<M, F> {
+  type Target<T> = Aberration<T, F>;
+
+  #[inline]
+  fn fmap<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(M) -> B,
+  {
+    self.map_mistake(callable)
+  }
+}
+
+impl<M, F> Bifunctor<'_, M, F> for Aberration<M, F> {
+  type Target<T, U> = Aberration<T, U>;
+
+  fn bimap<T, U, L, R>(self, left: L, right: R) -> Self::Target<T, U>
+  where
+    L: Fn(M) -> T,
+    R: Fn(F) -> U,
+  {
+    match self {
+      Self::Mistake(value) => Aberration::Mistake(left(value)),
+      Self::Failure(value) => Aberration::Failure(right(value)),
+    }
+  }
+}
+
+impl<'a, M: 'a, F> Apply<'a, M> for Aberration<M, F> {
+  type Target<T>
+    = Aberration<T, F>
+  where
+    T: 'a;
+
+  fn apply<B>(
+    self,
+    f: <Self as Apply<'a, M>>::Target<ApplyFn<'a, M, B>>,
+  ) -> <Self as Apply<'a, M>>::Target<B>
+  where
+    B: 'a,
+  {
+    self.and_then(|value| f.map_mistake(|f| f.apply(value)))
+  }
+}
+
+impl<M, F> Pure<M> for Aberration<M, F> {
+  #[inline]
+  fn pure(value: M) -> Self {
+    Self::Mistake(value)
+  }
+}
+
+impl<M, F> Bind<'_, M> for Aberration<M, F> {
+  type Target<T> = Aberration<T, F>;
+
+  #[inline]
+  fn bind<B, C>(self, callable: C) -> Self::Target<B>
+  where
+    C: Fn(M) -> Self::Target<B>,
+  {
+    self.and_then(callable)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ::higher::{apply::f, Monad};
+
+  fn double<'a, T>(value: T) -> T
+  where
+    T: Monad<'a, u32> + Bind<'a, u32, Target<u32> = T>,
+  {
+    value.bind::<u32, _>(|x| T::pure(x * 2))
+  }
+
+  #[test]
+  fn monad() {
+    assert_eq!(double(Outcome::<u32, (), ()>::pure(2)), Success(4));
+    assert_eq!(
+      double(Concern::<u32, ()>::Mistake(())),
+      Concern::Mistake(())
+    );
+    assert_eq!(
+      double(Aberration::<u32, ()>::Mistake(3)),
+      Aberration::Mistake(6)
+    );
+  }
+
+  #[test]
+  fn apply_keeps_first_error() {
+    let x: Concern<u32, &str> = Concern::Mistake("first");
+    let y = x.apply(Concern::<ApplyFn<'_, u32, u32>, _>::Mistake("second"));
+    assert_eq!(y, Concern::Mistake("first"));
+
+    let x: Aberration<u32, &str> = Aberration::Mistake(1);
+    assert_eq!(
+      x.apply(Aberration::Mistake(f(|x: u32| x + 1))),
+      Aberration::Mistake(2)
+    );
+    assert_eq!(x.bimap(|x| x + 1, str::len), Aberration::Mistake(2));
+  }
+}
@@ -0,0 +1,225 @@
+//! Process-wide hooks observing errors as they enter an [`Outcome`].
+//!
+//! Much like [`std::panic::set_hook`], this module allows a single, global
+//! hook to be registered for each error state. The hooks are invoked by the
+//! [`Outcome::mistake_hooked`] and [`Outcome::failure_hooked`] constructors,
+//! by the [`mistake!`] and [`failure!`] macros (and the macros built on them,
+//! such as [`ensure!`]), and by the `WrapFailure` trait whenever a failure
+//! is wrapped. This allows
+//! logging, metrics, or breadcrumbs to be captured in one place, without
+//! threading an observer through every call site.
+//!
+//! Hooks should be cheap, and must not panic.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::hooks;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static FAILURES: AtomicUsize = AtomicUsize::new(0);
+//!
+//! hooks::set_failure_hook(|event| {
+//!   FAILURES.fetch_add(1, Ordering::Relaxed);
+//!   eprintln!("failure at {}: {:?}", event.location(), event.value());
+//! });
+//!
+//! let x: Outcome<(), (), &str> = Outcome::failure_hooked("disk is full");
+//! assert_eq!(x, Failure("disk is full"));
+//! assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+//! # let _ = hooks::take_failure_hook();
+//! ```
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`mistake!`]: crate::mistake
+//! [`failure!`]: crate::failure
+//! [`ensure!`]: crate::ensure
+extern crate std;
+
+use core::{fmt::Debug, panic::Location};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::{context::Context, prelude::*};
+
+/// A registered hook.
+///
+/// Hooks are reference counted, so that a hook may register or unregister
+/// hooks itself while it is running.
+pub type Hook = Arc<dyn Fn(&Event<'_>) + Send + Sync + 'static>;
+
+static MISTAKE_HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+static FAILURE_HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Describes an error value that has entered an [`Outcome`].
+///
+/// [`Outcome`]: crate::prelude::Outcome
+#[derive(Debug)]
+pub struct Event<'a> {
+  value: &'a dyn Debug,
+  location: &'static Location<'static>,
+  context: Option<Context>,
+}
+
+impl<'a> Event<'a> {
+  /// Returns the error value.
+  #[must_use]
+  #[inline]
+  pub fn value(&self) -> &'a dyn Debug {
+    self.value
+  }
+
+  /// Returns the location at which the error value entered the [`Outcome`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[must_use]
+  #[inline]
+  pub fn location(&self) -> &'static Location<'static> {
+    self.location
+  }
+
+  /// Returns the [`context`](crate::context) stack active when the error
+  /// value entered the [`Outcome`], or `None` if no scope was active.
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[must_use]
+  #[inline]
+  pub fn context(&self) -> Option<&Context> {
+    self.context.as_ref()
+  }
+}
+
+/// Registers a hook invoked whenever a [`Mistake`] is created through a
+/// hooked constructor, replacing any previously registered hook.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+pub fn set_mistake_hook<H>(hook: H)
+where
+  H: Fn(&Event<'_>) + Send + Sync + 'static,
+{
+  *MISTAKE_HOOK.write().unwrap_or_else(PoisonError::into_inner) =
+    Some(Arc::new(hook));
+}
+
+/// Registers a hook invoked whenever a [`Failure`] is created through a
+/// hooked constructor or wrapped, replacing any previously registered hook.
+///
+/// [`Failure`]: crate::prelude::Failure
+pub fn set_failure_hook<H>(hook: H)
+where
+  H: Fn(&Event<'_>) + Send + Sync + 'static,
+{
+  *FAILURE_HOOK.write().unwrap_or_else(PoisonError::into_inner) =
+    Some(Arc::new(hook));
+}
+
+/// Unregisters the current mistake hook, returning it.
+pub fn take_mistake_hook() -> Option<Hook> {
+  MISTAKE_HOOK
+    .write()
+    .unwrap_or_else(PoisonError::into_inner)
+    .take()
+}
+
+/// Unregisters the current failure hook, returning it.
+pub fn take_failure_hook() -> Option<Hook> {
+  FAILURE_HOOK
+    .write()
+    .unwrap_or_else(PoisonError::into_inner)
+    .take()
+}
+
+/* The hook is cloned out of the lock before it is called, so that a hook
+ * creating a hooked outcome, or replacing a hook, cannot deadlock.
+ */
+#[track_caller]
+fn invoke(hook: &RwLock<Option<Hook>>, value: &dyn Debug) {
+  let location = Location::caller();
+  let hook = hook.read().unwrap_or_else(PoisonError::into_inner).clone();
+  if let Some(hook) = hook {
+    let context = crate::context::current();
+    hook(&Event {
+      value,
+      location,
+      context,
+    });
+  }
+}
+
+#[track_caller]
+#[inline]
+pub(crate) fn mistake(value: &dyn Debug) {
+  invoke(&MISTAKE_HOOK, value);
+}
+
+#[track_caller]
+#[inline]
+pub(crate) fn failure(value: &dyn Debug) {
+  invoke(&FAILURE_HOOK, value);
+}
+
+impl<S, M: Debug, F> Outcome<S, M, F> {
+  /// Creates a [`Mistake`], invoking the registered mistake hook.
+  ///
+  /// See the [`hooks`](crate::hooks) module for details.
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[track_caller]
+  #[inline]
+  pub fn mistake_hooked(value: M) -> Self {
+    mistake(&value);
+    Mistake(value)
+  }
+}
+
+impl<S, M, F: Debug> Outcome<S, M, F> {
+  /// Creates a [`Failure`], invoking the registered failure hook.
+  ///
+  /// See the [`hooks`](crate::hooks) module for details.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[track_caller]
+  #[inline]
+  pub fn failure_hooked(value: F) -> Self {
+    failure(&value);
+    Failure(value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{AtomicBool, Ordering};
+
+  #[test]
+  fn hook_may_unregister_itself() {
+    static CALLED: AtomicBool = AtomicBool::new(false);
+    set_mistake_hook(|_| {
+      CALLED.store(true, Ordering::Relaxed);
+      drop(take_mistake_hook());
+      let _: Outcome<(), u8, ()> = Outcome::mistake_hooked(0);
+    });
+    let x: Outcome<(), u8, ()> = Outcome::mistake_hooked(1);
+    assert_eq!(x, Mistake(1));
+    assert!(CALLED.load(Ordering::Relaxed));
+    assert!(take_mistake_hook().is_none());
+  }
+
+  #[test]
+  fn macros_invoke_hooks() {
+    static CALLED: AtomicBool = AtomicBool::new(false);
+    fn check(value: u32) -> Outcome<u32, (), &'static str> {
+      crate::ensure!(value > 0, "value is zero");
+      Success(value)
+    }
+
+    set_failure_hook(|event| {
+      if std::format!("{:?}", event.value()) == "\"value is zero\"" {
+        CALLED.store(true, Ordering::Relaxed);
+      }
+    });
+    assert_eq!(check(0), Failure("value is zero"));
+    drop(take_failure_hook());
+    assert!(CALLED.load(Ordering::Relaxed));
+  }
+}
@@ -1,4 +1,6 @@
-use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::{FusedIterator, Product, Sum};
 
 use crate::prelude::*;
 
@@ -44,14 +46,44 @@ pub struct Iter<'a, T: 'a> {
   pub(crate) inner: Option<&'a T>,
 }
 
-/// An iterator adapter that produces output as long as the underlying iterator
-/// produces [`Outcome::Success`] values.
+/// An iterator over the [`Success`] values of an iterator of [`Outcome`]s,
+/// which stops at the first [`Mistake`] or [`Failure`].
 ///
-/// If an error is encountered, the iterator stops and the error is stored.
-//struct OutcomeShunt<'a, I, M, F> {
-//  error: &'a mut Outcome<(), M, F>,
-//  iter: I,
-//}
+/// The [`Mistake`] or [`Failure`] that stopped the iterator is stored outside
+/// of it, and returned by [`OutcomeIteratorExt::shunt`] in place of the value
+/// computed from the successes.
+///
+/// This struct is created by the [`shunt`] method on [`OutcomeIteratorExt`].
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`shunt`]: OutcomeIteratorExt::shunt
+#[derive(Debug)]
+pub struct OutcomeShunt<'a, I, M, F> {
+  error: &'a mut Outcome<(), M, F>,
+  iter: I,
+}
+
+// Runs `processor` over the successes of `iter`, stopping at the first
+// mistake or failure, which is returned in place of the processed value.
+fn process_outcomes<I, S, M, F, T, C>(iter: I, processor: C) -> Outcome<T, M, F>
+where
+  I: Iterator<Item = Outcome<S, M, F>>,
+  C: FnOnce(OutcomeShunt<'_, I, M, F>) -> T,
+{
+  let mut error = Success(());
+  let value = processor(OutcomeShunt {
+    error: &mut error,
+    iter,
+  });
+  match error {
+    Success(()) => Success(value),
+    Mistake(m) => Mistake(m),
+    Failure(f) => Failure(f),
+  }
+}
 
 impl<'a, S, M, F> IntoIterator for &'a mut Outcome<S, M, F> {
   type IntoIter = IterMut<'a, S>;
@@ -83,18 +115,166 @@ impl<S, M, F> IntoIterator for Outcome<S, M, F> {
   }
 }
 
+impl<'a, M, F> IntoIterator for &'a mut Aberration<M, F> {
+  type IntoIter = IterMut<'a, M>;
+  type Item = &'a mut M;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<'a, M, F> IntoIterator for &'a Aberration<M, F> {
+  type IntoIter = Iter<'a, M>;
+  type Item = &'a M;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// Yields the [`Mistake`] value of an [`Aberration`], if any.
+///
+/// This allows the mistakes of many aberrations to be gathered with
+/// [`Iterator::flatten`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let errors = vec![
+///   Aberration::Mistake("busy"),
+///   Aberration::Failure(500),
+///   Aberration::Mistake("throttled"),
+/// ];
+/// let mistakes: Vec<_> = errors.into_iter().flatten().collect();
+/// assert_eq!(mistakes, ["busy", "throttled"]);
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Aberration::Mistake
+/// [`Aberration`]: crate::prelude::Aberration
+impl<M, F> IntoIterator for Aberration<M, F> {
+  type IntoIter = IntoIter<M>;
+  type Item = M;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIter {
+      inner: self.mistake(),
+    }
+  }
+}
+
 /* Iterator Trait Implementations */
-//impl<S, M, F, T: FromIterator<S>> FromIterator<Outcome<S, M, F>>
-//  for Outcome<T, M, F>
-//{
-//  #[inline]
-//  fn from_iter<I>(iter: I) -> Outcome<T, M, F>
-//  where
-//    I: IntoIterator<Item = Outcome<S, M, F>>,
-//  {
-//    process_outcomes(iter.into_iter(), Iterator::collect)
-//  }
-//}
+/// Takes each element in the [`Iterator`]: if it is a [`Mistake`] or
+/// [`Failure`], no further elements are taken, and that value is returned.
+/// Should no [`Mistake`] or [`Failure`] occur, a container with the values of
+/// each [`Success`] is returned.
+///
+/// This mirrors the [`FromIterator`] implementation for [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let v: Vec<Outcome<u32, &str, &str>> = vec![Success(1), Success(2)];
+/// let x: Outcome<Vec<u32>, &str, &str> = v.into_iter().collect();
+/// assert_eq!(x, Success(vec![1, 2]));
+///
+/// let v = vec![Success(1), Mistake("busy"), Failure("gone")];
+/// let x: Outcome<Vec<u32>, &str, &str> = v.into_iter().collect();
+/// assert_eq!(x, Mistake("busy"));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Result`]: core::result::Result
+impl<S, M, F, T: FromIterator<S>> FromIterator<Outcome<S, M, F>>
+  for Outcome<T, M, F>
+{
+  #[inline]
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = Outcome<S, M, F>>,
+  {
+    process_outcomes(iter.into_iter(), |shunt| shunt.collect())
+  }
+}
+
+/// Takes each element in the [`Iterator`]: if it is a [`Mistake`] or
+/// [`Failure`], no further elements are taken, and that value is returned.
+/// Should no [`Mistake`] or [`Failure`] occur, the sum of all elements is
+/// returned.
+///
+/// This mirrors the [`Sum`] implementation for [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let v: Vec<Outcome<u32, &str, &str>> = vec![Success(1), Success(2)];
+/// let x: Outcome<u32, &str, &str> = v.into_iter().sum();
+/// assert_eq!(x, Success(3));
+///
+/// let v = vec![Success(1), Failure("gone"), Mistake("busy")];
+/// let x: Outcome<u32, &str, &str> = v.into_iter().sum();
+/// assert_eq!(x, Failure("gone"));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Result`]: core::result::Result
+impl<T, U, M, F> Sum<Outcome<U, M, F>> for Outcome<T, M, F>
+where
+  T: Sum<U>,
+{
+  #[inline]
+  fn sum<I>(iter: I) -> Self
+  where
+    I: Iterator<Item = Outcome<U, M, F>>,
+  {
+    process_outcomes(iter, |shunt| shunt.sum())
+  }
+}
+
+/// Takes each element in the [`Iterator`]: if it is a [`Mistake`] or
+/// [`Failure`], no further elements are taken, and that value is returned.
+/// Should no [`Mistake`] or [`Failure`] occur, the product of all elements is
+/// returned.
+///
+/// This mirrors the [`Product`] implementation for [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let v: Vec<Outcome<u32, &str, &str>> = vec![Success(2), Success(3)];
+/// let x: Outcome<u32, &str, &str> = v.into_iter().product();
+/// assert_eq!(x, Success(6));
+///
+/// let v = vec![Success(2), Mistake("busy"), Failure("gone")];
+/// let x: Outcome<u32, &str, &str> = v.into_iter().product();
+/// assert_eq!(x, Mistake("busy"));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Result`]: core::result::Result
+impl<T, U, M, F> Product<Outcome<U, M, F>> for Outcome<T, M, F>
+where
+  T: Product<U>,
+{
+  #[inline]
+  fn product<I>(iter: I) -> Self
+  where
+    I: Iterator<Item = Outcome<U, M, F>>,
+  {
+    process_outcomes(iter, |shunt| shunt.product())
+  }
+}
 
 impl<T> Iterator for IntoIter<T> {
   type Item = T;
@@ -141,25 +321,38 @@ impl<'a, T> Iterator for Iter<'a, T> {
   }
 }
 
-//impl<I, S, M, F> Iterator for OutcomeShunt<'_, I, M, F>
-//where
-//  I: Iterator<Item = Outcome<S, M, F>>,
-//{
-//  type Item = S;
-//
-//  fn next(&mut self) -> Option<Self::Item> {
-//    self.find(|_| true)
-//  }
-//
-//  fn size_hint(&self) -> (usize, Option<usize>) {
-//    if self.error.is_error() {
-//      (0, Some(0))
-//    } else {
-//      let (_, upper) = self.iter.size_hint();
-//      (0, upper)
-//    }
-//  }
-//}
+impl<I, S, M, F> Iterator for OutcomeShunt<'_, I, M, F>
+where
+  I: Iterator<Item = Outcome<S, M, F>>,
+{
+  type Item = S;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.error.is_error() {
+      return None;
+    }
+    match self.iter.next()? {
+      Success(value) => Some(value),
+      Mistake(value) => {
+        *self.error = Mistake(value);
+        None
+      }
+      Failure(value) => {
+        *self.error = Failure(value);
+        None
+      }
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    if self.error.is_error() {
+      (0, Some(0))
+    } else {
+      let (_, upper) = self.iter.size_hint();
+      (0, upper)
+    }
+  }
+}
 
 impl<T> DoubleEndedIterator for IntoIter<T> {
   #[inline]
@@ -190,6 +383,559 @@ impl<T> FusedIterator for IntoIter<T> {}
 impl<T> FusedIterator for IterMut<'_, T> {}
 impl<T> FusedIterator for Iter<'_, T> {}
 
+/// Extension methods for an [`Iterator`] over [`Outcome`]s.
+///
+/// This trait is implemented for every [`Iterator`] whose items are an
+/// [`Outcome`].
+///
+/// [`Outcome`]: crate::prelude::Outcome
+pub trait OutcomeIteratorExt<S, M, F>:
+  Iterator<Item = Outcome<S, M, F>> + Sized
+{
+  /// Runs `processor` over an iterator of every [`Success`] value, stopping
+  /// at the first [`Mistake`] or [`Failure`].
+  ///
+  /// If a [`Mistake`] or [`Failure`] is found, it is returned in place of the
+  /// value computed by `processor`. This is the machinery behind collecting,
+  /// summing, and multiplying an iterator of [`Outcome`]s, and allows any
+  /// other consumer of an iterator to share the same short-circuiting
+  /// behavior.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::OutcomeIteratorExt;
+  ///
+  /// let batch: [Outcome<u32, &str, &str>; 3] = [Success(3), Success(7), Success(5)];
+  /// assert_eq!(batch.into_iter().shunt(|successes| successes.max()), Success(Some(7)));
+  ///
+  /// let batch = [Success(3), Mistake("busy"), Failure("gone")];
+  /// let x = batch.into_iter().shunt(|successes| successes.fold(0, |a, b| a + b));
+  /// assert_eq!(x, Mistake("busy"));
+  /// ```
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  fn shunt<T, C>(self, processor: C) -> Outcome<T, M, F>
+  where
+    C: FnOnce(OutcomeShunt<'_, Self, M, F>) -> T,
+  {
+    process_outcomes(self, processor)
+  }
+
+  /// Consumes the iterator, splitting every [`Success`], [`Mistake`], and
+  /// [`Failure`] into separate collections.
+  ///
+  /// Unlike collecting into an [`Outcome`], this does not stop at the first
+  /// [`Mistake`] or [`Failure`], so that every error in a batch can be
+  /// reported at once.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::OutcomeIteratorExt;
+  ///
+  /// let batch = [Success(1), Mistake("busy"), Success(2), Failure(0.5)];
+  /// let (successes, mistakes, failures): (Vec<_>, Vec<_>, Vec<_>) =
+  ///   batch.into_iter().partition_outcomes();
+  ///
+  /// assert_eq!(successes, [1, 2]);
+  /// assert_eq!(mistakes, ["busy"]);
+  /// assert_eq!(failures, [0.5]);
+  /// ```
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn partition_outcomes<A, B, C>(self) -> (A, B, C)
+  where
+    A: Default + Extend<S>,
+    B: Default + Extend<M>,
+    C: Default + Extend<F>,
+  {
+    let mut successes = A::default();
+    let mut mistakes = B::default();
+    let mut failures = C::default();
+    for outcome in self {
+      match outcome {
+        Success(value) => successes.extend(Some(value)),
+        Mistake(value) => mistakes.extend(Some(value)),
+        Failure(value) => failures.extend(Some(value)),
+      }
+    }
+    (successes, mistakes, failures)
+  }
+
+  /// Consumes the iterator, collecting every value, and returns an
+  /// [`Outcome`] based on the most severe state encountered.
+  ///
+  /// The result is a [`Failure`] containing every failure if any occurred,
+  /// otherwise a [`Mistake`] containing every mistake if any occurred, and a
+  /// [`Success`] containing every success otherwise. This is equivalent to
+  /// collecting into [`Outcomes`] and calling [`Outcomes::into_outcome`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::OutcomeIteratorExt;
+  ///
+  /// let batch = [Success(1), Failure("gone"), Mistake("busy"), Failure("lost")];
+  /// assert_eq!(batch.into_iter().aggregate_failures(), Failure(vec!["gone", "lost"]));
+  ///
+  /// let batch = [Success(1), Mistake("busy"), Success(2)];
+  /// let x: Outcome<_, _, Vec<()>> = batch.into_iter().aggregate_failures();
+  /// assert_eq!(x, Mistake(vec!["busy"]));
+  /// ```
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+  #[cfg(feature = "alloc")]
+  fn aggregate_failures(self) -> Outcome<Vec<S>, Vec<M>, Vec<F>> {
+    self.collect::<Outcomes<S, M, F>>().into_outcome()
+  }
+
+  /// Consumes the iterator, collecting every [`Success`] and [`Mistake`]
+  /// until the first [`Failure`] is encountered.
+  ///
+  /// Unlike [`aggregate_failures`], this short-circuits: the first
+  /// [`Failure`] is returned as is, and any values after it are neither
+  /// consumed nor collected. Otherwise, the result is a [`Mistake`]
+  /// containing every mistake if any occurred, and a [`Success`] containing
+  /// every success otherwise.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::OutcomeIteratorExt;
+  ///
+  /// let batch = [Mistake("busy"), Success(1), Mistake("slow")];
+  /// let x: Outcome<_, _, ()> = batch.into_iter().aggregate_until_failure();
+  /// assert_eq!(x, Mistake(vec!["busy", "slow"]));
+  ///
+  /// let batch = [Mistake("busy"), Failure("gone"), Failure("lost")];
+  /// let x: Outcome<Vec<()>, _, _> = batch.into_iter().aggregate_until_failure();
+  /// assert_eq!(x, Failure("gone"));
+  /// ```
+  ///
+  /// [`aggregate_failures`]: OutcomeIteratorExt::aggregate_failures
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+  #[cfg(feature = "alloc")]
+  fn aggregate_until_failure(self) -> Outcome<Vec<S>, Vec<M>, F> {
+    let mut successes = Vec::new();
+    let mut mistakes = Vec::new();
+    for outcome in self {
+      match outcome {
+        Success(value) => successes.push(value),
+        Mistake(value) => mistakes.push(value),
+        Failure(value) => return Failure(value),
+      }
+    }
+    if mistakes.is_empty() {
+      Success(successes)
+    } else {
+      Mistake(mistakes)
+    }
+  }
+
+  /// Folds every [`Success`] value into an accumulator with a fallible
+  /// function, stopping at the first [`Failure`].
+  ///
+  /// `callable` is given the current accumulator and the next value, and
+  /// returns the next accumulator as a [`Success`]. A [`Mistake`], whether
+  /// returned by `callable` or produced by the iterator, is handled
+  /// according to `policy`:
+  ///
+  ///  - [`MistakePolicy::Abort`] stops the fold, returning the mistake.
+  ///  - [`MistakePolicy::Accumulate`] collects the mistake, and continues the
+  ///    fold with the accumulator as it was before the mistake occurred.
+  ///
+  /// A [`Failure`] always stops the fold. The result is the [`Failure`] if
+  /// one occurred, otherwise a [`Mistake`] containing every mistake if any
+  /// occurred, and the final accumulator otherwise.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::{MistakePolicy, OutcomeIteratorExt};
+  ///
+  /// fn add(total: &u8, value: u8) -> Outcome<u8, u8, &'static str> {
+  ///   match total.checked_add(value) {
+  ///     Some(total) if value % 2 == 0 => Success(total),
+  ///     Some(_) => Mistake(value),
+  ///     None => Failure("overflow"),
+  ///   }
+  /// }
+  ///
+  /// let batch = [Success(2), Success(3), Success(4), Success(5)];
+  /// let x = batch.into_iter().try_fold_outcome(0, MistakePolicy::Accumulate, add);
+  /// assert_eq!(x, Mistake(vec![3, 5]));
+  ///
+  /// let x = batch.into_iter().try_fold_outcome(0, MistakePolicy::Abort, add);
+  /// assert_eq!(x, Mistake(vec![3]));
+  ///
+  /// let batch = [Success(2), Success(4)];
+  /// let x = batch.into_iter().try_fold_outcome(0, MistakePolicy::Abort, add);
+  /// assert_eq!(x, Success(6));
+  ///
+  /// let batch = [Success(200), Success(100), Success(3)];
+  /// let x = batch.into_iter().try_fold_outcome(0, MistakePolicy::Accumulate, add);
+  /// assert_eq!(x, Failure("overflow"));
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+  #[cfg(feature = "alloc")]
+  fn try_fold_outcome<A, C>(
+    self,
+    init: A,
+    policy: MistakePolicy,
+    mut callable: C,
+  ) -> Outcome<A, Vec<M>, F>
+  where
+    C: FnMut(&A, S) -> Outcome<A, M, F>,
+  {
+    let mut accumulator = init;
+    let mut mistakes = Vec::new();
+    for outcome in self {
+      match outcome.and_then(|value| callable(&accumulator, value)) {
+        Success(value) => accumulator = value,
+        Mistake(value) => {
+          mistakes.push(value);
+          if policy == MistakePolicy::Abort {
+            break;
+          }
+        }
+        Failure(value) => return Failure(value),
+      }
+    }
+    if mistakes.is_empty() {
+      Success(accumulator)
+    } else {
+      Mistake(mistakes)
+    }
+  }
+}
+
+/// Determines how [`OutcomeIteratorExt::try_fold_outcome`] handles a
+/// [`Mistake`].
+///
+/// [`Mistake`]: crate::prelude::Mistake
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum MistakePolicy {
+  /// Stop at the first mistake.
+  Abort,
+  /// Collect every mistake, skipping the value that caused it.
+  Accumulate,
+}
+
+impl<I, S, M, F> OutcomeIteratorExt<S, M, F> for I where
+  I: Iterator<Item = Outcome<S, M, F>>
+{
+}
+
+/// Every [`Success`], [`Mistake`], and [`Failure`] collected from a set of
+/// [`Outcome`]s.
+///
+/// Unlike collecting into an [`Outcome`], no value is discarded, and the
+/// order in which values were added is preserved within each state.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::Outcomes;
+///
+/// let outcomes: Outcomes<u32, &str, &str> =
+///   [Success(1), Mistake("busy"), Success(2), Failure("gone")]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(outcomes.successes, [1, 2]);
+/// assert_eq!(outcomes.mistakes, ["busy"]);
+/// assert_eq!(outcomes.failures, ["gone"]);
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Outcome`]: crate::prelude::Outcome
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Outcomes<S, M, F> {
+  /// Every [`Success`](crate::prelude::Success) value
+  pub successes: Vec<S>,
+  /// Every [`Mistake`](crate::prelude::Mistake) value
+  pub mistakes: Vec<M>,
+  /// Every [`Failure`](crate::prelude::Failure) value
+  pub failures: Vec<F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<S, M, F> Outcomes<S, M, F> {
+  /// Creates an empty collection.
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      successes: Vec::new(),
+      mistakes: Vec::new(),
+      failures: Vec::new(),
+    }
+  }
+
+  /// Adds the value of `outcome` to the collection for its state.
+  #[inline]
+  pub fn push(&mut self, outcome: Outcome<S, M, F>) {
+    match outcome {
+      Success(s) => self.successes.push(s),
+      Mistake(m) => self.mistakes.push(m),
+      Failure(f) => self.failures.push(f),
+    }
+  }
+
+  /// Returns the total number of values collected.
+  #[must_use]
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.successes.len() + self.mistakes.len() + self.failures.len()
+  }
+
+  /// Returns `true` if no values have been collected.
+  #[must_use]
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Converts the collection into a single [`Outcome`], according to the
+  /// most severe state collected.
+  ///
+  /// This is a [`Failure`] containing every failure if any were collected,
+  /// otherwise a [`Mistake`] containing every mistake if any were collected,
+  /// and a [`Success`] containing every success otherwise.
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::Outcomes;
+  ///
+  /// let outcomes: Outcomes<u32, &str, ()> =
+  ///   [Success(1), Mistake("busy"), Success(2)].into_iter().collect();
+  /// assert_eq!(outcomes.into_outcome(), Mistake(vec!["busy"]));
+  /// ```
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub fn into_outcome(self) -> Outcome<Vec<S>, Vec<M>, Vec<F>> {
+    if !self.failures.is_empty() {
+      Failure(self.failures)
+    } else if !self.mistakes.is_empty() {
+      Mistake(self.mistakes)
+    } else {
+      Success(self.successes)
+    }
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, M, F> Default for Outcomes<S, M, F> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, M, F> Extend<Outcome<S, M, F>> for Outcomes<S, M, F> {
+  fn extend<I: IntoIterator<Item = Outcome<S, M, F>>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|outcome| self.push(outcome));
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, M, F> FromIterator<Outcome<S, M, F>> for Outcomes<S, M, F> {
+  fn from_iter<I: IntoIterator<Item = Outcome<S, M, F>>>(iter: I) -> Self {
+    let mut outcomes = Self::new();
+    outcomes.extend(iter);
+    outcomes
+  }
+}
+
+/// Every [`Mistake`] and [`Failure`] found while validating a value.
+///
+/// Errors are pushed as they are found, and [`finish`] then turns the
+/// collection into an [`Outcome`] according to the most severe error pushed.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::Aberrations;
+///
+/// fn validate(name: &str) -> Outcome<&str, Vec<&'static str>, Vec<&'static str>> {
+///   let mut errors = Aberrations::new();
+///   if name.is_empty() {
+///     errors.push_failure("name is empty");
+///   }
+///   if name.len() > 8 {
+///     errors.push_mistake("name is too long");
+///   }
+///   if name.chars().any(char::is_uppercase) {
+///     errors.push_mistake("name is not lowercase");
+///   }
+///   errors.finish(name)
+/// }
+///
+/// assert_eq!(validate("outcome"), Success("outcome"));
+/// assert_eq!(validate(""), Failure(vec!["name is empty"]));
+/// assert_eq!(
+///   validate("Aberrations"),
+///   Mistake(vec!["name is too long", "name is not lowercase"])
+/// );
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`finish`]: Aberrations::finish
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Aberrations<M, F> {
+  /// Every [`Mistake`](crate::prelude::Mistake) value
+  pub mistakes: Vec<M>,
+  /// Every [`Failure`](crate::prelude::Failure) value
+  pub failures: Vec<F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<M, F> Aberrations<M, F> {
+  /// Creates an empty collection.
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      mistakes: Vec::new(),
+      failures: Vec::new(),
+    }
+  }
+
+  /// Adds the value of `aberration` to the collection for its state.
+  #[inline]
+  pub fn push(&mut self, aberration: Aberration<M, F>) {
+    match aberration {
+      Aberration::Mistake(m) => self.mistakes.push(m),
+      Aberration::Failure(f) => self.failures.push(f),
+    }
+  }
+
+  /// Adds a [`Mistake`](crate::prelude::Mistake) to the collection.
+  #[inline]
+  pub fn push_mistake(&mut self, mistake: M) {
+    self.mistakes.push(mistake);
+  }
+
+  /// Adds a [`Failure`](crate::prelude::Failure) to the collection.
+  #[inline]
+  pub fn push_failure(&mut self, failure: F) {
+    self.failures.push(failure);
+  }
+
+  /// Returns the total number of errors collected.
+  #[must_use]
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.mistakes.len() + self.failures.len()
+  }
+
+  /// Returns `true` if no errors have been collected.
+  #[must_use]
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Converts the collection into an [`Outcome`], according to the most
+  /// severe error collected.
+  ///
+  /// This is a [`Failure`] containing every failure if any were collected,
+  /// otherwise a [`Mistake`] containing every mistake if any were collected,
+  /// and `Success(success)` otherwise.
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::Aberrations;
+  ///
+  /// let mut errors: Aberrations<&str, &str> = Aberrations::new();
+  /// assert_eq!(errors.clone().finish(47), Success(47));
+  ///
+  /// errors.push(Aberration::Mistake("busy"));
+  /// assert_eq!(errors.clone().finish(47), Mistake(vec!["busy"]));
+  ///
+  /// errors.push(Aberration::Failure("gone"));
+  /// assert_eq!(errors.finish(47), Failure(vec!["gone"]));
+  /// ```
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  pub fn finish<S>(self, success: S) -> Outcome<S, Vec<M>, Vec<F>> {
+    if !self.failures.is_empty() {
+      Failure(self.failures)
+    } else if !self.mistakes.is_empty() {
+      Mistake(self.mistakes)
+    } else {
+      Success(success)
+    }
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, F> Default for Aberrations<M, F> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, F> Extend<Aberration<M, F>> for Aberrations<M, F> {
+  fn extend<I: IntoIterator<Item = Aberration<M, F>>>(&mut self, iter: I) {
+    iter
+      .into_iter()
+      .for_each(|aberration| self.push(aberration));
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<M, F> FromIterator<Aberration<M, F>> for Aberrations<M, F> {
+  fn from_iter<I: IntoIterator<Item = Aberration<M, F>>>(iter: I) -> Self {
+    let mut aberrations = Self::new();
+    aberrations.extend(iter);
+    aberrations
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #[cfg(feature = "std")]
@@ -210,6 +956,29 @@ mod tests {
     assert_eq!(failure, []);
   }
 
+  #[cfg(feature = "std")]
+  #[test]
+  fn collect_short_circuits() {
+    let mut taken = 0;
+    let outcomes = [Success(1), Failure("stop"), Mistake("never")];
+    let outcome: Outcome<Vec<i32>, &str, &str> =
+      outcomes.into_iter().inspect(|_| taken += 1).collect();
+    assert_eq!(outcome, Failure("stop"));
+    assert_eq!(taken, 2);
+  }
+
+  #[test]
+  fn shunt_stops_at_first_error() {
+    let mut taken = 0;
+    let outcomes = [Success(1), Mistake("busy"), Success(2)];
+    let outcome = outcomes
+      .into_iter()
+      .inspect(|_| taken += 1)
+      .shunt(|successes| successes.count());
+    assert_eq!(outcome, Mistake::<usize, _, ()>("busy"));
+    assert_eq!(taken, 2);
+  }
+
   #[test]
   fn size_hint() {
     assert_eq!((1, Some(1)), Success::<i32, (), ()>(1).iter().size_hint());
@@ -0,0 +1,86 @@
+//! Classification of [`kube`] client errors.
+//!
+//! Reconcile loops in a Kubernetes operator are expected to retry transient
+//! errors, and to surface everything else. This module implements
+//! [`Classify`] for [`kube_client::Error`] and [`ErrorResponse`], so that
+//! [`ResultExt::into_outcome`] produces the following:
+//!
+//!  - [`Mistake`]: `409 Conflict`, `410 Gone` (the watch has desynchronized
+//!    and must be restarted), `429 Too Many Requests`, `503 Service
+//!    Unavailable`, `504 Gateway Timeout`, and connection errors, such as a
+//!    connection reset or timeout.
+//!  - [`Failure`]: every other API error, which includes validation errors and
+//!    rejections by admission webhooks, as well as configuration and
+//!    (de)serialization errors.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use kube_client::{error::ErrorResponse, Error};
+//! use outcome::ext::ResultExt;
+//!
+//! fn response(code: u16, reason: &str) -> Result<(), Error> {
+//!   Err(Error::Api(ErrorResponse {
+//!     status: "Failure".into(),
+//!     message: String::new(),
+//!     reason: reason.into(),
+//!     code,
+//!   }))
+//! }
+//!
+//! assert!(response(409, "Conflict").into_outcome().is_mistake());
+//! assert!(response(422, "Invalid").into_outcome().is_failure());
+//! ```
+//!
+//! [`kube`]: https://crates.io/crates/kube
+//! [`Classify`]: crate::ext::Classify
+//! [`ResultExt::into_outcome`]: crate::ext::ResultExt::into_outcome
+//! [`ErrorResponse`]: kube_client::error::ErrorResponse
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use kube_client::{error::ErrorResponse, Error};
+
+use crate::ext::Classify;
+
+impl Classify for ErrorResponse {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    matches!(self.code, 409 | 410 | 429 | 503 | 504)
+  }
+
+  #[inline]
+  fn kind(&self) -> Option<&str> {
+    Some(&self.reason)
+  }
+
+  #[inline]
+  fn code(&self) -> Option<i64> {
+    Some(self.code.into())
+  }
+}
+
+impl Classify for Error {
+  fn is_mistake(&self) -> bool {
+    match self {
+      Self::Api(response) => response.is_mistake(),
+      Self::HyperError(error) => !(error.is_parse() || error.is_user()),
+      Self::Service(_) | Self::ReadEvents(_) => true,
+      _ => false,
+    }
+  }
+
+  fn kind(&self) -> Option<&str> {
+    match self {
+      Self::Api(response) => response.kind(),
+      _ => None,
+    }
+  }
+
+  fn code(&self) -> Option<i64> {
+    match self {
+      Self::Api(response) => response.code(),
+      _ => None,
+    }
+  }
+}
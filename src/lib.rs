@@ -31,7 +31,8 @@
   all(nightly, feature = "nightly"),
   feature(try_trait_v2),
   feature(never_type),
-  feature(exhaustive_patterns)
+  feature(exhaustive_patterns),
+  feature(error_generic_member_access)
 )]
 #![cfg_attr(any(docsrs, nightly), feature(doc_cfg))]
 #![no_std]
@@ -39,6 +40,9 @@
 #[cfg(doc)]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "unstable")))]
 #[cfg(feature = "unstable")]
 mod unstable;
@@ -48,18 +52,143 @@ mod unstable;
 mod nightly;
 
 mod aberration;
+#[cfg(feature = "std")]
+mod checked;
 mod concern;
+mod macros;
 mod outcome;
 mod private;
+mod severity;
+#[cfg(feature = "alloc")]
+mod shared;
 
 mod iter;
 
 #[cfg(any(feature = "report", feature = "diagnostic"))]
 mod wrap;
 
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "anyhow")))]
+#[cfg(feature = "anyhow")]
+pub mod anyhow;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod backend;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "bevy")))]
+#[cfg(feature = "bevy")]
+pub mod bevy;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod budget;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "tokio")))]
+#[cfg(feature = "tokio")]
+pub mod cancel;
+
+pub mod cell;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod channel;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod classify;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod context;
+
 pub mod convert;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+pub mod envelope;
+
+pub mod ext;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod extensions;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "frunk")))]
+#[cfg(feature = "frunk")]
+pub mod frunk;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "futures")))]
+#[cfg(feature = "futures")]
+pub mod future;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "higher")))]
+#[cfg(feature = "higher")]
+pub mod higher;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub mod pipeline;
+
 pub mod prelude;
 
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod process;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "rayon")))]
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
+pub mod retry;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "async-graphql")))]
+#[cfg(feature = "async-graphql")]
+pub mod graphql;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod hooks;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "kube")))]
+#[cfg(feature = "kube")]
+pub mod kube;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "log")))]
+#[cfg(feature = "log")]
+pub mod log;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod net;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "panic-payload")))]
+#[cfg(feature = "panic-payload")]
+pub mod panic;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "test-util")))]
+#[cfg(feature = "test-util")]
+pub mod testing;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod thread;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "tracing")))]
+#[cfg(feature = "tracing")]
+pub mod tracing;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub mod validate;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "tungstenite")))]
+#[cfg(feature = "tungstenite")]
+pub mod websocket;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "uniffi")))]
+#[cfg(feature = "uniffi")]
+pub mod ffi;
+
 #[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "report")))]
 #[cfg(feature = "report")]
 pub mod report;
@@ -69,4 +198,167 @@ pub mod report;
 pub mod diagnostic;
 
 #[cfg_attr(doc, doc(inline))]
-pub use crate::{aberration::*, concern::*, convert::*, iter::*, outcome::*};
+pub use crate::{
+  aberration::*, concern::*, convert::*, iter::*, outcome::*, severity::*,
+};
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg_attr(doc, doc(inline))]
+#[cfg(feature = "std")]
+pub use crate::checked::*;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg_attr(doc, doc(inline))]
+#[cfg(feature = "alloc")]
+pub use crate::shared::*;
+
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use outcome_macros::AttemptNew;
+
+/// Splits an error enum into separate [`Mistake`] and [`Failure`] enums.
+///
+/// Each variant is marked with `#[aberrant(mistake)]` or
+/// `#[aberrant(failure)]`. Unmarked variants are treated as failures. For an
+/// enum named `Error`, the following items are generated with the same
+/// visibility:
+///
+///  - `ErrorMistake` and `ErrorFailure` enums, containing the variants for
+///    each channel.
+///  - An `ErrorAberration` alias for `Aberration<ErrorMistake, ErrorFailure>`.
+///  - `From` conversions from `Error` to `ErrorAberration`, and from each of
+///    `ErrorAberration`, `ErrorMistake`, and `ErrorFailure` back to `Error`.
+///
+/// The generated names may be changed with `#[aberrant(mistake = Name)]` and
+/// `#[aberrant(failure = Name)]` on the enum, and derives may be added to the
+/// generated enums with `#[aberrant(derive(...))]`. Generic enums are not
+/// supported.
+///
+/// # Examples
+///
+/// ```
+/// use outcome::prelude::*;
+/// use outcome::Aberrant;
+///
+/// #[derive(Aberrant, Debug, PartialEq)]
+/// #[aberrant(derive(Debug, PartialEq))]
+/// enum FetchError {
+///   #[aberrant(mistake)]
+///   Timeout { seconds: u32 },
+///   #[aberrant(mistake)]
+///   RateLimited,
+///   NotFound(String),
+/// }
+///
+/// let split = FetchErrorAberration::from(FetchError::RateLimited);
+/// assert_eq!(split, Aberration::Mistake(FetchErrorMistake::RateLimited));
+///
+/// let error = FetchError::from(FetchErrorFailure::NotFound("/".into()));
+/// assert_eq!(error, FetchError::NotFound("/".into()));
+///
+/// let error = FetchError::from(FetchErrorAberration::Mistake(
+///   FetchErrorMistake::Timeout { seconds: 30 },
+/// ));
+/// assert_eq!(error, FetchError::Timeout { seconds: 30 });
+/// ```
+///
+/// The generated enums and alias share the visibility of the original enum,
+/// so a public error type produces public halves:
+///
+/// ```
+/// use outcome::prelude::*;
+///
+/// pub mod errors {
+///   use outcome::Aberrant;
+///
+///   #[derive(Aberrant, Debug)]
+///   #[aberrant(derive(Debug, PartialEq))]
+///   pub enum StoreError {
+///     #[aberrant(mistake)]
+///     Locked,
+///     Corrupt,
+///   }
+/// }
+///
+/// use errors::{StoreError, StoreErrorAberration, StoreErrorFailure};
+///
+/// let split = StoreErrorAberration::from(StoreError::Corrupt);
+/// assert_eq!(split, Aberration::Failure(StoreErrorFailure::Corrupt));
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use outcome_macros::Aberrant;
+
+/// Allows the `?` operator to be used on an [`Outcome`] in a function that
+/// returns a [`Result`].
+///
+/// The function is written as returning an `Outcome<S, M, F>`, and its
+/// signature is rewritten to return a `Result<Concern<S, M>, F>`, as
+/// [`Outcome::acclimate`] does. Within the body, `?` may be applied to an
+/// [`Outcome`] as well as a [`Result`]: a [`Failure`] is converted with
+/// [`From`] and returned, while a [`Success`] or [`Mistake`] is given to the
+/// surrounding code as a [`Concern`]. The value of the body, and of any
+/// `return` expression, is an [`Outcome`], which is acclimated on return.
+///
+/// Closures, async blocks, and nested items within the body are left as is.
+///
+/// # Examples
+///
+/// ```
+/// use outcome::prelude::*;
+/// use outcome::acclimated;
+///
+/// fn connect(port: u16) -> Outcome<u16, &'static str, String> {
+///   match port {
+///     0 => Failure("no port given".into()),
+///     1..=1023 => Mistake("port is reserved"),
+///     port => Success(port),
+///   }
+/// }
+///
+/// #[acclimated]
+/// pub fn listen(port: &str) -> Outcome<u16, &'static str, String> {
+///   if port.is_empty() {
+///     return Mistake("no port given");
+///   }
+///   let port = port.parse::<u16>().map_err(|e| e.to_string())?;
+///   match connect(port)? {
+///     Concern::Success(port) => Success(port),
+///     Concern::Mistake(_) => connect(port + 8000),
+///   }
+/// }
+///
+/// let port: Result<Concern<u16, &str>, String> = listen("4000");
+/// assert_eq!(port, Ok(Concern::Success(4000)));
+/// assert_eq!(listen("80"), Ok(Concern::Success(8080)));
+/// assert_eq!(listen("0"), Err("no port given".into()));
+/// assert_eq!(listen(""), Ok(Concern::Mistake("no port given")));
+/// assert!(listen("http").is_err());
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Outcome::acclimate`]: crate::prelude::Outcome::acclimate
+/// [`Concern`]: crate::prelude::Concern
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Result`]: core::result::Result
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use outcome_macros::acclimated;
+
+#[doc(hidden)]
+pub mod __private {
+  #[cfg(feature = "std")]
+  pub use crate::private::contextualize;
+  #[cfg(feature = "derive")]
+  pub use crate::private::Acclimate;
+  pub use crate::private::{failure, mistake};
+  #[cfg(feature = "report")]
+  pub use ::eyre;
+  #[cfg(feature = "diagnostic")]
+  pub use ::miette;
+}
@@ -0,0 +1,144 @@
+//! Integration with the [`log`] crate.
+//!
+//! When the `log` feature is enabled, [`Outcome`] and [`Aberration`] gain
+//! `log_mistake`, `log_failure`, and `log_error` methods. Each logs the
+//! [`Debug`] representation of the contained value, prefixed with the name of
+//! its variant, and returns `self` unchanged, so that a call chain can be
+//! instrumented without being split apart.
+//!
+//! Additionally, the value discarded by [`Outcome::ok_mistake`] or
+//! [`Outcome::ok_failure`] is logged at the debug level, with the `outcome`
+//! target.
+//!
+//! For projects using `tracing` instead, see the [`tracing`] module.
+//!
+//! This module does not require `std`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use log::Level;
+//!
+//! fn connect() -> Outcome<u16, &'static str, &'static str> {
+//!   Mistake("connection refused")
+//! }
+//!
+//! // Logs "Mistake: \"connection refused\"" at the warn level
+//! let port = connect()
+//!   .log_mistake("client", Level::Warn)
+//!   .log_failure("client", Level::Error);
+//! assert_eq!(port, Mistake("connection refused"));
+//! ```
+//!
+//! [`log`]: https://docs.rs/log
+//! [`tracing`]: crate::tracing
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Outcome::ok_mistake`]: crate::prelude::Outcome::ok_mistake
+//! [`Outcome::ok_failure`]: crate::prelude::Outcome::ok_failure
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Debug`]: core::fmt::Debug
+use core::fmt::Debug;
+
+use ::log::Level;
+
+use crate::prelude::*;
+
+impl<S, M: Debug, F> Outcome<S, M, F> {
+  /// Logs a contained [`Mistake`] value to `target` at `level`, and returns
+  /// the outcome unchanged.
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[inline]
+  pub fn log_mistake(self, target: &str, level: Level) -> Self {
+    if let Mistake(value) = &self {
+      ::log::log!(target: target, level, "Mistake: {value:?}");
+    }
+    self
+  }
+}
+
+impl<S, M, F: Debug> Outcome<S, M, F> {
+  /// Logs a contained [`Failure`] value to `target` at `level`, and returns
+  /// the outcome unchanged.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn log_failure(self, target: &str, level: Level) -> Self {
+    if let Failure(value) = &self {
+      ::log::log!(target: target, level, "Failure: {value:?}");
+    }
+    self
+  }
+}
+
+impl<S, M: Debug, F: Debug> Outcome<S, M, F> {
+  /// Logs a contained [`Mistake`] or [`Failure`] value to `target` at
+  /// `level`, and returns the outcome unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use log::Level;
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("disk is full");
+  /// let x = x.log_error("storage", Level::Error);
+  /// assert_eq!(x, Failure("disk is full"));
+  /// ```
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  #[inline]
+  pub fn log_error(self, target: &str, level: Level) -> Self {
+    self.log_mistake(target, level).log_failure(target, level)
+  }
+}
+
+impl<M: Debug, F> Aberration<M, F> {
+  /// Logs a contained [`Mistake`] value to `target` at `level`, and returns
+  /// the aberration unchanged.
+  ///
+  /// [`Mistake`]: Aberration::Mistake
+  #[inline]
+  pub fn log_mistake(self, target: &str, level: Level) -> Self {
+    if let Self::Mistake(value) = &self {
+      ::log::log!(target: target, level, "Mistake: {value:?}");
+    }
+    self
+  }
+}
+
+impl<M, F: Debug> Aberration<M, F> {
+  /// Logs a contained [`Failure`] value to `target` at `level`, and returns
+  /// the aberration unchanged.
+  ///
+  /// [`Failure`]: Aberration::Failure
+  #[inline]
+  pub fn log_failure(self, target: &str, level: Level) -> Self {
+    if let Self::Failure(value) = &self {
+      ::log::log!(target: target, level, "Failure: {value:?}");
+    }
+    self
+  }
+}
+
+impl<M: Debug, F: Debug> Aberration<M, F> {
+  /// Logs the contained value to `target` at `level`, and returns the
+  /// aberration unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use log::Level;
+  ///
+  /// let x: Aberration<&str, &str> = Aberration::Mistake("busy");
+  /// let x = x.log_error("storage", Level::Warn);
+  /// assert_eq!(x, Aberration::Mistake("busy"));
+  /// ```
+  #[inline]
+  pub fn log_error(self, target: &str, level: Level) -> Self {
+    self.log_mistake(target, level).log_failure(target, level)
+  }
+}
@@ -0,0 +1,338 @@
+/// Returns whether an [`Outcome`] matches the given pattern.
+///
+/// This is the [`matches!`] macro, with the [`Success`], [`Mistake`], and
+/// [`Failure`] variants brought into scope for the pattern. As with
+/// [`matches!`], the pattern may be followed by `if` and a guard expression.
+///
+/// A variant name may also be given without any parentheses, in which case
+/// only the variant is checked, regardless of its value. As with
+/// [`matches!`], the expression is not moved unless the pattern binds a value
+/// by move.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::outcome_matches;
+///
+/// let x: Outcome<u32, &str, &str> = Success(47);
+/// assert!(outcome_matches!(x, Success(47)));
+/// assert!(outcome_matches!(x, Success(n) if n > 40));
+/// assert!(outcome_matches!(x, Success(_) | Mistake(_)));
+/// assert!(!outcome_matches!(x, Failure));
+///
+/// let x: Outcome<u32, &str, &str> = Mistake("busy");
+/// assert!(outcome_matches!(x, Mistake));
+/// ```
+///
+/// The variants do not need to be imported to be used in the pattern:
+///
+/// ```
+/// use outcome::{outcome_matches, Outcome};
+///
+/// let x: Outcome<(), u8, &str> = Outcome::Mistake(3);
+/// assert!(outcome_matches!(x, Mistake(1..=5)));
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! outcome_matches {
+  ($expression:expr, Success $(,)?) => {
+    $crate::outcome_matches!($expression, Success(_))
+  };
+  ($expression:expr, Mistake $(,)?) => {
+    $crate::outcome_matches!($expression, Mistake(_))
+  };
+  ($expression:expr, Failure $(,)?) => {
+    $crate::outcome_matches!($expression, Failure(_))
+  };
+  ($expression:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+    #[allow(unused_imports)]
+    use $crate::prelude::{Failure, Mistake, Success};
+    ::core::matches!($expression, $pattern $(if $guard)?)
+  }};
+}
+
+/// Unwraps the [`Concern`] of an [`Outcome`], or returns its [`Failure`]
+/// from the enclosing function.
+///
+/// Until the [`Try`] trait is stabilized, the `?` operator cannot be used
+/// on an [`Outcome`] directly. The usual workaround is
+/// `outcome.acclimate()?`, which this macro expands to: a [`Failure`] is
+/// converted with [`From`] and returned as an [`Err`], while a [`Success`]
+/// or [`Mistake`] is given to the surrounding code as a [`Concern`]. As
+/// such, it may be used in any function that returns a [`Result`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::attempt;
+///
+/// fn connect(port: u16) -> Outcome<u16, &'static str, u8> {
+///   match port {
+///     0 => Failure(1),
+///     1..=1023 => Mistake("port is reserved"),
+///     port => Success(port),
+///   }
+/// }
+///
+/// fn invoke(port: u16) -> Result<u16, u32> {
+///   Ok(match attempt!(connect(port)) {
+///     Concern::Success(port) => port,
+///     Concern::Mistake(_) => 8080,
+///   })
+/// }
+///
+/// assert_eq!(invoke(4000), Ok(4000));
+/// assert_eq!(invoke(80), Ok(8080));
+/// assert_eq!(invoke(0), Err(1));
+/// ```
+///
+/// [`Try`]: core::ops::Try
+/// [`Result`]: core::result::Result
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Concern`]: crate::prelude::Concern
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! attempt {
+  ($expression:expr $(,)?) => {
+    match $crate::prelude::Outcome::acclimate($expression) {
+      ::core::result::Result::Ok(concern) => concern,
+      ::core::result::Result::Err(failure) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+          failure,
+        ));
+      }
+    }
+  };
+}
+
+/// Builds an [`Outcome`] from a ladder of conditions.
+///
+/// Each condition is checked in order, and the value of the first one that
+/// holds is produced. The final `else` arm is required, so that some value is
+/// always produced. The [`Success`], [`Mistake`], and [`Failure`] variants
+/// are brought into scope for every arm.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::outcome;
+///
+/// fn classify(status: u16) -> Outcome<u16, u16, u16> {
+///   outcome!(
+///     if status < 400 => Success(status);
+///     else if status == 429 || status == 503 => Mistake(status);
+///     else => Failure(status)
+///   )
+/// }
+///
+/// assert_eq!(classify(200), Success(200));
+/// assert_eq!(classify(503), Mistake(503));
+/// assert_eq!(classify(404), Failure(404));
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! outcome {
+  (
+    if $condition:expr => $value:expr;
+    $(else if $conditions:expr => $values:expr;)*
+    else => $otherwise:expr $(;)?
+  ) => {{
+    #[allow(unused_imports)]
+    use $crate::prelude::{Failure, Mistake, Success};
+    if $condition {
+      $value
+    } $(else if $conditions {
+      $values
+    })* else {
+      $otherwise
+    }
+  }};
+}
+
+/// Returns a [`Success`] from the enclosing function.
+///
+/// Without a value, `()` is returned as the [`Success`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::success;
+///
+/// fn lookup(cache: &[u32], index: usize) -> Outcome<u32, (), ()> {
+///   if let Some(value) = cache.get(index) {
+///     success!(*value);
+///   }
+///   Mistake(())
+/// }
+///
+/// assert_eq!(lookup(&[47], 0), Success(47));
+/// assert_eq!(lookup(&[47], 1), Mistake(()));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+#[macro_export]
+macro_rules! success {
+  () => {
+    return $crate::prelude::Outcome::Success(())
+  };
+  ($value:expr $(,)?) => {
+    return $crate::prelude::Outcome::Success($value)
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function.
+///
+/// As with the `?` operator, the value is converted with [`From`]. Without a
+/// value, `()` is returned as the [`Mistake`]. When the `std` feature is
+/// enabled, the registered mistake hook is invoked, as with
+/// [`Outcome::mistake_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::mistake;
+///
+/// fn reserve(available: u32, count: u32) -> Outcome<u32, String, ()> {
+///   if count > available {
+///     mistake!("not enough seats");
+///   }
+///   Success(available - count)
+/// }
+///
+/// assert_eq!(reserve(3, 2), Success(1));
+/// assert_eq!(reserve(1, 2), Mistake("not enough seats".to_string()));
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`From`]: core::convert::From
+/// [`Outcome::mistake_hooked`]: crate::prelude::Outcome::mistake_hooked
+#[macro_export]
+macro_rules! mistake {
+  () => {
+    return $crate::__private::mistake(())
+  };
+  ($value:expr $(,)?) => {
+    return $crate::__private::mistake(::core::convert::From::from($value))
+  };
+}
+
+/// Returns a [`Failure`] from the enclosing function.
+///
+/// As with the `?` operator, the value is converted with [`From`]. Without a
+/// value, `()` is returned as the [`Failure`]. When the `std` feature is
+/// enabled, the registered failure hook is invoked, as with
+/// [`Outcome::failure_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::failure;
+/// use std::io::{Error, ErrorKind};
+///
+/// fn open(path: &str) -> Outcome<&str, (), Error> {
+///   if path.is_empty() {
+///     failure!(ErrorKind::NotFound);
+///   }
+///   Success(path)
+/// }
+///
+/// assert!(open("").is_failure());
+/// assert!(open("config.toml").is_success());
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`From`]: core::convert::From
+/// [`Outcome::failure_hooked`]: crate::prelude::Outcome::failure_hooked
+#[macro_export]
+macro_rules! failure {
+  () => {
+    return $crate::__private::failure(())
+  };
+  ($value:expr $(,)?) => {
+    return $crate::__private::failure(::core::convert::From::from($value))
+  };
+}
+
+/// Returns a [`Failure`] from the enclosing function if a condition does not
+/// hold.
+///
+/// This is shorthand for `if !condition { failure!(value) }`, and so the
+/// value is converted with [`From`]. See [`ensure_or_mistake!`] for
+/// conditions that are worth retrying.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::ensure;
+///
+/// fn withdraw(balance: u32, amount: u32) -> Outcome<u32, (), String> {
+///   ensure!(amount > 0, "amount must be positive");
+///   ensure!(amount <= balance, format!("balance is only {balance}"));
+///   Success(balance - amount)
+/// }
+///
+/// assert_eq!(withdraw(10, 3), Success(7));
+/// assert_eq!(withdraw(10, 0), Failure("amount must be positive".into()));
+/// assert_eq!(withdraw(2, 3), Failure("balance is only 2".into()));
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`From`]: core::convert::From
+/// [`ensure_or_mistake!`]: crate::ensure_or_mistake
+#[macro_export]
+macro_rules! ensure {
+  ($condition:expr, $value:expr $(,)?) => {
+    if !$condition {
+      $crate::failure!($value);
+    }
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function if a condition does not
+/// hold.
+///
+/// This is shorthand for `if !condition { mistake!(value) }`, and so the
+/// value is converted with [`From`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::ensure_or_mistake;
+///
+/// fn send(queued: usize, capacity: usize) -> Outcome<usize, &'static str, ()> {
+///   ensure_or_mistake!(queued < capacity, "queue is full");
+///   Success(queued + 1)
+/// }
+///
+/// assert_eq!(send(1, 4), Success(2));
+/// assert_eq!(send(4, 4), Mistake("queue is full"));
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`From`]: core::convert::From
+#[macro_export]
+macro_rules! ensure_or_mistake {
+  ($condition:expr, $value:expr $(,)?) => {
+    if !$condition {
+      $crate::mistake!($value);
+    }
+  };
+}
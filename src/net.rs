@@ -0,0 +1,164 @@
+//! Extension traits for non-blocking networking.
+//!
+//! When a socket is placed in non-blocking mode, an operation that cannot
+//! complete immediately returns an error of kind [`WouldBlock`]. This is not
+//! an error at all, but a request to try again later, which is exactly what a
+//! [`Mistake`] represents. The traits in this module provide `attempt_*`
+//! methods for [`TcpListener`], [`TcpStream`], and [`UdpSocket`] that classify
+//! their errors with [`Classify`], so that event loops can match on the
+//! returned [`Outcome`] directly.
+//!
+//! These traits are sealed and cannot be implemented for types outside of
+//! `outcome`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::net::TcpListenerExt;
+//! use std::net::TcpListener;
+//!
+//! let listener = TcpListener::bind("127.0.0.1:0")?;
+//! listener.set_nonblocking(true)?;
+//!
+//! match listener.attempt_accept() {
+//!   Success((_stream, address)) => println!("accepted {address}"),
+//!   Mistake(_) => { /* nobody is connecting yet, poll again later */ }
+//!   Failure(error) => return Err(error),
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`WouldBlock`]: std::io::ErrorKind::WouldBlock
+//! [`TcpListener`]: std::net::TcpListener
+//! [`TcpStream`]: std::net::TcpStream
+//! [`UdpSocket`]: std::net::UdpSocket
+//! [`Classify`]: crate::ext::Classify
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Mistake`]: crate::prelude::Mistake
+extern crate std;
+
+use std::{
+  io::{Error, Read, Write},
+  net::{SocketAddr, TcpListener, TcpStream, UdpSocket},
+};
+
+use crate::{ext::ResultExt, prelude::*};
+
+/// Extension trait for [`TcpListener`].
+///
+/// [`TcpListener`]: std::net::TcpListener
+pub trait TcpListenerExt: crate::private::Sealed {
+  /// Attempts to accept a new incoming connection.
+  ///
+  /// See [`TcpListener::accept`] for details.
+  ///
+  /// [`TcpListener::accept`]: std::net::TcpListener::accept
+  fn attempt_accept(&self) -> Outcome<(TcpStream, SocketAddr), Error, Error>;
+}
+
+/// Extension trait for [`TcpStream`].
+///
+/// [`TcpStream`]: std::net::TcpStream
+pub trait TcpStreamExt: crate::private::Sealed {
+  /// Attempts to read bytes into `buffer`, returning how many were read.
+  ///
+  /// See [`Read::read`] for details.
+  ///
+  /// [`Read::read`]: std::io::Read::read
+  fn attempt_read(&mut self, buffer: &mut [u8])
+    -> Outcome<usize, Error, Error>;
+
+  /// Attempts to write bytes from `buffer`, returning how many were written.
+  ///
+  /// See [`Write::write`] for details.
+  ///
+  /// [`Write::write`]: std::io::Write::write
+  fn attempt_write(&mut self, buffer: &[u8]) -> Outcome<usize, Error, Error>;
+}
+
+/// Extension trait for [`UdpSocket`].
+///
+/// [`UdpSocket`]: std::net::UdpSocket
+pub trait UdpSocketExt: crate::private::Sealed {
+  /// Attempts to receive a datagram from the connected peer.
+  ///
+  /// See [`UdpSocket::recv`] for details.
+  ///
+  /// [`UdpSocket::recv`]: std::net::UdpSocket::recv
+  fn attempt_recv(&self, buffer: &mut [u8]) -> Outcome<usize, Error, Error>;
+
+  /// Attempts to receive a datagram, returning its length and sender.
+  ///
+  /// See [`UdpSocket::recv_from`] for details.
+  ///
+  /// [`UdpSocket::recv_from`]: std::net::UdpSocket::recv_from
+  fn attempt_recv_from(
+    &self,
+    buffer: &mut [u8],
+  ) -> Outcome<(usize, SocketAddr), Error, Error>;
+}
+
+impl TcpListenerExt for TcpListener {
+  #[inline]
+  fn attempt_accept(&self) -> Outcome<(TcpStream, SocketAddr), Error, Error> {
+    self.accept().into_outcome()
+  }
+}
+
+impl TcpStreamExt for TcpStream {
+  #[inline]
+  fn attempt_read(
+    &mut self,
+    buffer: &mut [u8],
+  ) -> Outcome<usize, Error, Error> {
+    self.read(buffer).into_outcome()
+  }
+
+  #[inline]
+  fn attempt_write(&mut self, buffer: &[u8]) -> Outcome<usize, Error, Error> {
+    self.write(buffer).into_outcome()
+  }
+}
+
+impl UdpSocketExt for UdpSocket {
+  #[inline]
+  fn attempt_recv(&self, buffer: &mut [u8]) -> Outcome<usize, Error, Error> {
+    self.recv(buffer).into_outcome()
+  }
+
+  #[inline]
+  fn attempt_recv_from(
+    &self,
+    buffer: &mut [u8],
+  ) -> Outcome<(usize, SocketAddr), Error, Error> {
+    self.recv_from(buffer).into_outcome()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::ErrorKind;
+
+  #[test]
+  fn would_block_is_mistake() {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket.set_nonblocking(true).unwrap();
+    let mut buffer = [0; 16];
+    let error = socket.attempt_recv_from(&mut buffer).unwrap_mistake();
+    assert_eq!(error.kind(), ErrorKind::WouldBlock);
+  }
+
+  #[test]
+  fn round_trip() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut client =
+      TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (mut server, _) = listener.attempt_accept().unwrap();
+    assert_eq!(client.attempt_write(b"ping").unwrap(), 4);
+    let mut buffer = [0; 4];
+    assert_eq!(server.attempt_read(&mut buffer).unwrap(), 4);
+    assert_eq!(&buffer, b"ping");
+  }
+}
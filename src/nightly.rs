@@ -6,12 +6,13 @@ use core::{
 };
 #[cfg(feature = "std")]
 use std::{
-  eprintln,
   fmt::Debug,
   process::{ExitCode, Termination},
 };
 
 use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::private::terminate;
 
 /* feature(never_type) */
 impl<S, M, F> Outcome<S, M, F> {
@@ -154,12 +155,10 @@ impl<S: Into<!>, M: Into<!>, F> Outcome<S, M, F> {
 #[cfg(feature = "std")]
 impl<M: Debug, F: Debug> Termination for Outcome<!, M, F> {
   fn report(self) -> ExitCode {
-    #[allow(clippy::print_stderr)]
     match self {
-      Mistake(m) => eprintln!("Mistake: {:?}", m),
-      Failure(f) => eprintln!("Failure: {:?}", f),
-    };
-    ExitCode::FAILURE
+      Mistake(m) => terminate("Mistake", &m),
+      Failure(f) => terminate("Failure", &f),
+    }
   }
 }
 
@@ -1,26 +1,23 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+
 use core::{
-  fmt::Debug,
-  ops::{Deref, DerefMut},
+  convert::Infallible,
+  fmt::{self, Debug, Display, Formatter},
+  ops::{ControlFlow, Deref, DerefMut},
+  pin::Pin,
+  slice,
 };
 
 #[doc(hidden)]
 pub use crate::iter::*;
-use crate::{aberration::*, concern::*, private::*};
+use crate::{aberration::*, concern::*, private::*, severity::*};
 
 #[cfg(feature = "std")]
-use std::{
-  eprintln,
-  process::{ExitCode, Termination},
-};
-
-// TODO: Add an 'aggregate' set of functions (aggregate(_(mistake|failure))?)
-// to collect all success, mistake or failure into iterators/partition an
-// iterable of failures, concerns, mistakes, etc.
-//
-// TODO: Add an aggregate_reports function in crate::report
+use std::process::{ExitCode, Termination};
 
 /// `Outcome` is a type that represents a [`Success`], [`Mistake`], or
 /// [`Failure`].
@@ -40,14 +37,7 @@ use std::{
 /// ```
 /// # use outcome::prelude::*;
 /// use std::sync::{Mutex, MutexGuard, PoisonError, LockResult, TryLockError};
-/// #[cfg(target_arch = "x86_64")]
-/// use std::arch::x86_64::_mm_pause;
-/// #[cfg(target_arch = "x86")]
-/// use std::arch::x86::_mm_pause;
-///
-/// #[cfg(not(any(target_arch = "x86_64", target_arch="x86")))]
-/// #[inline(never)]
-/// unsafe fn _mm_pause() { }
+/// use std::hint::spin_loop;
 ///
 /// struct WouldBlock;
 ///
@@ -62,7 +52,7 @@ use std::{
 /// >;
 ///
 /// impl<T> SpinMutex<T> {
-///   pub fn try_lock(&self) -> TryLockOutcome<T> {
+///   pub fn try_lock(&self) -> TryLockOutcome<'_, T> {
 ///     match self.inner.try_lock() {
 ///       Err(TryLockError::Poisoned(f)) => Failure(f),
 ///       Err(TryLockError::WouldBlock) => Mistake(WouldBlock),
@@ -82,7 +72,7 @@ use std::{
 ///     for _ in 0..10 {
 ///       match self.try_lock() {
 ///         Success(s) => { return Ok(s); }
-///         Mistake(_) => { unsafe { _mm_pause(); } }
+///         Mistake(_) => { spin_loop(); }
 ///         Failure(f) => { return Err(f); }
 ///       }
 ///     }
@@ -93,7 +83,7 @@ use std::{
 ///         match self.try_lock() {
 ///           Success(s) => { return Ok(s); }
 ///           Mistake(_) => {
-///             for _ in 0..10 { unsafe { _mm_pause(); } }
+///             for _ in 0..10 { spin_loop(); }
 ///             continue;
 ///           }
 ///           Failure(f) => { return Err(f); }
@@ -124,6 +114,52 @@ pub enum Outcome<S, M, F> {
 use Outcome::{Failure, Mistake, Success};
 
 impl<S, M, F> Outcome<S, M, F> {
+  /// Creates an `Outcome<S, M, F>` from a [`Result<S, F>`].
+  ///
+  /// An [`Ok`] becomes a [`Success`], while an [`Err`] becomes a
+  /// [`Failure`]. No [`Mistake`] can be produced.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, (), &str> = Outcome::from_result(Ok(2));
+  /// assert_eq!(x, Success(2));
+  ///
+  /// let x: Outcome<u32, (), &str> = Outcome::from_result(Err("error"));
+  /// assert_eq!(x, Failure("error"));
+  /// ```
+  #[inline]
+  pub fn from_result(result: Result<S, F>) -> Self {
+    match result {
+      Ok(value) => Success(value),
+      Err(value) => Failure(value),
+    }
+  }
+
+  /// Creates an `Outcome<S, M, F>` from an [`Option<S>`].
+  ///
+  /// A [`Some`] becomes a [`Success`], while [`None`] becomes a [`Mistake`]
+  /// containing `mistake`. No [`Failure`] can be produced.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, ()> = Outcome::from_option(Some(2), "empty");
+  /// assert_eq!(x, Success(2));
+  ///
+  /// let x: Outcome<u32, &str, ()> = Outcome::from_option(None, "empty");
+  /// assert_eq!(x, Mistake("empty"));
+  /// ```
+  #[inline]
+  pub fn from_option(option: Option<S>, mistake: M) -> Self {
+    match option {
+      Some(value) => Success(value),
+      None => Mistake(mistake),
+    }
+  }
+
   /// Converts from `&Outcome<S, M, F>` to `Outcome<&S, &M, &F>`.
   ///
   /// Produces a new `Outcome`, containing a reference into the original,
@@ -143,7 +179,7 @@ impl<S, M, F> Outcome<S, M, F> {
   /// assert_eq!(x.as_ref(), Failure(&42));
   /// ```
   #[inline]
-  pub fn as_ref(&self) -> Outcome<&S, &M, &F> {
+  pub const fn as_ref(&self) -> Outcome<&S, &M, &F> {
     match *self {
       Success(ref value) => Success(value),
       Mistake(ref value) => Mistake(value),
@@ -179,7 +215,7 @@ impl<S, M, F> Outcome<S, M, F> {
   /// assert_eq!(x.unwrap_failure(), 0);
   /// ```
   #[inline]
-  pub fn as_mut(&mut self) -> Outcome<&mut S, &mut M, &mut F> {
+  pub const fn as_mut(&mut self) -> Outcome<&mut S, &mut M, &mut F> {
     match *self {
       Success(ref mut value) => Success(value),
       Mistake(ref mut value) => Mistake(value),
@@ -187,6 +223,81 @@ impl<S, M, F> Outcome<S, M, F> {
     }
   }
 
+  /// Converts from `Pin<&Outcome<S, M, F>>` to `Outcome<Pin<&S>, Pin<&M>,
+  /// Pin<&F>>`.
+  ///
+  /// The value of every variant is structurally pinned: once an `Outcome` is
+  /// pinned, its contained value is never moved out of it. A [`Mistake`] or
+  /// [`Failure`] can still be read through [`Pin::get_ref`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::pin::pin;
+  ///
+  /// let x: Outcome<u32, &str, &str> = Success(47);
+  /// let x = pin!(x);
+  /// match x.as_ref().as_pin_ref() {
+  ///   Success(value) => assert_eq!(*value, 47),
+  ///   _ => unreachable!(),
+  /// }
+  /// ```
+  #[inline]
+  #[allow(unsafe_code)]
+  pub fn as_pin_ref(self: Pin<&Self>) -> Outcome<Pin<&S>, Pin<&M>, Pin<&F>> {
+    /* SAFETY: `self` is pinned, and `Outcome` never moves the value of a
+     * variant out of a pinned reference, so the value is pinned as well.
+     */
+    unsafe {
+      match self.get_ref() {
+        Success(value) => Success(Pin::new_unchecked(value)),
+        Mistake(value) => Mistake(Pin::new_unchecked(value)),
+        Failure(value) => Failure(Pin::new_unchecked(value)),
+      }
+    }
+  }
+
+  /// Converts from `Pin<&mut Outcome<S, M, F>>` to `Outcome<Pin<&mut S>,
+  /// Pin<&mut M>, Pin<&mut F>>`.
+  ///
+  /// This is the projection needed to poll a future stored within an
+  /// `Outcome`. As with [`as_pin_ref`], the value of every variant is
+  /// structurally pinned, so a [`Mistake`] or [`Failure`] can only be
+  /// mutated directly through [`Pin::get_mut`] when it is [`Unpin`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::pin::pin;
+  ///
+  /// let x: Outcome<u32, u32, &str> = Mistake(1);
+  /// let mut x = pin!(x);
+  /// if let Mistake(value) = x.as_mut().as_pin_mut() {
+  ///   *value.get_mut() += 1;
+  /// }
+  /// assert_eq!(*x, Mistake(2));
+  /// ```
+  ///
+  /// [`as_pin_ref`]: Outcome::as_pin_ref
+  #[inline]
+  #[allow(unsafe_code)]
+  pub fn as_pin_mut(
+    self: Pin<&mut Self>,
+  ) -> Outcome<Pin<&mut S>, Pin<&mut M>, Pin<&mut F>> {
+    /* SAFETY: the value of a variant is never moved out of `self`, and is
+     * immediately pinned again, so the pinning guarantee is upheld.
+     */
+    unsafe {
+      match self.get_unchecked_mut() {
+        Success(value) => Success(Pin::new_unchecked(value)),
+        Mistake(value) => Mistake(Pin::new_unchecked(value)),
+        Failure(value) => Failure(Pin::new_unchecked(value)),
+      }
+    }
+  }
+
   /// Returns a `Result<Concern<S, M>, F>`, which allows a user to still rely
   /// on the `?` operator until [`Try`] has been stabilized.
   ///
@@ -216,6 +327,202 @@ impl<S, M, F> Outcome<S, M, F> {
     }
   }
 
+  /// Reconstructs an `Outcome` from the result of [`acclimate`].
+  ///
+  /// This is the inverse of [`acclimate`], allowing an outcome to be
+  /// round-tripped through code that relies on the `?` operator.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn double(x: Outcome<u32, f32, &'static str>) -> Result<Concern<u32, f32>, &'static str> {
+  ///   Ok(x.acclimate()?.map(|s| s * 2))
+  /// }
+  ///
+  /// let x = Outcome::from_acclimated(double(Success(2)));
+  /// assert_eq!(x, Success(4));
+  ///
+  /// let x = Outcome::from_acclimated(double(Failure("error")));
+  /// assert_eq!(x, Failure("error"));
+  /// ```
+  ///
+  /// [`acclimate`]: Outcome::acclimate
+  #[inline]
+  pub fn from_acclimated(result: Result<Concern<S, M>, F>) -> Self {
+    match result {
+      Ok(Concern::Success(value)) => Success(value),
+      Ok(Concern::Mistake(value)) => Mistake(value),
+      Err(value) => Failure(value),
+    }
+  }
+
+  /// Converts the `Outcome` into a [`ControlFlow`], which continues with a
+  /// [`Success`] and breaks with a [`Mistake`] or [`Failure`] (by way of an
+  /// [`Aberration`]).
+  ///
+  /// This allows an `Outcome` to be used with algorithms written against
+  /// [`ControlFlow`], such as [`Iterator::try_for_each`], without requiring
+  /// the nightly [`Try`] trait. The inverse conversion is available via
+  /// [`From`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::ops::ControlFlow;
+  ///
+  /// let check = |x: u32| -> Outcome<(), &str, &str> {
+  ///   match x {
+  ///     0 => Failure("zero"),
+  ///     1..=9 => Success(()),
+  ///     _ => Mistake("too large, yet"),
+  ///   }
+  /// };
+  ///
+  /// let flow = [1, 2, 30, 0]
+  ///   .into_iter()
+  ///   .try_for_each(|x| check(x).into_control_flow());
+  /// assert_eq!(flow, ControlFlow::Break(Aberration::Mistake("too large, yet")));
+  ///
+  /// let x: Outcome<(), &str, &str> = flow.into();
+  /// assert_eq!(x, Mistake("too large, yet"));
+  /// ```
+  ///
+  /// [`Try`]: core::ops::Try
+  #[inline]
+  pub fn into_control_flow(self) -> ControlFlow<Aberration<M, F>, S> {
+    match self {
+      Success(value) => ControlFlow::Continue(value),
+      Mistake(value) => ControlFlow::Break(Aberration::Mistake(value)),
+      Failure(value) => ControlFlow::Break(Aberration::Failure(value)),
+    }
+  }
+
+  /// Converts the `Outcome` into a [`Result`], with any [`Mistake`] or
+  /// [`Failure`] in an [`Aberration`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, u8> = Success(47);
+  /// assert_eq!(x.into_result(), Ok(47));
+  ///
+  /// let x: Outcome<u32, &str, u8> = Mistake("busy");
+  /// assert_eq!(x.into_result(), Err(Aberration::Mistake("busy")));
+  /// ```
+  ///
+  /// [`Result`]: core::result::Result
+  #[inline]
+  pub fn into_result(self) -> Result<S, Aberration<M, F>> {
+    match self {
+      Success(value) => Ok(value),
+      Mistake(value) => Err(Aberration::Mistake(value)),
+      Failure(value) => Err(Aberration::Failure(value)),
+    }
+  }
+
+  /// Converts the `Outcome` into a [`Result`], where only a [`Failure`] is
+  /// an error, and a [`Success`] or [`Mistake`] is a [`Concern`].
+  ///
+  /// This is the same as [`acclimate`], but is named to match the other
+  /// `into_result` conversions.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, u8> = Mistake("busy");
+  /// assert_eq!(x.into_result_strict(), Ok(Concern::Mistake("busy")));
+  ///
+  /// let x: Outcome<u32, &str, u8> = Failure(0);
+  /// assert_eq!(x.into_result_strict(), Err(0));
+  /// ```
+  ///
+  /// [`Result`]: core::result::Result
+  /// [`acclimate`]: Outcome::acclimate
+  #[inline]
+  pub fn into_result_strict(self) -> Result<Concern<S, M>, F> {
+    self.acclimate()
+  }
+
+  /// Converts the `Outcome` into a [`Result`], escalating a [`Mistake`] into
+  /// a [`Failure`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, u8, u32> = Mistake(3);
+  /// assert_eq!(x.into_result_flat(), Err(3));
+  ///
+  /// let x: Outcome<u32, u8, u32> = Failure(500);
+  /// assert_eq!(x.into_result_flat(), Err(500));
+  /// ```
+  ///
+  /// [`Result`]: core::result::Result
+  #[inline]
+  pub fn into_result_flat(self) -> Result<S, F>
+  where
+    M: Into<F>,
+  {
+    self.unify_errors()
+  }
+
+  /// Returns a slice of the possibly contained value.
+  ///
+  /// The slice holds one value if the outcome is [`Success`], otherwise it is
+  /// empty. This mirrors [`Option::as_slice`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<i32, f32, &str> = Success(47);
+  /// assert_eq!(x.as_slice(), &[47]);
+  ///
+  /// let x: Outcome<i32, f32, &str> = Mistake(0.0f32);
+  /// assert!(x.as_slice().is_empty());
+  /// ```
+  ///
+  /// [`Option::as_slice`]: core::option::Option::as_slice
+  #[must_use]
+  #[inline]
+  pub const fn as_slice(&self) -> &[S] {
+    match self {
+      Success(value) => slice::from_ref(value),
+      _ => &[],
+    }
+  }
+
+  /// Returns a mutable slice of the possibly contained value.
+  ///
+  /// The slice holds one value if the outcome is [`Success`], otherwise it is
+  /// empty. This mirrors [`Option::as_mut_slice`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Outcome<i32, f32, &str> = Success(47);
+  /// x.as_mut_slice()[0] = 19;
+  /// assert_eq!(x, Success(19));
+  ///
+  /// let mut x: Outcome<i32, f32, &str> = Failure("nope!");
+  /// assert!(x.as_mut_slice().is_empty());
+  /// ```
+  ///
+  /// [`Option::as_mut_slice`]: core::option::Option::as_mut_slice
+  #[must_use]
+  #[inline]
+  pub const fn as_mut_slice(&mut self) -> &mut [S] {
+    match self {
+      Success(value) => slice::from_mut(value),
+      _ => &mut [],
+    }
+  }
+
   /// Returns an iterator over the possibly contained value.
   ///
   /// The iterators yields one value if the outcome is [`Success`], otherwise
@@ -284,9 +591,22 @@ impl<S, M, F> Outcome<S, M, F> {
   /// assert!(!x.is_success());
   /// ```
   ///
+  /// Inspecting an outcome in a `const` context:
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// const EXPECTED: [Outcome<u16, u16, u16>; 3] =
+  ///   [Success(200), Mistake(429), Failure(500)];
+  /// const RETRYABLE: bool = EXPECTED[1].is_mistake();
+  /// const FIRST: Outcome<&u16, &u16, &u16> = EXPECTED[0].as_ref();
+  ///
+  /// assert!(RETRYABLE);
+  /// assert!(FIRST.is_success());
+  /// ```
+  ///
   #[must_use = "if you intended to assert a success, consider `.unwrap()` instead"]
   #[inline]
-  pub fn is_success(&self) -> bool {
+  pub const fn is_success(&self) -> bool {
     if let Success(_) = self {
       return true;
     }
@@ -310,7 +630,7 @@ impl<S, M, F> Outcome<S, M, F> {
   /// ```
   #[must_use = "if you intended to assert a mistake, consider `.unwrap_mistake()` instead"]
   #[inline]
-  pub fn is_mistake(&self) -> bool {
+  pub const fn is_mistake(&self) -> bool {
     if let Mistake(_) = self {
       return true;
     }
@@ -334,7 +654,7 @@ impl<S, M, F> Outcome<S, M, F> {
   /// ```
   #[must_use = "if you intended to assert a failure, consider `.unwrap_failure()` instead"]
   #[inline]
-  pub fn is_failure(&self) -> bool {
+  pub const fn is_failure(&self) -> bool {
     if let Failure(_) = self {
       return true;
     }
@@ -358,10 +678,31 @@ impl<S, M, F> Outcome<S, M, F> {
   /// ```
   #[must_use = "If you intended to assert an error, consider `.unwrap_error()` instead"]
   #[inline]
-  pub fn is_error(&self) -> bool {
+  pub const fn is_error(&self) -> bool {
     !self.is_success()
   }
 
+  /// Returns the [`Severity`] of the outcome.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::Severity;
+  ///
+  /// let x: Outcome<i32, f32, &str> = Mistake(0.0f32);
+  /// assert_eq!(x.severity(), Severity::Mistake);
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn severity(&self) -> Severity {
+    match self {
+      Success(_) => Severity::Success,
+      Mistake(_) => Severity::Mistake,
+      Failure(_) => Severity::Failure,
+    }
+  }
+
   /// Converts from `Outcome<S, M, F>` to [`Option<S>`].
   ///
   /// Converts `self` into an [`Option<S>`], consuming `self`, and discarding
@@ -440,6 +781,68 @@ impl<S, M, F> Outcome<S, M, F> {
     None
   }
 
+  /// Transforms the `Outcome<S, M, F>` into a [`Result<S, E>`], mapping
+  /// [`Success(s)`] to [`Ok(s)`] and any [`Mistake`] or [`Failure`] to
+  /// [`Err(error)`].
+  ///
+  /// The mistake or failure value is discarded. Arguments passed to
+  /// `success_or` are eagerly evaluated; if passing the result of a function
+  /// call, it is recommended to use [`success_or_else`], which is lazily
+  /// evaluated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(42);
+  /// assert_eq!(x.success_or(0), Ok(42));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("hello");
+  /// assert_eq!(x.success_or(0), Err(0));
+  /// ```
+  ///
+  /// [`Success(s)`]: Success
+  /// [`Ok(s)`]: Ok
+  /// [`Err(error)`]: Err
+  /// [`success_or_else`]: Outcome::success_or_else
+  #[inline]
+  pub fn success_or<E>(self, error: E) -> Result<S, E> {
+    match self {
+      Success(value) => Ok(value),
+      Mistake(_) | Failure(_) => Err(error),
+    }
+  }
+
+  /// Transforms the `Outcome<S, M, F>` into a [`Result<S, E>`], mapping
+  /// [`Success(s)`] to [`Ok(s)`], and any [`Mistake`] or [`Failure`] to
+  /// [`Err`] by calling `callable` with the value wrapped in an
+  /// [`Aberration`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Mistake("busy");
+  /// assert_eq!(x.success_or_else(|e| e), Err(Aberration::Mistake("busy")));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("gone");
+  /// assert_eq!(x.success_or_else(|e| e.into_inner().len()), Err(4));
+  /// ```
+  ///
+  /// [`Success(s)`]: Success
+  /// [`Ok(s)`]: Ok
+  #[inline]
+  pub fn success_or_else<E, C>(self, callable: C) -> Result<S, E>
+  where
+    C: FnOnce(Aberration<M, F>) -> E,
+  {
+    match self {
+      Success(value) => Ok(value),
+      Mistake(value) => Err(callable(Aberration::Mistake(value))),
+      Failure(value) => Err(callable(Aberration::Failure(value))),
+    }
+  }
+
   /// Returns the contained [`Success`] value, consuming the `self` value,
   /// without checking that the value is not a [`Mistake`] or [`Failure`].
   ///
@@ -561,73 +964,394 @@ impl<S, M, F> Outcome<S, M, F> {
     core::hint::unreachable_unchecked();
   }
 
-  /// Calls `op` if the result is [`Success`], otherwise returns the
+  /// Returns `other` if the outcome is [`Success`], otherwise returns the
   /// [`Mistake`] or [`Failure`] value of `self`.
   ///
-  /// This function can be used for control flow based on `Outcome` values.
+  /// Arguments passed to `and` are eagerly evaluated; if you are passing the
+  /// result of a function call, it is recommended to use [`and_then`], which
+  /// is lazily evaluated.
   ///
   /// # Examples
   ///
   /// ```
-  /// # #![allow(unused_variables)]
   /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// let y: Outcome<&str, &str, &str> = Mistake("late");
+  /// assert_eq!(x.and(y), Mistake("late"));
   ///
-  /// fn square(x: u32) -> Outcome<u32, u32, u32> { Success(x * x) }
-  /// fn mistake(x: u32) -> Outcome<u32, u32, u32> { Mistake(x) }
-  /// fn failure(x: u32) -> Outcome<u32, u32, u32> { Failure(0) }
+  /// let x: Outcome<u32, &str, &str> = Failure("early");
+  /// let y: Outcome<&str, &str, &str> = Success("foo");
+  /// assert_eq!(x.and(y), Failure("early"));
   ///
-  /// assert_eq!(Success(2).and_then(square).and_then(square), Success(16));
-  /// assert_eq!(Success(2).and_then(square).and_then(failure), Failure(0));
-  /// assert_eq!(Success(2).and_then(square).and_then(mistake), Mistake(4));
-  /// assert_eq!(Failure(2).and_then(square).and_then(square), Failure(2));
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// let y: Outcome<&str, &str, &str> = Success("foo");
+  /// assert_eq!(x.and(y), Success("foo"));
   /// ```
+  ///
+  /// [`and_then`]: Outcome::and_then
   #[inline]
-  pub fn and_then<T, C>(self, callable: C) -> Outcome<T, M, F>
-  where
-    C: FnOnce(S) -> Outcome<T, M, F>,
-  {
+  pub fn and<T>(self, other: Outcome<T, M, F>) -> Outcome<T, M, F> {
     match self {
-      Success(value) => callable(value),
+      Success(_) => other,
       Mistake(value) => Mistake(value),
       Failure(value) => Failure(value),
     }
   }
 
-  /// Maps an `Outcome<S, M, F>` to `Outcome<T, M, F>` by applying a function
-  /// to a contained [`Success`] value, leaving any [`Mistake`] or [`Failure`]
-  /// value untouched.
+  /// Combines two outcomes into an outcome of a tuple, accumulating the
+  /// errors of *both* sides rather than stopping at the first.
   ///
-  /// This function can be used to compose the results of two functions.
-  #[inline]
-  pub fn map<T, C>(self, callable: C) -> Outcome<T, M, F>
+  /// If both are a [`Success`], their values are paired. Otherwise, if either
+  /// is a [`Failure`], every failure is collected into `G`. If neither is a
+  /// failure, every mistake is collected into `N`. This allows validation code
+  /// to report every problem in a single pass.
+  ///
+  /// When a failure is returned, any mistakes are discarded, as a failure
+  /// cannot be retried regardless.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let name: Outcome<&str, &str, &str> = Mistake("name is empty");
+  /// let age: Outcome<u32, &str, &str> = Mistake("age is negative");
+  /// let both: Outcome<_, Vec<_>, Vec<_>> = name.zip_acc(age);
+  /// assert_eq!(both, Mistake(vec!["name is empty", "age is negative"]));
+  ///
+  /// let name: Outcome<&str, &str, &str> = Mistake("name is empty");
+  /// let age: Outcome<u32, &str, &str> = Failure("database offline");
+  /// let both: Outcome<_, Vec<_>, Vec<_>> = name.zip_acc(age);
+  /// assert_eq!(both, Failure(vec!["database offline"]));
+  ///
+  /// let name: Outcome<&str, &str, &str> = Success("Ferris");
+  /// let age: Outcome<u32, &str, &str> = Success(7);
+  /// let both: Outcome<_, Vec<_>, Vec<_>> = name.zip_acc(age);
+  /// assert_eq!(both, Success(("Ferris", 7)));
+  /// ```
+  pub fn zip_acc<T, N, G>(
+    self,
+    other: Outcome<T, M, F>,
+  ) -> Outcome<(S, T), N, G>
   where
-    C: FnOnce(S) -> T,
+    N: Default + Extend<M>,
+    G: Default + Extend<F>,
   {
-    match self {
-      Success(s) => Success(callable(s)),
-      Mistake(m) => Mistake(m),
-      Failure(f) => Failure(f),
+    match (self, other) {
+      (Success(left), Success(right)) => Success((left, right)),
+      (Failure(left), Failure(right)) => {
+        let mut failures = G::default();
+        failures.extend([left, right]);
+        Failure(failures)
+      }
+      (Failure(value), _) | (_, Failure(value)) => {
+        let mut failures = G::default();
+        failures.extend(Some(value));
+        Failure(failures)
+      }
+      (left, right) => {
+        let mut mistakes = N::default();
+        mistakes.extend(left.mistake());
+        mistakes.extend(right.mistake());
+        Mistake(mistakes)
+      }
     }
   }
 
-  /// Returns the provided default (if [`Mistake`] or [`Failure`]), or applies
-  /// a function to the contained value (if [`Success`]).
+  /// Returns `other` if the outcome is a [`Mistake`] or [`Failure`],
+  /// otherwise returns the [`Success`] value of `self`.
   ///
-  /// Arguments passed to `map_or` are eagerly evaluated; if you are passing
-  /// the result of a function call, it is recommended to use [`map_or_else`],
-  /// which is lazily evaluated.
+  /// Arguments passed to `or` are eagerly evaluated; if you are passing the
+  /// result of a function call, it is recommended to use [`or_else`], which
+  /// is lazily evaluated.
   ///
   /// # Examples
   ///
-  ///```
+  /// ```
   /// # use outcome::prelude::*;
-  /// let x: Outcome<_, &str, &str> = Success("foo");
-  /// assert_eq!(x.map_or(47, |v| v.len()), 3);
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// let y: Outcome<u32, (), ()> = Failure(());
+  /// assert_eq!(x.or(y), Success(2));
   ///
-  /// let x: Outcome<&str, _, &str> = Mistake("bar");
-  /// assert_eq!(x.map_or(47, |v| v.len()), 47);
+  /// let x: Outcome<u32, &str, &str> = Mistake("retry");
+  /// let y: Outcome<u32, (), ()> = Success(3);
+  /// assert_eq!(x.or(y), Success(3));
   ///
-  /// let x: Outcome<&str, &str, _> = Failure("baz");
+  /// let x: Outcome<u32, &str, &str> = Failure("error");
+  /// let y: Outcome<u32, (), ()> = Mistake(());
+  /// assert_eq!(x.or(y), Mistake(()));
+  /// ```
+  ///
+  /// [`or_else`]: Outcome::or_else
+  #[inline]
+  pub fn or<N, G>(self, other: Outcome<S, N, G>) -> Outcome<S, N, G> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(_) | Failure(_) => other,
+    }
+  }
+
+  /// Calls `op` if the outcome is a [`Mistake`] or [`Failure`], otherwise
+  /// returns the [`Success`] value of `self`.
+  ///
+  /// The closure receives an [`Aberration`], allowing the fallback to
+  /// distinguish between mistakes and failures.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn recover(x: Aberration<u32, &str>) -> Outcome<u32, (), &str> {
+  ///   match x {
+  ///     Aberration::Mistake(value) => Success(value * 2),
+  ///     Aberration::Failure(value) => Failure(value),
+  ///   }
+  /// }
+  ///
+  /// assert_eq!(Success(2).or_else(recover), Success(2));
+  /// assert_eq!(Mistake(3).or_else(recover), Success(6));
+  /// assert_eq!(Failure("error").or_else(recover), Failure("error"));
+  /// ```
+  #[inline]
+  pub fn or_else<N, G, C>(self, op: C) -> Outcome<S, N, G>
+  where
+    C: FnOnce(Aberration<M, F>) -> Outcome<S, N, G>,
+  {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => op(Aberration::Mistake(value)),
+      Failure(value) => op(Aberration::Failure(value)),
+    }
+  }
+
+  /// Returns `other` if the outcome is a [`Mistake`], otherwise returns the
+  /// [`Success`] or [`Failure`] value of `self`.
+  ///
+  /// This is useful when only mistakes are recoverable, such as replacing a
+  /// retryable operation with a fallback, while failures must propagate.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Mistake("busy");
+  /// let y: Outcome<u32, (), &str> = Success(3);
+  /// assert_eq!(x.mistake_or(y), Success(3));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("error");
+  /// let y: Outcome<u32, (), &str> = Success(3);
+  /// assert_eq!(x.mistake_or(y), Failure("error"));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// let y: Outcome<u32, (), &str> = Mistake(());
+  /// assert_eq!(x.mistake_or(y), Success(2));
+  /// ```
+  #[inline]
+  pub fn mistake_or<N>(self, other: Outcome<S, N, F>) -> Outcome<S, N, F> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(_) => other,
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Returns `other` if the outcome is a [`Failure`], otherwise returns the
+  /// [`Success`] or [`Mistake`] value of `self`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Failure("error");
+  /// let y: Outcome<u32, &str, ()> = Success(3);
+  /// assert_eq!(x.failure_or(y), Success(3));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Mistake("busy");
+  /// let y: Outcome<u32, &str, ()> = Success(3);
+  /// assert_eq!(x.failure_or(y), Mistake("busy"));
+  /// ```
+  #[inline]
+  pub fn failure_or<G>(self, other: Outcome<S, M, G>) -> Outcome<S, M, G> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Mistake(value),
+      Failure(_) => other,
+    }
+  }
+
+  /// Calls `op` if the result is [`Success`], otherwise returns the
+  /// [`Mistake`] or [`Failure`] value of `self`.
+  ///
+  /// This function can be used for control flow based on `Outcome` values.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #![allow(unused_variables)]
+  /// # use outcome::prelude::*;
+  ///
+  /// fn square(x: u32) -> Outcome<u32, u32, u32> { Success(x * x) }
+  /// fn mistake(x: u32) -> Outcome<u32, u32, u32> { Mistake(x) }
+  /// fn failure(x: u32) -> Outcome<u32, u32, u32> { Failure(0) }
+  ///
+  /// assert_eq!(Success(2).and_then(square).and_then(square), Success(16));
+  /// assert_eq!(Success(2).and_then(square).and_then(failure), Failure(0));
+  /// assert_eq!(Success(2).and_then(square).and_then(mistake), Mistake(4));
+  /// assert_eq!(Failure(2).and_then(square).and_then(square), Failure(2));
+  /// ```
+  #[inline]
+  pub fn and_then<T, C>(self, callable: C) -> Outcome<T, M, F>
+  where
+    C: FnOnce(S) -> Outcome<T, M, F>,
+  {
+    match self {
+      Success(value) => callable(value),
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<T, M, F>` by applying a function
+  /// to a contained [`Success`] value, leaving any [`Mistake`] or [`Failure`]
+  /// value untouched.
+  ///
+  /// This function can be used to compose the results of two functions.
+  #[inline]
+  pub fn map<T, C>(self, callable: C) -> Outcome<T, M, F>
+  where
+    C: FnOnce(S) -> T,
+  {
+    match self {
+      Success(s) => Success(callable(s)),
+      Mistake(m) => Mistake(m),
+      Failure(f) => Failure(f),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<T, M, F>` by applying a fallible
+  /// function to a contained [`Success`] value. If the function returns an
+  /// error, it is converted into a [`Failure`].
+  ///
+  /// This allows mapping through existing functions that return a [`Result`]
+  /// without first converting their return value into an `Outcome`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::num::ParseIntError;
+  ///
+  /// let x: Outcome<&str, (), ParseIntError> = Success("47");
+  /// assert_eq!(x.try_map(str::parse::<u8>), Success(47));
+  ///
+  /// let x: Outcome<&str, (), ParseIntError> = Success("x");
+  /// assert!(x.try_map(str::parse::<u8>).is_failure());
+  ///
+  /// let x: Outcome<&str, (), ParseIntError> = Mistake(());
+  /// assert_eq!(x.try_map(str::parse::<u8>), Mistake(()));
+  /// ```
+  #[inline]
+  pub fn try_map<T, E, C>(self, callable: C) -> Outcome<T, M, F>
+  where
+    E: Into<F>,
+    C: FnOnce(S) -> Result<T, E>,
+  {
+    match self {
+      Success(value) => match callable(value) {
+        Ok(value) => Success(value),
+        Err(error) => Failure(error.into()),
+      },
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<S, N, F>` by applying a fallible
+  /// function to a contained [`Mistake`] value. If the function returns an
+  /// error, it is converted into a [`Failure`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::num::ParseIntError;
+  ///
+  /// let x: Outcome<(), &str, ParseIntError> = Mistake("3");
+  /// assert_eq!(x.try_map_mistake(str::parse::<u8>), Mistake(3));
+  ///
+  /// let x: Outcome<(), &str, ParseIntError> = Mistake("x");
+  /// assert!(x.try_map_mistake(str::parse::<u8>).is_failure());
+  /// ```
+  #[inline]
+  pub fn try_map_mistake<N, E, C>(self, callable: C) -> Outcome<S, N, F>
+  where
+    E: Into<F>,
+    C: FnOnce(M) -> Result<N, E>,
+  {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => match callable(value) {
+        Ok(value) => Mistake(value),
+        Err(error) => Failure(error.into()),
+      },
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<S, M, G>` by applying a function
+  /// that returns an `Outcome` to a contained [`Failure`] value, leaving a
+  /// [`Success`] or [`Mistake`] value untouched.
+  ///
+  /// Unlike [`map_failure`], the function may reclassify the failure as a
+  /// [`Success`] or [`Mistake`], such as when the payload of a failure
+  /// reveals that it can be recovered from.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let classify = |status: u16| match status {
+  ///   503 => Mistake("service unavailable"),
+  ///   _ => Failure(status),
+  /// };
+  ///
+  /// let x: Outcome<(), &str, u16> = Failure(503);
+  /// assert_eq!(x.try_map_failure(classify), Mistake("service unavailable"));
+  ///
+  /// let x: Outcome<(), &str, u16> = Failure(404);
+  /// assert_eq!(x.try_map_failure(classify), Failure(404));
+  /// ```
+  ///
+  /// [`map_failure`]: Outcome::map_failure
+  #[inline]
+  pub fn try_map_failure<G, C>(self, callable: C) -> Outcome<S, M, G>
+  where
+    C: FnOnce(F) -> Outcome<S, M, G>,
+  {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Mistake(value),
+      Failure(value) => callable(value),
+    }
+  }
+
+  /// Returns the provided default (if [`Mistake`] or [`Failure`]), or applies
+  /// a function to the contained value (if [`Success`]).
+  ///
+  /// Arguments passed to `map_or` are eagerly evaluated; if you are passing
+  /// the result of a function call, it is recommended to use [`map_or_else`],
+  /// which is lazily evaluated.
+  ///
+  /// # Examples
+  ///
+  ///```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<_, &str, &str> = Success("foo");
+  /// assert_eq!(x.map_or(47, |v| v.len()), 3);
+  ///
+  /// let x: Outcome<&str, _, &str> = Mistake("bar");
+  /// assert_eq!(x.map_or(47, |v| v.len()), 47);
+  ///
+  /// let x: Outcome<&str, &str, _> = Failure("baz");
   /// assert_eq!(x.map_or(47, |v| v.len()), 47);
   ///```
   ///
@@ -699,17 +1423,343 @@ impl<S, M, F> Outcome<S, M, F> {
   /// to a contained [`Failure`] value, leaving a [`Success`] or [`Failure`]
   /// value untouched.
   ///
-  /// This function can be used to pass through a successful or mistaken
-  /// outcome while handling a failure.
+  /// This function can be used to pass through a successful or mistaken
+  /// outcome while handling a failure.
+  #[inline]
+  pub fn map_failure<G, C>(self, callable: C) -> Outcome<S, M, G>
+  where
+    C: FnOnce(F) -> G,
+  {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(callable(value)),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<T, M, F>` by applying a function
+  /// to a contained [`Success`] value, passing `context` along with it.
+  ///
+  /// Unlike [`map`], the function is a plain `fn` rather than a closure, and
+  /// any state it needs is passed explicitly. This avoids borrowing the
+  /// surrounding environment when mapping many outcomes in a loop.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn tally(total: &mut u32, value: u32) -> u32 {
+  ///   *total += value;
+  ///   *total
+  /// }
+  ///
+  /// let mut total = 0;
+  /// let outcomes: [Outcome<u32, (), ()>; 3] = [Success(1), Mistake(()), Success(2)];
+  /// let mapped = outcomes.map(|x| x.map_with(&mut total, tally));
+  /// assert_eq!(mapped, [Success(1), Mistake(()), Success(3)]);
+  /// assert_eq!(total, 3);
+  /// ```
+  ///
+  /// [`map`]: Outcome::map
+  #[inline]
+  pub fn map_with<T, X>(
+    self,
+    context: &mut X,
+    callable: fn(&mut X, S) -> T,
+  ) -> Outcome<T, M, F> {
+    match self {
+      Success(value) => Success(callable(context, value)),
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<S, N, F>` by applying a function
+  /// to a contained [`Mistake`] value, passing `context` along with it.
+  ///
+  /// See [`map_with`] for details.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn count(seen: &mut usize, message: &str) -> usize {
+  ///   *seen += 1;
+  ///   message.len()
+  /// }
+  ///
+  /// let mut seen = 0;
+  /// let x: Outcome<(), &str, ()> = Mistake("busy");
+  /// assert_eq!(x.map_mistake_with(&mut seen, count), Mistake(4));
+  /// assert_eq!(seen, 1);
+  /// ```
+  ///
+  /// [`map_with`]: Outcome::map_with
+  #[inline]
+  pub fn map_mistake_with<N, X>(
+    self,
+    context: &mut X,
+    callable: fn(&mut X, M) -> N,
+  ) -> Outcome<S, N, F> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Mistake(callable(context, value)),
+      Failure(value) => Failure(value),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<S, M, G>` by applying a function
+  /// to a contained [`Failure`] value, passing `context` along with it.
+  ///
+  /// See [`map_with`] for details.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// fn record(log: &mut Vec<u16>, status: u16) -> &'static str {
+  ///   log.push(status);
+  ///   "request failed"
+  /// }
+  ///
+  /// let mut log = Vec::new();
+  /// let x: Outcome<(), (), u16> = Failure(500);
+  /// assert_eq!(x.map_failure_with(&mut log, record), Failure("request failed"));
+  /// assert_eq!(log, [500]);
+  /// ```
+  ///
+  /// [`map_with`]: Outcome::map_with
+  #[inline]
+  pub fn map_failure_with<G, X>(
+    self,
+    context: &mut X,
+    callable: fn(&mut X, F) -> G,
+  ) -> Outcome<S, M, G> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Mistake(value),
+      Failure(value) => Failure(callable(context, value)),
+    }
+  }
+
+  /// Maps an `Outcome<S, M, F>` to `Outcome<T, N, G>` by applying `success`,
+  /// `mistake`, or `failure` to the contained value, depending on its variant.
+  ///
+  /// This is equivalent to chaining [`map`], [`map_mistake`], and
+  /// [`map_failure`], but only a single match is performed.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let translate = |x: Outcome<u8, &str, &str>| {
+  ///   x.map_all(u32::from, str::len, |f| f.to_uppercase())
+  /// };
+  ///
+  /// assert_eq!(translate(Success(2)), Success(2u32));
+  /// assert_eq!(translate(Mistake("busy")), Mistake(4));
+  /// assert_eq!(translate(Failure("gone")), Failure("GONE".to_string()));
+  /// ```
+  ///
+  /// [`map`]: Outcome::map
+  /// [`map_mistake`]: Outcome::map_mistake
+  /// [`map_failure`]: Outcome::map_failure
+  #[inline]
+  pub fn map_all<T, N, G, A, B, C>(
+    self,
+    success: A,
+    mistake: B,
+    failure: C,
+  ) -> Outcome<T, N, G>
+  where
+    A: FnOnce(S) -> T,
+    B: FnOnce(M) -> N,
+    C: FnOnce(F) -> G,
+  {
+    match self {
+      Success(value) => Success(success(value)),
+      Mistake(value) => Mistake(mistake(value)),
+      Failure(value) => Failure(failure(value)),
+    }
+  }
+
+  /// Returns the `Outcome` unchanged if it is not a [`Success`], or if
+  /// `predicate` returns `true` for the contained [`Success`] value.
+  /// Otherwise, returns a [`Mistake`] containing `mistake`.
+  ///
+  /// Arguments passed to `filter` are eagerly evaluated; if you are passing
+  /// the result of a function call, it is recommended to use
+  /// [`filter_or_else`], which is lazily evaluated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(4);
+  /// assert_eq!(x.filter(|n| n % 2 == 0, "odd"), Success(4));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Success(3);
+  /// assert_eq!(x.filter(|n| n % 2 == 0, "odd"), Mistake("odd"));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("gone");
+  /// assert_eq!(x.filter(|n| n % 2 == 0, "odd"), Failure("gone"));
+  /// ```
+  ///
+  /// [`filter_or_else`]: Outcome::filter_or_else
+  #[inline]
+  pub fn filter<P>(self, predicate: P, mistake: M) -> Self
+  where
+    P: FnOnce(&S) -> bool,
+  {
+    match self {
+      Success(value) if !predicate(&value) => Mistake(mistake),
+      outcome => outcome,
+    }
+  }
+
+  /// Returns the `Outcome` unchanged if it is not a [`Success`], or if
+  /// `predicate` returns `true` for the contained [`Success`] value.
+  /// Otherwise, returns a [`Mistake`] computed from the rejected value.
+  ///
+  /// As the rejected value is handed to `callable`, it can be returned to the
+  /// caller within the [`Mistake`], to be corrected and tried again.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<&str, String, ()> = Success("  ");
+  /// let x = x.filter_or_else(
+  ///   |s| !s.trim().is_empty(),
+  ///   |s| format!("{s:?} is blank"),
+  /// );
+  /// assert_eq!(x, Mistake("\"  \" is blank".to_string()));
+  /// ```
+  #[inline]
+  pub fn filter_or_else<P, C>(self, predicate: P, callable: C) -> Self
+  where
+    P: FnOnce(&S) -> bool,
+    C: FnOnce(S) -> M,
+  {
+    match self {
+      Success(value) if !predicate(&value) => Mistake(callable(value)),
+      outcome => outcome,
+    }
+  }
+
+  /// Calls `callable` with a reference to the contained [`Success`] value,
+  /// if any, and returns the `Outcome` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Outcome<u32, &str, &str> = Success(4);
+  /// assert_eq!(x.inspect(|value| seen = Some(*value)), Success(4));
+  /// assert_eq!(seen, Some(4));
+  /// ```
+  #[inline]
+  pub fn inspect<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&S),
+  {
+    if let Success(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Calls `callable` with a reference to the contained [`Mistake`] value,
+  /// if any, and returns the `Outcome` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Outcome<u32, &str, &str> = Mistake("busy");
+  /// assert_eq!(x.inspect_mistake(|value| seen = Some(*value)), Mistake("busy"));
+  /// assert_eq!(seen, Some("busy"));
+  /// ```
+  #[inline]
+  pub fn inspect_mistake<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&M),
+  {
+    if let Mistake(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Calls `callable` with a reference to the contained [`Failure`] value,
+  /// if any, and returns the `Outcome` unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut seen = None;
+  /// let x: Outcome<u32, &str, &str> = Failure("error");
+  /// assert_eq!(x.inspect_failure(|value| seen = Some(*value)), Failure("error"));
+  /// assert_eq!(seen, Some("error"));
+  /// ```
+  #[inline]
+  pub fn inspect_failure<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(&F),
+  {
+    if let Failure(ref value) = self {
+      callable(value);
+    }
+    self
+  }
+
+  /// Collapses both error channels into a single error type `E`, producing a
+  /// [`Result`].
+  ///
+  /// This is useful at hand-off points where the distinction between a
+  /// [`Mistake`] and a [`Failure`] no longer matters, such as when returning
+  /// a `Box<dyn Error>` or an application specific error enum.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// #[derive(Debug, PartialEq)]
+  /// enum AppError {
+  ///   Busy,
+  ///   Crashed(u8),
+  /// }
+  ///
+  /// impl From<()> for AppError {
+  ///   fn from(_: ()) -> Self { Self::Busy }
+  /// }
+  ///
+  /// impl From<u8> for AppError {
+  ///   fn from(code: u8) -> Self { Self::Crashed(code) }
+  /// }
+  ///
+  /// let x: Outcome<&str, (), u8> = Mistake(());
+  /// assert_eq!(x.unify_errors::<AppError>(), Err(AppError::Busy));
+  ///
+  /// let x: Outcome<&str, (), u8> = Failure(3);
+  /// assert_eq!(x.unify_errors::<AppError>(), Err(AppError::Crashed(3)));
+  ///
+  /// let x: Outcome<&str, (), u8> = Success("ok");
+  /// assert_eq!(x.unify_errors::<AppError>(), Ok("ok"));
+  /// ```
   #[inline]
-  pub fn map_failure<G, C>(self, callable: C) -> Outcome<S, M, G>
+  pub fn unify_errors<E>(self) -> Result<S, E>
   where
-    C: FnOnce(F) -> G,
+    M: Into<E>,
+    F: Into<E>,
   {
     match self {
-      Success(value) => Success(value),
-      Mistake(value) => Mistake(value),
-      Failure(value) => Failure(callable(value)),
+      Success(value) => Ok(value),
+      Mistake(value) => Err(value.into()),
+      Failure(value) => Err(value.into()),
     }
   }
 }
@@ -790,6 +1840,135 @@ impl<S: Copy, M, F> Outcome<&mut S, M, F> {
   }
 }
 
+impl<S: Clone, M: Clone, F: Clone> Outcome<&S, &M, &F> {
+  /// Maps an `Outcome<&S, &M, &F>` to an `Outcome<S, M, F>` by cloning the
+  /// contained value, whatever its variant.
+  ///
+  /// Unlike [`cloned`], which only clones a [`Success`] value, this is meant
+  /// for outcomes produced by [`as_ref`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let outcomes: Vec<Outcome<String, String, String>> =
+  ///   vec![Success("a".into()), Mistake("b".into())];
+  /// let owned: Vec<_> =
+  ///   outcomes.iter().map(|x| x.as_ref().cloned_all()).collect();
+  /// assert_eq!(owned, outcomes);
+  /// ```
+  ///
+  /// [`cloned`]: Outcome::cloned
+  /// [`as_ref`]: Outcome::as_ref
+  #[inline]
+  pub fn cloned_all(self) -> Outcome<S, M, F> {
+    self.map_all(Clone::clone, Clone::clone, Clone::clone)
+  }
+}
+
+impl<S: Clone, M: Clone, F: Clone> Outcome<&mut S, &mut M, &mut F> {
+  /// Maps an `Outcome<&mut S, &mut M, &mut F>` to an `Outcome<S, M, F>` by
+  /// cloning the contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Outcome<u32, String, ()> = Mistake("busy".into());
+  /// assert_eq!(x.as_mut().cloned_all(), Mistake("busy".to_string()));
+  /// ```
+  #[inline]
+  pub fn cloned_all(self) -> Outcome<S, M, F> {
+    self.map_all(|s| s.clone(), |m| m.clone(), |f| f.clone())
+  }
+}
+
+impl<S: Copy, M: Copy, F: Copy> Outcome<&S, &M, &F> {
+  /// Maps an `Outcome<&S, &M, &F>` to an `Outcome<S, M, F>` by copying the
+  /// contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, u8, i8> = Failure(-1);
+  /// assert_eq!(x.as_ref().copied_all(), Failure(-1));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Outcome<S, M, F> {
+    self.map_all(|&s| s, |&m| m, |&f| f)
+  }
+}
+
+impl<S: Copy, M: Copy, F: Copy> Outcome<&mut S, &mut M, &mut F> {
+  /// Maps an `Outcome<&mut S, &mut M, &mut F>` to an `Outcome<S, M, F>` by
+  /// copying the contained value, whatever its variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let mut x: Outcome<u32, u8, i8> = Mistake(3);
+  /// assert_eq!(x.as_mut().copied_all(), Mistake(3));
+  /// ```
+  #[inline]
+  pub fn copied_all(self) -> Outcome<S, M, F> {
+    self.map_all(|&mut s| s, |&mut m| m, |&mut f| f)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: ToOwned, M: ToOwned, F: ToOwned> Outcome<S, M, F> {
+  /// Converts from `&Outcome<S, M, F>` to `Outcome<Cow<S>, Cow<M>, Cow<F>>`,
+  /// borrowing the contained value.
+  ///
+  /// This allows an outcome to be handed to code that may need to take
+  /// ownership of its value, without cloning it up front.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use std::borrow::Cow;
+  ///
+  /// let x: Outcome<u32, String, ()> = Mistake("busy".into());
+  /// let cow = x.as_cow();
+  /// assert!(matches!(cow, Mistake(Cow::Borrowed(_))));
+  /// assert_eq!(cow.into_owned(), x);
+  /// ```
+  #[inline]
+  pub fn as_cow(&self) -> Outcome<Cow<'_, S>, Cow<'_, M>, Cow<'_, F>> {
+    self
+      .as_ref()
+      .map_all(Cow::Borrowed, Cow::Borrowed, Cow::Borrowed)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, M, F> Outcome<Cow<'_, S>, Cow<'_, M>, Cow<'_, F>>
+where
+  S: ToOwned + ?Sized,
+  M: ToOwned + ?Sized,
+  F: ToOwned + ?Sized,
+{
+  /// Converts from `Outcome<Cow<S>, Cow<M>, Cow<F>>` to an outcome of owned
+  /// values, cloning the contained value if it is borrowed.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use std::borrow::Cow;
+  ///
+  /// let x: Outcome<Cow<str>, Cow<str>, Cow<str>> = Failure("gone".into());
+  /// assert_eq!(x.into_owned(), Failure("gone".to_string()));
+  /// ```
+  #[inline]
+  pub fn into_owned(self) -> Outcome<S::Owned, M::Owned, F::Owned> {
+    self.map_all(Cow::into_owned, Cow::into_owned, Cow::into_owned)
+  }
+}
+
 /* special interfaces */
 #[cfg(not(feature = "nightly"))]
 impl<S, M, F> Outcome<S, M, F> {
@@ -826,6 +2005,138 @@ where
   }
 }
 
+#[cfg(not(feature = "nightly"))]
+impl<S, M: Into<F>, F> Outcome<S, M, F> {
+  /// Escalates a [`Mistake`] to a [`Failure`], leaving a [`Success`]
+  /// untouched.
+  ///
+  /// The returned `Outcome` can no longer represent a [`Mistake`]. On
+  /// nightly, this method instead requires that the [`Success`] type has
+  /// already been eliminated, and returns an `Outcome<!, !, F>`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::convert::Infallible;
+  ///
+  /// let x: Outcome<u32, &str, String> = Mistake("still busy");
+  /// let y: Outcome<u32, Infallible, String> = x.escalate_mistake();
+  /// assert_eq!(y, Failure("still busy".to_string()));
+  /// ```
+  #[inline]
+  pub fn escalate_mistake(self) -> Outcome<S, Infallible, F> {
+    match self {
+      Success(s) => Success(s),
+      Mistake(m) => Failure(m.into()),
+      Failure(f) => Failure(f),
+    }
+  }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<S, M, F> Outcome<S, M, F> {
+  /// Escalates a [`Mistake`] to a [`Failure`] using the given closure,
+  /// leaving a [`Success`] untouched.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, u8, String> = Mistake(3);
+  /// let y = x.escalate_mistake_with(|n| format!("gave up after {n} attempts"));
+  /// assert_eq!(y, Failure("gave up after 3 attempts".to_string()));
+  /// ```
+  #[inline]
+  pub fn escalate_mistake_with<C, G>(
+    self,
+    closure: C,
+  ) -> Outcome<S, Infallible, F>
+  where
+    G: Into<F>,
+    C: FnOnce(M) -> G,
+  {
+    match self {
+      Success(s) => Success(s),
+      Mistake(m) => Failure(closure(m).into()),
+      Failure(f) => Failure(f),
+    }
+  }
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Reclassifies a [`Failure`] as a [`Mistake`] by applying `callable` to
+  /// it, leaving a [`Success`] or [`Mistake`] untouched.
+  ///
+  /// This is the inverse of escalation, and is useful when a dependency
+  /// considers an error fatal that the caller can recover from.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Failure("connection reset");
+  /// assert_eq!(x.demote_failure(|_| "reconnecting"), Mistake("reconnecting"));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// assert_eq!(x.demote_failure(|_| "reconnecting"), Success(2));
+  /// ```
+  #[inline]
+  pub fn demote_failure<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(F) -> M,
+  {
+    match self {
+      Failure(value) => Mistake(callable(value)),
+      outcome => outcome,
+    }
+  }
+
+  /// Reclassifies a [`Mistake`] as a [`Failure`] by applying `callable` to
+  /// it, leaving a [`Success`] or [`Failure`] untouched.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, u8, String> = Mistake(3);
+  /// let x = x.promote_mistake(|n| format!("gave up after {n} attempts"));
+  /// assert_eq!(x, Failure("gave up after 3 attempts".to_string()));
+  /// ```
+  #[inline]
+  pub fn promote_mistake<C>(self, callable: C) -> Self
+  where
+    C: FnOnce(M) -> F,
+  {
+    match self {
+      Mistake(value) => Failure(callable(value)),
+      outcome => outcome,
+    }
+  }
+
+  /// Swaps the [`Mistake`] and [`Failure`] variants, turning an
+  /// `Outcome<S, M, F>` into an `Outcome<S, F, M>`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Mistake("busy");
+  /// assert_eq!(x.swap_errors(), Failure("busy"));
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("gone");
+  /// assert_eq!(x.swap_errors(), Mistake("gone"));
+  /// ```
+  #[inline]
+  pub fn swap_errors(self) -> Outcome<S, F, M> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) => Failure(value),
+      Failure(value) => Mistake(value),
+    }
+  }
+}
+
 impl<S: Deref, M, F> Outcome<S, M, F> {
   /// Converts from `Outcome<S, M, F>` (or `&Outcome<S, M, F>`) to `Outcome<&<S
   /// as Deref>::Target, M, F>`.
@@ -917,6 +2228,75 @@ impl<S, M: Debug, F: Debug> Outcome<S, M, F> {
     }
   }
 
+  /// Returns the contained [`Success`] value, consuming the `self` value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Mistake`] or [`Failure`], with a panic
+  /// message including the passed message, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// assert_eq!(x.expect("expected a success"), 2);
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Failure("emergency failure");
+  /// x.expect("Testing expect"); // panics with `Testing expect: "emergency failure"`
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect(self, message: &str) -> S {
+    match self {
+      Success(s) => s,
+      Mistake(m) => expect("Outcome::expect()", "Mistake", &message, &m),
+      Failure(f) => expect("Outcome::expect()", "Failure", &message, &f),
+    }
+  }
+
+  /// Returns the contained [`Success`] value, consuming the `self` value.
+  ///
+  /// Unlike `expect`, the panic message is only computed by calling `message`
+  /// if the value is a [`Mistake`] or [`Failure`]. This keeps potentially
+  /// expensive formatting out of the successful path.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Mistake`] or [`Failure`], with a panic
+  /// message including the result of `message`, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(2);
+  /// assert_eq!(x.expect_with(|| format!("expected {}", 2)), 2);
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let path = "/etc/outcome.toml";
+  /// let x: Outcome<u32, &str, &str> = Failure("emergency failure");
+  /// x.expect_with(|| format!("could not read {path}")); // panics with `could not read /etc/outcome.toml: "emergency failure"`
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_with<D, C>(self, message: C) -> S
+  where
+    D: Display,
+    C: FnOnce() -> D,
+  {
+    match self {
+      Success(s) => s,
+      Mistake(m) => expect("Outcome::expect_with()", "Mistake", &message(), &m),
+      Failure(f) => expect("Outcome::expect_with()", "Failure", &message(), &f),
+    }
+  }
+
   /// Returns the [`Success`] value or a provided default.
   ///
   /// Arguments passed to `unwrap_or` are eagerly evaluated; if you are passing
@@ -1001,11 +2381,86 @@ impl<S: Debug, M, F: Debug> Outcome<S, M, F> {
   /// ```
   #[track_caller]
   #[inline]
-  pub fn unwrap_mistake(self) -> M {
+  pub fn unwrap_mistake(self) -> M {
+    match self {
+      Success(s) => panic("Outcome::unwrap_mistake()", "Success", &s),
+      Mistake(m) => m,
+      Failure(f) => panic("Outcome::unwrap_mistake()", "Failure", &f),
+    }
+  }
+
+  /// Returns the contained [`Mistake`] value, consuming the `self` value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Success`] or [`Failure`], with a panic
+  /// message including the passed message, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, f32> = Mistake("try again!");
+  /// assert_eq!(x.expect_mistake("expected a mistake"), "try again!");
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, f32> = Success(47);
+  /// x.expect_mistake("Testing expect_mistake"); // panics with `Testing expect_mistake: 47`
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_mistake(self, message: &str) -> M {
+    match self {
+      Success(s) => {
+        expect("Outcome::expect_mistake()", "Success", &message, &s)
+      }
+      Mistake(m) => m,
+      Failure(f) => {
+        expect("Outcome::expect_mistake()", "Failure", &message, &f)
+      }
+    }
+  }
+
+  /// Returns the contained [`Mistake`] value, consuming the `self` value.
+  ///
+  /// The panic message is only computed by calling `message` if the value is
+  /// a [`Success`] or [`Failure`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Success`] or [`Failure`], with a panic
+  /// message including the result of `message`, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, f32> = Mistake("try again!");
+  /// assert_eq!(x.expect_mistake_with(|| "expected a mistake"), "try again!");
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, f32> = Success(47);
+  /// x.expect_mistake_with(|| format!("expected a mistake from {}", "foo")); // panics
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_mistake_with<D, C>(self, message: C) -> M
+  where
+    D: Display,
+    C: FnOnce() -> D,
+  {
     match self {
-      Success(s) => panic("Outcome::unwrap_mistake()", "Success", &s),
+      Success(s) => {
+        expect("Outcome::expect_mistake_with()", "Success", &message(), &s)
+      }
       Mistake(m) => m,
-      Failure(f) => panic("Outcome::unwrap_mistake()", "Failure", &f),
+      Failure(f) => {
+        expect("Outcome::expect_mistake_with()", "Failure", &message(), &f)
+      }
     }
   }
 }
@@ -1046,6 +2501,81 @@ impl<S: Debug, M: Debug, F> Outcome<S, M, F> {
       Failure(f) => f,
     }
   }
+
+  /// Returns the contained [`Failure`] value, consuming the `self` value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Success`] or [`Mistake`], with a panic
+  /// message including the passed message, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, f32, &str> = Failure("failure!");
+  /// assert_eq!(x.expect_failure("expected a failure"), "failure!");
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, f32, &str> = Mistake(0.0);
+  /// x.expect_failure("Testing expect_failure"); // panics with `Testing expect_failure: 0.0`
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_failure(self, message: &str) -> F {
+    match self {
+      Success(s) => {
+        expect("Outcome::expect_failure()", "Success", &message, &s)
+      }
+      Mistake(m) => {
+        expect("Outcome::expect_failure()", "Mistake", &message, &m)
+      }
+      Failure(f) => f,
+    }
+  }
+
+  /// Returns the contained [`Failure`] value, consuming the `self` value.
+  ///
+  /// The panic message is only computed by calling `message` if the value is
+  /// a [`Success`] or [`Mistake`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Success`] or [`Mistake`], with a panic
+  /// message including the result of `message`, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, f32, &str> = Failure("failure!");
+  /// assert_eq!(x.expect_failure_with(|| "expected a failure"), "failure!");
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, f32, &str> = Mistake(0.0);
+  /// x.expect_failure_with(|| format!("expected a failure from {}", "foo")); // panics
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_failure_with<D, C>(self, message: C) -> F
+  where
+    D: Display,
+    C: FnOnce() -> D,
+  {
+    match self {
+      Success(s) => {
+        expect("Outcome::expect_failure_with()", "Success", &message(), &s)
+      }
+      Mistake(m) => {
+        expect("Outcome::expect_failure_with()", "Mistake", &message(), &m)
+      }
+      Failure(f) => f,
+    }
+  }
 }
 
 impl<S: Debug, M, F> Outcome<S, M, F> {
@@ -1084,6 +2614,107 @@ impl<S: Debug, M, F> Outcome<S, M, F> {
       Failure(value) => Aberration::Failure(value),
     }
   }
+
+  /// Returns the contained [`Mistake`] or [`Failure`] value wrapped in an
+  /// [`Aberration`], consuming the `self` value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is a [`Success`], with a panic message including the
+  /// passed message, and the contained value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Mistake("mistake!");
+  /// let ab = x.expect_error("expected an error");
+  /// assert_eq!(ab, Aberration::Mistake("mistake!"));
+  /// ```
+  ///
+  /// ```should_panic
+  /// # #![allow(unused_must_use)]
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u32, &str, &str> = Success(47);
+  /// x.expect_error("Testing expect_error"); // panics with `Testing expect_error: 47`
+  /// ```
+  #[track_caller]
+  #[inline]
+  pub fn expect_error(self, message: &str) -> Aberration<M, F> {
+    match self {
+      Success(value) => {
+        expect("Outcome::expect_error()", "Success", &message, &value)
+      }
+      Mistake(value) => Aberration::Mistake(value),
+      Failure(value) => Aberration::Failure(value),
+    }
+  }
+}
+
+impl<S, M, F: Debug> Outcome<S, M, F> {
+  /// Converts from `Outcome<S, M, F>` to [`Option<M>`], recording a
+  /// discarded [`Failure`].
+  ///
+  /// This is [`mistake`], except that when the `log` or `tracing` feature is
+  /// enabled, a discarded [`Failure`] value is emitted at the debug level, so
+  /// that it is not lost without a trace.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<(), u32, &str> = Mistake(47);
+  /// assert_eq!(x.ok_mistake(), Some(47));
+  ///
+  /// let x: Outcome<(), u32, &str> = Failure("disk is full");
+  /// assert_eq!(x.ok_mistake(), None);
+  /// ```
+  ///
+  /// [`mistake`]: Outcome::mistake
+  #[inline]
+  pub fn ok_mistake(self) -> Option<M> {
+    match self {
+      Success(_) => None,
+      Mistake(value) => Some(value),
+      Failure(value) => {
+        discard("failure", &value);
+        None
+      }
+    }
+  }
+}
+
+impl<S, M: Debug, F> Outcome<S, M, F> {
+  /// Converts from `Outcome<S, M, F>` to [`Option<F>`], recording a
+  /// discarded [`Mistake`].
+  ///
+  /// This is [`failure`], except that when the `log` or `tracing` feature is
+  /// enabled, a discarded [`Mistake`] value is emitted at the debug level, so
+  /// that it is not lost without a trace.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<(), &str, u32> = Failure(47);
+  /// assert_eq!(x.ok_failure(), Some(47));
+  ///
+  /// let x: Outcome<(), &str, u32> = Mistake("busy");
+  /// assert_eq!(x.ok_failure(), None);
+  /// ```
+  ///
+  /// [`failure`]: Outcome::failure
+  #[inline]
+  pub fn ok_failure(self) -> Option<F> {
+    match self {
+      Success(_) => None,
+      Mistake(value) => {
+        discard("mistake", &value);
+        None
+      }
+      Failure(value) => Some(value),
+    }
+  }
 }
 
 impl<S: Default, M, F> Outcome<S, M, F> {
@@ -1107,7 +2738,7 @@ impl<S, M, F> Outcome<Option<S>, M, F> {
   ///
   /// - `Success(None)` will be mapped to `None`.
   /// - `Success(Some(_))`, `Mistake(_)`, and `Failure(_)` will be mapped to
-  ///     `Some(Success(_))`, `Some(Mistake(_))`, and `Some(Failure(_))`.
+  ///   `Some(Success(_))`, `Some(Mistake(_))`, and `Some(Failure(_))`.
   ///
   /// # Examples
   ///
@@ -1129,6 +2760,190 @@ impl<S, M, F> Outcome<Option<S>, M, F> {
   }
 }
 
+impl<S, M, F> Outcome<S, Outcome<S, M, F>, F> {
+  /// Converts from `Outcome<S, Outcome<S, M, F>, F>` to `Outcome<S, M, F>`
+  ///
+  /// This is useful when a [`Mistake`] carries the outcome of an attempt that
+  /// was retried in its place.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// type Nested = Outcome<u32, Outcome<u32, &'static str, ()>, ()>;
+  ///
+  /// let x: Nested = Mistake(Success(47));
+  /// assert_eq!(x.flatten_mistake(), Success(47));
+  ///
+  /// let x: Nested = Mistake(Mistake("busy"));
+  /// assert_eq!(x.flatten_mistake(), Mistake("busy"));
+  ///
+  /// let x: Nested = Mistake(Failure(()));
+  /// assert_eq!(x.flatten_mistake(), Failure(()));
+  ///
+  /// let x: Nested = Success(42);
+  /// assert_eq!(x.flatten_mistake(), Success(42));
+  /// ```
+  #[inline]
+  pub fn flatten_mistake(self) -> Outcome<S, M, F> {
+    match self {
+      Success(value) | Mistake(Success(value)) => Success(value),
+      Mistake(Mistake(value)) => Mistake(value),
+      Failure(value) | Mistake(Failure(value)) => Failure(value),
+    }
+  }
+}
+
+impl<S, M, F> Outcome<S, M, Aberration<M, F>> {
+  /// Converts from `Outcome<S, M, Aberration<M, F>>` to `Outcome<S, M, F>`
+  ///
+  /// A [`Failure`] containing an [`Aberration::Mistake`] becomes a
+  /// [`Mistake`], while one containing an [`Aberration::Failure`] remains a
+  /// [`Failure`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// type Nested = Outcome<u32, &'static str, Aberration<&'static str, ()>>;
+  ///
+  /// let x: Nested = Failure(Aberration::Mistake("busy"));
+  /// assert_eq!(x.flatten_failure(), Mistake("busy"));
+  ///
+  /// let x: Nested = Failure(Aberration::Failure(()));
+  /// assert_eq!(x.flatten_failure(), Failure(()));
+  ///
+  /// let x: Nested = Mistake("retry");
+  /// assert_eq!(x.flatten_failure(), Mistake("retry"));
+  /// ```
+  #[inline]
+  pub fn flatten_failure(self) -> Outcome<S, M, F> {
+    match self {
+      Success(value) => Success(value),
+      Mistake(value) | Failure(Aberration::Mistake(value)) => Mistake(value),
+      Failure(Aberration::Failure(value)) => Failure(value),
+    }
+  }
+}
+
+impl<S, M> Outcome<S, M, Infallible> {
+  /// Converts an `Outcome` that cannot fail into a [`Concern`].
+  ///
+  /// This is a stable alternative to the `never_type` methods, allowing the
+  /// failure channel to be dropped when it has been statically ruled out,
+  /// without an `unreachable!()` match arm.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::convert::Infallible;
+  ///
+  /// let x: Outcome<u32, &str, Infallible> = Mistake("retry");
+  /// assert_eq!(x.into_concern(), Concern::Mistake("retry"));
+  /// ```
+  #[inline]
+  pub fn into_concern(self) -> Concern<S, M> {
+    match self {
+      Success(value) => Concern::Success(value),
+      Mistake(value) => Concern::Mistake(value),
+      // Unreachable with the `nightly` feature's `exhaustive_patterns`, but
+      // required on stable.
+      #[allow(unreachable_patterns)]
+      Failure(value) => match value {},
+    }
+  }
+}
+
+impl<M, F> Outcome<Infallible, M, F> {
+  /// Converts an `Outcome` that cannot succeed into an [`Aberration`].
+  ///
+  /// This is a stable alternative to the `never_type` methods, allowing the
+  /// success channel to be dropped when it has been statically ruled out,
+  /// without an `unreachable!()` match arm.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use core::convert::Infallible;
+  ///
+  /// let x: Outcome<Infallible, &str, u8> = Failure(2);
+  /// assert_eq!(x.into_aberration(), Aberration::Failure(2));
+  /// ```
+  #[inline]
+  pub fn into_aberration(self) -> Aberration<M, F> {
+    match self {
+      // Unreachable with the `nightly` feature's `exhaustive_patterns`, but
+      // required on stable.
+      #[allow(unreachable_patterns)]
+      Success(value) => match value {},
+      Mistake(value) => Aberration::Mistake(value),
+      Failure(value) => Aberration::Failure(value),
+    }
+  }
+}
+
+impl<S, M, F> Outcome<S, M, F> {
+  /// Converts the contained value into a `T`, regardless of the variant.
+  ///
+  /// This is useful at boundaries where the severity has already been
+  /// recorded elsewhere (e.g., by logging), and only the payload matters.
+  /// When every variant already holds a `T`, [`into_inner`] does the same
+  /// without needing the type to be named.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<u8, u16, u32> = Mistake(429);
+  /// assert_eq!(x.converge::<u64>(), 429);
+  ///
+  /// let x: Outcome<&str, String, String> = Success("done");
+  /// assert_eq!(x.converge::<String>(), "done");
+  /// ```
+  ///
+  /// [`into_inner`]: Outcome::into_inner
+  #[inline]
+  pub fn converge<T>(self) -> T
+  where
+    S: Into<T>,
+    M: Into<T>,
+    F: Into<T>,
+  {
+    match self {
+      Success(value) => value.into(),
+      Mistake(value) => value.into(),
+      Failure(value) => value.into(),
+    }
+  }
+}
+
+impl<T> Outcome<T, T, T> {
+  /// Returns the contained value, regardless of the variant.
+  ///
+  /// This is useful when every variant has already been normalized to a
+  /// common type (e.g., a status message or exit code), and only the payload
+  /// matters. This mirrors the unstable `Result::into_ok_or_err`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// let x: Outcome<&str, &str, &str> = Success("done");
+  /// assert_eq!(x.into_inner(), "done");
+  ///
+  /// let x: Outcome<u8, u8, u8> = Failure(2);
+  /// assert_eq!(x.into_inner(), 2);
+  /// ```
+  #[inline]
+  pub fn into_inner(self) -> T {
+    match self {
+      Success(value) | Mistake(value) | Failure(value) => value,
+    }
+  }
+}
+
 /* Builtin Trait Implementations */
 impl<S: Clone, M: Clone, F: Clone> Clone for Outcome<S, M, F> {
   #[inline]
@@ -1151,17 +2966,112 @@ impl<S: Clone, M: Clone, F: Clone> Clone for Outcome<S, M, F> {
   }
 }
 
+impl<'a, S, M, F> From<&'a Outcome<S, M, F>> for Outcome<&'a S, &'a M, &'a F> {
+  /// Converts from `&Outcome<S, M, F>` to `Outcome<&S, &M, &F>`, as with
+  /// [`Outcome::as_ref`].
+  #[inline]
+  fn from(outcome: &'a Outcome<S, M, F>) -> Self {
+    outcome.as_ref()
+  }
+}
+
+impl<'a, S, M, F> From<&'a mut Outcome<S, M, F>>
+  for Outcome<&'a mut S, &'a mut M, &'a mut F>
+{
+  /// Converts from `&mut Outcome<S, M, F>` to `Outcome<&mut S, &mut M, &mut
+  /// F>`, as with [`Outcome::as_mut`].
+  #[inline]
+  fn from(outcome: &'a mut Outcome<S, M, F>) -> Self {
+    outcome.as_mut()
+  }
+}
+
+impl<S, M, F> From<ControlFlow<Aberration<M, F>, S>> for Outcome<S, M, F> {
+  #[inline]
+  fn from(flow: ControlFlow<Aberration<M, F>, S>) -> Self {
+    match flow {
+      ControlFlow::Continue(value) => Success(value),
+      ControlFlow::Break(Aberration::Mistake(value)) => Mistake(value),
+      ControlFlow::Break(Aberration::Failure(value)) => Failure(value),
+    }
+  }
+}
+
+impl<S, M, F> From<Outcome<S, M, F>> for ControlFlow<Aberration<M, F>, S> {
+  #[inline]
+  fn from(outcome: Outcome<S, M, F>) -> Self {
+    outcome.into_control_flow()
+  }
+}
+
+/// Formats the contained value, prefixed with the name of its variant.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// let x: Outcome<u32, &str, &str> = Success(42);
+/// assert_eq!(x.to_string(), "Success: 42");
+///
+/// let x: Outcome<u32, &str, &str> = Mistake("try again");
+/// assert_eq!(x.to_string(), "Mistake: try again");
+///
+/// let x: Outcome<u32, &str, &str> = Failure("gave up");
+/// assert_eq!(x.to_string(), "Failure: gave up");
+/// ```
+impl<S: Display, M: Display, F: Display> Display for Outcome<S, M, F> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Success(value) => write!(f, "Success: {value}"),
+      Mistake(value) => write!(f, "Mistake: {value}"),
+      Failure(value) => write!(f, "Failure: {value}"),
+    }
+  }
+}
+
+/// Allows an `Outcome` to be returned from `main` and from tests.
+///
+/// A [`Mistake`] or [`Failure`] is printed via [`Debug`] (or the installed
+/// report handler, if the `report` or `diagnostic` feature is enabled), and
+/// [`ExitCode::FAILURE`] is returned. To exit with a distinct code for a
+/// [`Mistake`], see [`ExitOutcome`].
+///
+/// [`ExitOutcome`]: crate::process::ExitOutcome
 #[cfg(feature = "std")]
 impl<M: Debug, F: Debug> Termination for Outcome<(), M, F> {
   #[inline]
   fn report(self) -> ExitCode {
-    #[allow(clippy::print_stderr)]
     match self {
-      Success(()) => return ().report(),
-      Mistake(m) => eprintln!("Mistake: {m:?}"),
-      Failure(f) => eprintln!("Failure: {f:?}"),
+      Success(()) => ().report(),
+      Mistake(m) => terminate("Mistake", &m),
+      Failure(f) => terminate("Failure", &f),
+    }
+  }
+}
+
+/// Allows `main` to choose its exit code on [`Success`], while a [`Mistake`]
+/// or [`Failure`] is reported as it is for `Outcome<(), M, F>`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use outcome::prelude::*;
+/// use std::process::ExitCode;
+///
+/// fn main() -> Outcome<ExitCode, &'static str, &'static str> {
+///   let changed = false;
+///   Success(if changed { ExitCode::from(3) } else { ExitCode::SUCCESS })
+/// }
+/// ```
+#[cfg(feature = "std")]
+impl<M: Debug, F: Debug> Termination for Outcome<ExitCode, M, F> {
+  #[inline]
+  fn report(self) -> ExitCode {
+    match self {
+      Success(code) => code,
+      Mistake(m) => terminate("Mistake", &m),
+      Failure(f) => terminate("Failure", &f),
     }
-    ExitCode::FAILURE
   }
 }
 
@@ -1171,6 +3081,13 @@ mod tests {
   use super::*;
   use std::{string::String, vec, vec::Vec};
 
+  #[test]
+  fn termination_accepts_borrowed_values() {
+    let message = String::from("borrowed");
+    let x: Outcome<(), &str, ()> = Mistake(message.as_str());
+    assert_eq!(x.report(), ExitCode::FAILURE);
+  }
+
   #[test]
   fn filter_map_with() {
     let failures: Vec<Outcome<(), (), String>> = vec![
@@ -1204,5 +3121,13 @@ mod tests {
     fn outcome() -> Outcome<(), (), &'static str> {
       Success(())
     }
+
+    #[test]
+    fn exit_code() {
+      let success: Outcome<_, (), ()> = Success(ExitCode::from(3));
+      assert_eq!(success.report(), ExitCode::from(3));
+      let failure: Outcome<ExitCode, (), _> = Failure("failure");
+      assert_eq!(failure.report(), ExitCode::FAILURE);
+    }
   }
 }
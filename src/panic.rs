@@ -0,0 +1,178 @@
+//! Structured payloads for panics raised by the `unwrap` and `expect`
+//! families.
+//!
+//! By default, calling `unwrap` (or `expect`, `unwrap_mistake`,
+//! `expect_failure`, and so on) on the wrong variant panics with a formatted
+//! string. Once [`install_hook`] has been called, these methods instead panic
+//! with an [`UnwrapPanic`] as the payload, so that test harnesses and panic
+//! hooks can inspect which method was called and which variant was found,
+//! rather than parsing the message.
+//!
+//! The default panic hook cannot print a payload that is not a string, so the
+//! payload is only used once a hook that can print it has been installed.
+//! [`install_hook`] installs one that prints an [`UnwrapPanic`] through its
+//! [`Display`] implementation, producing the same message as before, and
+//! leaves every other panic to the previously installed hook.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::panic::UnwrapPanic;
+//!
+//! outcome::panic::install_hook();
+//! let x: Outcome<u32, &str, ()> = Mistake("busy");
+//! let payload = std::panic::catch_unwind(|| x.unwrap()).unwrap_err();
+//! let panic = payload.downcast_ref::<UnwrapPanic>().unwrap();
+//! assert_eq!(panic.method, "Outcome::unwrap()");
+//! assert_eq!(panic.variant, "Mistake");
+//! assert_eq!(panic.debug_repr, "\"busy\"");
+//! ```
+extern crate std;
+
+use core::{
+  fmt::{self, Debug, Display, Formatter},
+  sync::atomic::{AtomicBool, Ordering},
+};
+use std::{
+  backtrace::{Backtrace, BacktraceStatus},
+  eprintln,
+  panic::{self, PanicHookInfo},
+  string::String,
+  sync::Once,
+  thread,
+};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// The payload of a panic raised by an `unwrap` or `expect` method called on
+/// the wrong variant.
+///
+/// The [`Display`] implementation produces the same message that is used when
+/// no hook has been installed with [`install_hook`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct UnwrapPanic {
+  /// The method that was called, such as `"Outcome::unwrap()"`.
+  pub method: &'static str,
+  /// The variant that was found, such as `"Mistake"`.
+  pub variant: &'static str,
+  /// The message passed to an `expect` method, if one was called.
+  pub message: Option<String>,
+  /// The [`Debug`] representation of the value held by the variant.
+  pub debug_repr: String,
+}
+
+impl UnwrapPanic {
+  pub(crate) fn new(
+    method: &'static str,
+    variant: &'static str,
+    message: Option<&dyn Display>,
+    value: &dyn Debug,
+  ) -> Self {
+    Self {
+      method,
+      variant,
+      message: message.map(|message| std::format!("{message}")),
+      debug_repr: std::format!("{value:?}"),
+    }
+  }
+}
+
+impl Display for UnwrapPanic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match &self.message {
+      Some(message) => write!(f, "{}: {}", message, self.debug_repr),
+      None => write!(
+        f,
+        "Called `{}` on a `{}` value: {}",
+        self.method, self.variant, self.debug_repr
+      ),
+    }
+  }
+}
+
+impl std::error::Error for UnwrapPanic {}
+
+/// Installs a panic hook that prints an [`UnwrapPanic`], and makes the
+/// `unwrap` and `expect` families panic with one as the payload.
+///
+/// The hook prints the [`Display`] output of an [`UnwrapPanic`] along with
+/// the location of the panic, and a backtrace if one was requested via
+/// `RUST_BACKTRACE`. Any other panic is passed to the hook that was installed
+/// before this function was called. Calling this function more than once has
+/// no further effect.
+///
+/// If the hook is later replaced with [`std::panic::set_hook`], the
+/// replacement is responsible for printing an [`UnwrapPanic`].
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+pub fn install_hook() {
+  static HOOK: Once = Once::new();
+  HOOK.call_once(|| {
+    let previous = panic::take_hook();
+    panic::set_hook(std::boxed::Box::new(move |info| {
+      match info.payload().downcast_ref::<UnwrapPanic>() {
+        Some(payload) => print(info, payload),
+        None => previous(info),
+      }
+    }));
+    INSTALLED.store(true, Ordering::Release);
+  });
+}
+
+/* Mirrors the output of the default panic hook. */
+#[allow(clippy::print_stderr)]
+fn print(info: &PanicHookInfo<'_>, payload: &UnwrapPanic) {
+  let thread = thread::current();
+  let name = thread.name().unwrap_or("<unnamed>");
+  match info.location() {
+    Some(location) => {
+      eprintln!("\nthread '{name}' panicked at {location}:\n{payload}");
+    }
+    None => eprintln!("\nthread '{name}' panicked:\n{payload}"),
+  }
+  let backtrace = Backtrace::capture();
+  if backtrace.status() == BacktraceStatus::Captured {
+    eprintln!("stack backtrace:\n{backtrace}");
+  }
+}
+
+pub(crate) fn installed() -> bool {
+  INSTALLED.load(Ordering::Acquire)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::*;
+  use std::{panic::catch_unwind, string::ToString};
+
+  #[test]
+  fn aberration_payload() {
+    install_hook();
+    let x: Aberration<u8, &str> = Aberration::Failure("lost");
+    let payload = catch_unwind(|| x.unwrap_mistake()).unwrap_err();
+    let panic = payload.downcast::<UnwrapPanic>().unwrap();
+    assert_eq!(panic.method, "Aberration::unwrap_mistake()");
+    assert_eq!(panic.variant, "Failure");
+    assert_eq!(
+      panic.to_string(),
+      "Called `Aberration::unwrap_mistake()` on a `Failure` value: \"lost\""
+    );
+  }
+
+  #[test]
+  fn expect_payload() {
+    install_hook();
+    let x: Outcome<u8, (), &str> = Failure("lost");
+    let payload = catch_unwind(|| x.expect("reading config")).unwrap_err();
+    let panic = payload.downcast::<UnwrapPanic>().unwrap();
+    assert_eq!(panic.method, "Outcome::expect()");
+    assert_eq!(panic.variant, "Failure");
+    assert_eq!(panic.message.as_deref(), Some("reading config"));
+    assert_eq!(panic.to_string(), "reading config: \"lost\"");
+  }
+}
@@ -0,0 +1,215 @@
+//! Composition of fallible processing stages.
+//!
+//! A [`Pipeline`] chains functions of the form `A -> Outcome<B, M, F>` into a
+//! single value which can then be [`execute`]d any number of times. Each
+//! stage may use its own mistake and failure types, so long as they can be
+//! converted [`Into`] the pipeline's mistake and failure types. Every stage is
+//! given a name, and when a stage returns a [`Mistake`] or [`Failure`], the
+//! error is tagged with the name of the stage that produced it.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::pipeline::Pipeline;
+//!
+//! let pipeline = Pipeline::<&str, &str, String, String>::new()
+//!   .stage("decode", |input| -> Outcome<u32, String, String> {
+//!     match input.parse() {
+//!       Ok(value) => Success(value),
+//!       Err(error) => Failure(format!("{error}")),
+//!     }
+//!   })
+//!   .stage("validate", |value| -> Outcome<u32, &str, String> {
+//!     match value {
+//!       0 => Mistake("value must not be zero"),
+//!       value => Success(value),
+//!     }
+//!   })
+//!   .stage("transform", |value: u32| -> Outcome<_, &str, &str> {
+//!     Success(value * 2)
+//!   });
+//!
+//! assert_eq!(pipeline.stages(), ["decode", "validate", "transform"]);
+//! assert_eq!(pipeline.execute("21"), Success(42));
+//!
+//! let mistake = pipeline.execute("0").unwrap_mistake();
+//! assert_eq!(mistake.name(), "validate");
+//! assert_eq!(mistake.to_string(), "validate: value must not be zero");
+//!
+//! let failure = pipeline.execute("x").unwrap_failure();
+//! assert_eq!(failure.name(), "decode");
+//! ```
+//!
+//! [`execute`]: Pipeline::execute
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::prelude::*;
+
+type Run<I, O, M, F> = Box<dyn Fn(I) -> Outcome<O, Staged<M>, Staged<F>>>;
+
+/// An error value tagged with the name of the stage that produced it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Staged<E> {
+  name: &'static str,
+  error: E,
+}
+
+impl<E> Staged<E> {
+  /// Returns the name of the stage that produced the error.
+  #[must_use]
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns a reference to the error.
+  #[must_use]
+  #[inline]
+  pub const fn error(&self) -> &E {
+    &self.error
+  }
+
+  /// Consumes the `Staged`, returning the error.
+  #[inline]
+  pub fn into_error(self) -> E {
+    self.error
+  }
+}
+
+impl<E: Display> Display for Staged<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}: {}", self.name, self.error)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for Staged<E>
+where
+  E: std::error::Error + 'static,
+{
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.error)
+  }
+}
+
+/// A sequence of named stages, executed in order.
+///
+/// See the [module documentation](crate::pipeline) for details.
+pub struct Pipeline<I, O, M, F> {
+  stages: Vec<&'static str>,
+  run: Run<I, O, M, F>,
+}
+
+impl<I: 'static, M: 'static, F: 'static> Pipeline<I, I, M, F> {
+  /// Creates an empty pipeline, which returns its input as a [`Success`].
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      stages: Vec::new(),
+      run: Box::new(Success),
+    }
+  }
+}
+
+impl<I: 'static, M: 'static, F: 'static> Default for Pipeline<I, I, M, F> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<I: 'static, O: 'static, M: 'static, F: 'static> Pipeline<I, O, M, F> {
+  /// Appends a stage to the pipeline.
+  ///
+  /// The stage is only invoked when every stage before it has returned a
+  /// [`Success`]. Its mistake and failure types are converted into those of
+  /// the pipeline with [`Into`].
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[must_use]
+  pub fn stage<P, SM, SF, G>(
+    mut self,
+    name: &'static str,
+    stage: G,
+  ) -> Pipeline<I, P, M, F>
+  where
+    G: Fn(O) -> Outcome<P, SM, SF> + 'static,
+    SM: Into<M>,
+    SF: Into<F>,
+  {
+    let run = self.run;
+    self.stages.push(name);
+    Pipeline {
+      stages: self.stages,
+      run: Box::new(move |input| {
+        run(input).and_then(|value| {
+          stage(value)
+            .map_mistake(|error| Staged {
+              name,
+              error: error.into(),
+            })
+            .map_failure(|error| Staged {
+              name,
+              error: error.into(),
+            })
+        })
+      }),
+    }
+  }
+}
+
+impl<I, O, M, F> Pipeline<I, O, M, F> {
+  /// Returns the names of each stage, in the order they are executed.
+  #[must_use]
+  #[inline]
+  pub fn stages(&self) -> &[&'static str] {
+    &self.stages
+  }
+
+  /// Executes every stage of the pipeline on `input`, stopping at the first
+  /// stage that does not return a [`Success`].
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[inline]
+  pub fn execute(&self, input: I) -> Outcome<O, Staged<M>, Staged<F>> {
+    (self.run)(input)
+  }
+}
+
+impl<I, O, M, F> Debug for Pipeline<I, O, M, F> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Pipeline")
+      .field("stages", &self.stages)
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty() {
+    let pipeline = Pipeline::<u8, u8, (), ()>::new();
+    assert!(pipeline.stages().is_empty());
+    assert_eq!(pipeline.execute(7), Success(7));
+  }
+
+  #[test]
+  fn short_circuit() {
+    let pipeline = Pipeline::<u8, u8, (), u16>::new()
+      .stage("first", |x: u8| -> Outcome<u8, (), u8> { Failure(x) })
+      .stage("second", |_: u8| -> Outcome<u8, (), u8> { unreachable!() });
+    let failure = pipeline.execute(3).unwrap_failure();
+    assert_eq!(failure.name(), "first");
+    assert_eq!(failure.into_error(), 3u16);
+  }
+}
@@ -1,21 +1,268 @@
-use core::fmt::Debug;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::{eprintln, process::ExitCode};
 
 /* Much like the internal `unwrap_failed` function found in core::result, this
  * function helps reduce method code size. Given that we have several types
  * that all have `unwrap(_.+)?` names, this helps immensely for generated code.
+ * With the `panic-payload` feature, the panic carries an `UnwrapPanic` in
+ * place of the formatted message once `panic::install_hook` has been called.
  */
 #[inline(never)]
 #[track_caller]
 #[cold]
-pub fn panic(method: &str, variant: &str, error: &dyn Debug) -> ! {
+pub fn panic(
+  method: &'static str,
+  variant: &'static str,
+  error: &dyn Debug,
+) -> ! {
+  #[cfg(feature = "panic-payload")]
+  unwind(crate::panic::UnwrapPanic::new(method, variant, None, error));
+  #[cfg(not(feature = "panic-payload"))]
   panic!("Called `{}` on a `{}` value: {:?}", method, variant, error);
 }
 
-pub trait Sealed {}
+/* The `expect` family mirrors `core::result::Result::expect`, and uses the
+ * caller provided message in place of the method and variant.
+ */
+#[inline(never)]
+#[track_caller]
+#[cold]
+#[allow(unused_variables)]
+pub fn expect(
+  method: &'static str,
+  variant: &'static str,
+  message: &dyn Display,
+  error: &dyn Debug,
+) -> ! {
+  #[cfg(feature = "panic-payload")]
+  unwind(crate::panic::UnwrapPanic::new(
+    method,
+    variant,
+    Some(message),
+    error,
+  ));
+  #[cfg(not(feature = "panic-payload"))]
+  panic!("{}: {:?}", message, error);
+}
+
+/* The default panic hook only prints string payloads, so the `UnwrapPanic`
+ * is only used as the payload once a hook that prints it is installed.
+ */
+#[cfg(feature = "panic-payload")]
+#[track_caller]
+fn unwind(payload: crate::panic::UnwrapPanic) -> ! {
+  if crate::panic::installed() {
+    std::panic::panic_any(payload);
+  }
+  panic!("{payload}");
+}
+
+/* Every `Termination` implementation funnels through this function, which
+ * prints the value before an exit code is chosen. When either the `report` or
+ * `diagnostic` feature is enabled, the `Debug` output of the value is rendered
+ * by the installed `eyre` or `miette` report handler, so that returning an
+ * `Outcome` from `main` reads the same as returning a `Report`. As this only
+ * requires `Debug`, enabling either feature does not change which types may
+ * be returned from `main`.
+ */
+#[cfg(feature = "std")]
+#[inline(never)]
+#[cold]
+#[allow(clippy::print_stderr)]
+pub fn describe(variant: &str, value: &dyn Debug) {
+  #[cfg(any(feature = "report", feature = "diagnostic"))]
+  message(variant, std::format!("{value:?}"));
+  #[cfg(not(any(feature = "report", feature = "diagnostic")))]
+  eprintln!("{variant}: {value:?}");
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn terminate(variant: &str, value: &dyn Debug) -> ExitCode {
+  describe(variant, value);
+  ExitCode::FAILURE
+}
+
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+#[allow(clippy::print_stderr)]
+fn message(variant: &str, message: std::string::String) {
+  #[cfg(feature = "diagnostic")]
+  {
+    use miette::{MietteDiagnostic, Report, Severity};
+    let severity = match variant {
+      "Mistake" => Severity::Warning,
+      _ => Severity::Error,
+    };
+    let diagnostic = MietteDiagnostic::new(message).with_severity(severity);
+    eprintln!("{variant}: {:?}", Report::new(diagnostic));
+  }
+  #[cfg(not(feature = "diagnostic"))]
+  eprintln!("{variant}: {:?}", eyre::Report::msg(message));
+}
 
 #[cfg(any(feature = "report", feature = "diagnostic"))]
+fn take<T: 'static>(slot: &mut dyn core::any::Any) -> Option<T> {
+  slot.downcast_mut::<Option<T>>()?.take()
+}
+
+/* Used by `process::ReportOutcome`, which opts into inspecting the type of
+ * the value being printed. Values that already are a `Report` are printed as
+ * is, and boxed errors (as well as `anyhow::Error`) are converted into one,
+ * keeping their source chain, spans, and severity. Any other value is
+ * rendered from its `Debug` output, exactly as `describe` does.
+ */
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+#[inline(never)]
+#[cold]
+#[allow(clippy::print_stderr)]
+pub fn render<T: Debug + 'static>(variant: &str, value: T) {
+  use std::{boxed::Box, error::Error};
+  let mut slot = Some(value);
+  let slot = &mut slot;
+  #[cfg(feature = "diagnostic")]
+  if let Some(report) = take::<miette::Report>(slot) {
+    eprintln!("{variant}: {report:?}");
+    return;
+  }
+  #[cfg(feature = "report")]
+  if let Some(report) = take::<eyre::Report>(slot) {
+    eprintln!("{variant}: {report:?}");
+    return;
+  }
+  #[cfg(feature = "diagnostic")]
+  if let Some(diagnostic) =
+    take::<Box<dyn miette::Diagnostic + Send + Sync>>(slot)
+  {
+    eprintln!("{variant}: {:?}", miette::Report::new_boxed(diagnostic));
+    return;
+  }
+  let error = take::<Box<dyn Error + Send + Sync>>(slot);
+  #[cfg(feature = "anyhow")]
+  let error = error.or_else(|| take::<::anyhow::Error>(slot).map(Into::into));
+  if let Some(error) = error {
+    #[cfg(feature = "diagnostic")]
+    eprintln!("{variant}: {:?}", miette::Report::new_boxed(error.into()));
+    #[cfg(not(feature = "diagnostic"))]
+    eprintln!("{variant}: {:?}", eyre::eyre!(error));
+    return;
+  }
+  if let Some(value) = slot.take() {
+    message(variant, std::format!("{value:?}"));
+  }
+}
+
+/* The `mistake!` and `failure!` macros (and every macro built on them)
+ * construct their outcome through these functions, so that values entering an
+ * outcome through a macro invoke the registered hooks, as the hooked
+ * constructors do. Without `std` there are no hooks to invoke.
+ */
+#[doc(hidden)]
+#[track_caller]
+#[inline]
+pub fn mistake<S, M: Debug, F>(value: M) -> crate::outcome::Outcome<S, M, F> {
+  #[cfg(feature = "std")]
+  return crate::outcome::Outcome::mistake_hooked(value);
+  #[cfg(not(feature = "std"))]
+  crate::outcome::Outcome::Mistake(value)
+}
+
+#[doc(hidden)]
+#[track_caller]
+#[inline]
+pub fn failure<S, M, F: Debug>(value: F) -> crate::outcome::Outcome<S, M, F> {
+  #[cfg(feature = "std")]
+  return crate::outcome::Outcome::failure_hooked(value);
+  #[cfg(not(feature = "std"))]
+  crate::outcome::Outcome::Failure(value)
+}
+
+/* Reports created by `WrapFailure` and the `fail!` family of macros inside a
+ * `context::scope` have the active context stack added as their outermost
+ * message.
+ */
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn contextualize<R: crate::backend::ReportBackend>(report: R) -> R {
+  match crate::context::current() {
+    Some(context) => report.wrap(context),
+    None => report,
+  }
+}
+
+/* Records an error discarded by one of the `ok_*` conversions. Without the
+ * `log` or `tracing` feature, the value is dropped silently.
+ */
+#[inline(never)]
+#[cold]
+#[allow(unused_variables)]
+pub fn discard(variant: &str, value: &dyn Debug) {
+  #[cfg(feature = "log")]
+  ::log::debug!(target: "outcome", "discarded {variant}: {value:?}");
+  #[cfg(feature = "tracing")]
+  ::tracing::debug!(outcome = variant, value = ?value, "discarded");
+}
+
+/* Reports a `Checked` outcome that was dropped without being inspected. A
+ * panic while already unwinding would abort the process, so the report is
+ * only printed in that case, as it is in release builds.
+ */
+#[cfg(feature = "std")]
+#[inline(never)]
+#[cold]
+#[allow(clippy::print_stderr)]
+pub fn unhandled(variant: &str, location: &core::panic::Location<'_>) {
+  if cfg!(debug_assertions) && !std::thread::panicking() {
+    panic!("unhandled `{variant}` created at {location}");
+  }
+  eprintln!("unhandled `{variant}` created at {location}");
+}
+
+/* Used by the `acclimated` attribute, so that `?` accepts both an `Outcome`
+ * and a `Result` without knowing the type of the operand.
+ */
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub trait Acclimate {
+  type Output;
+  fn acclimate(self) -> Self::Output;
+}
+
+#[cfg(feature = "derive")]
+impl<S, M, F> Acclimate for crate::outcome::Outcome<S, M, F> {
+  type Output = Result<crate::concern::Concern<S, M>, F>;
+
+  #[inline]
+  fn acclimate(self) -> Self::Output {
+    Self::acclimate(self)
+  }
+}
+
+#[cfg(feature = "derive")]
+impl<T, E> Acclimate for Result<T, E> {
+  type Output = Self;
+
+  #[inline]
+  fn acclimate(self) -> Self::Output {
+    self
+  }
+}
+
+pub trait Sealed {}
+
 impl<T, E> Sealed for Result<T, E> {}
+impl Sealed for str {}
 
 impl<S, M, F> Sealed for crate::outcome::Outcome<S, M, F> {}
 impl<M, F> Sealed for crate::aberration::Aberration<M, F> {}
 impl<S, M> Sealed for crate::concern::Concern<S, M> {}
+
+#[cfg(feature = "std")]
+impl Sealed for std::net::TcpListener {}
+#[cfg(feature = "std")]
+impl Sealed for std::net::TcpStream {}
+#[cfg(feature = "std")]
+impl Sealed for std::net::UdpSocket {}
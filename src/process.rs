@@ -0,0 +1,297 @@
+//! Distinct process exit codes for a [`Mistake`] and a [`Failure`].
+//!
+//! Returning an [`Outcome`] or [`Aberration`] from `main` exits with
+//! [`ExitCode::FAILURE`] for both a [`Mistake`] and a [`Failure`]. A shell
+//! script invoking the program then cannot tell "try again later" apart from
+//! "this will never work". [`ExitOutcome`] wraps an [`Outcome`] and exits with
+//! [`MISTAKE`] (`2`) for a [`Mistake`], and [`FAILURE`] (`1`) for a
+//! [`Failure`]. Either code can be changed with
+//! [`ExitOutcome::with_mistake_code`] and [`ExitOutcome::with_failure_code`].
+//!
+//! The contained value is printed exactly as it would be had the [`Outcome`]
+//! itself been returned from `main`.
+//!
+//! When the `report` or `diagnostic` feature is enabled, a [`Mistake`] or
+//! [`Failure`] returned from `main` is rendered from its [`Debug`] output by
+//! the installed report handler. [`ReportOutcome`] additionally inspects the
+//! type of the value, so that a `Report` or boxed error keeps its source chain,
+//! spans, and severity. This requires the values to be `'static`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use outcome::prelude::*;
+//! use outcome::process::ExitOutcome;
+//!
+//! fn run() -> Outcome<(), &'static str, &'static str> {
+//!   Mistake("the lock file is held by another process")
+//! }
+//!
+//! // Exits with a status of 75 (EX_TEMPFAIL), which a wrapping script can
+//! // use to schedule another attempt.
+//! fn main() -> ExitOutcome<(), &'static str, &'static str> {
+//!   ExitOutcome::new(run()).with_mistake_code(75)
+//! }
+//! ```
+//!
+//! [`Debug`]: core::fmt::Debug
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use core::fmt::Debug;
+use std::process::{ExitCode, Termination};
+
+use crate::{prelude::*, private::describe};
+
+/// The exit code used for a [`Mistake`] by default.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+pub const MISTAKE: u8 = 2;
+
+/// The exit code used for a [`Failure`] by default.
+///
+/// [`Failure`]: crate::prelude::Failure
+pub const FAILURE: u8 = 1;
+
+/// An [`Outcome`] that exits with a different code for a [`Mistake`] than for
+/// a [`Failure`] when returned from `main`.
+///
+/// See the [module documentation](crate::process) for details.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct ExitOutcome<S, M, F> {
+  outcome: Outcome<S, M, F>,
+  mistake: u8,
+  failure: u8,
+}
+
+impl<S, M, F> ExitOutcome<S, M, F> {
+  /// Wraps `outcome`, using [`MISTAKE`] and [`FAILURE`] as its exit codes.
+  #[inline]
+  pub const fn new(outcome: Outcome<S, M, F>) -> Self {
+    Self {
+      outcome,
+      mistake: MISTAKE,
+      failure: FAILURE,
+    }
+  }
+
+  /// Sets the exit code used for a [`Mistake`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub fn with_mistake_code(self, code: u8) -> Self {
+    Self {
+      mistake: code,
+      ..self
+    }
+  }
+
+  /// Sets the exit code used for a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn with_failure_code(self, code: u8) -> Self {
+    Self {
+      failure: code,
+      ..self
+    }
+  }
+
+  /// Returns the exit code used for a [`Mistake`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub const fn mistake_code(&self) -> u8 {
+    self.mistake
+  }
+
+  /// Returns the exit code used for a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub const fn failure_code(&self) -> u8 {
+    self.failure
+  }
+
+  /// Returns a reference to the wrapped [`Outcome`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[inline]
+  pub const fn outcome(&self) -> &Outcome<S, M, F> {
+    &self.outcome
+  }
+
+  /// Consumes the `ExitOutcome`, returning the wrapped [`Outcome`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[inline]
+  pub fn into_inner(self) -> Outcome<S, M, F> {
+    self.outcome
+  }
+}
+
+impl<S, M, F> From<Outcome<S, M, F>> for ExitOutcome<S, M, F> {
+  #[inline]
+  fn from(outcome: Outcome<S, M, F>) -> Self {
+    Self::new(outcome)
+  }
+}
+
+impl<S, M, F> Termination for ExitOutcome<S, M, F>
+where
+  S: Termination,
+  M: Debug,
+  F: Debug,
+{
+  fn report(self) -> ExitCode {
+    match self.outcome {
+      Success(value) => value.report(),
+      Mistake(value) => {
+        describe("Mistake", &value);
+        ExitCode::from(self.mistake)
+      }
+      Failure(value) => {
+        describe("Failure", &value);
+        ExitCode::from(self.failure)
+      }
+    }
+  }
+}
+
+/// An [`Outcome`] whose [`Mistake`] or [`Failure`] keeps its source chain when
+/// returned from `main`.
+///
+/// Returning an [`Outcome`] from `main` renders its error from the [`Debug`]
+/// output alone. A `ReportOutcome` instead prints a `Report` through its own
+/// handler, and converts a `Box<dyn Error + Send + Sync>` (or, with the
+/// `diagnostic` feature, a `Box<dyn Diagnostic + Send + Sync>`, and with the
+/// `anyhow` feature, an `anyhow::Error`) into one first. Any other value is
+/// rendered from its [`Debug`] output. Both a [`Mistake`] and a [`Failure`]
+/// exit with [`ExitCode::FAILURE`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use outcome::prelude::*;
+/// use outcome::process::ReportOutcome;
+///
+/// type Error = Box<dyn std::error::Error + Send + Sync>;
+///
+/// fn run() -> Outcome<(), &'static str, Error> {
+///   match "forty-two".parse::<u32>() {
+///     Ok(_) => Success(()),
+///     Err(error) => Failure(error.into()),
+///   }
+/// }
+///
+/// // Prints the `ParseIntError` through the installed report handler.
+/// fn main() -> ReportOutcome<(), &'static str, Error> {
+///   ReportOutcome::new(run())
+/// }
+/// ```
+///
+/// [`Debug`]: core::fmt::Debug
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+#[cfg_attr(
+  any(docsrs, nightly),
+  doc(cfg(any(feature = "report", feature = "diagnostic")))
+)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct ReportOutcome<S, M, F> {
+  outcome: Outcome<S, M, F>,
+}
+
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+impl<S, M, F> ReportOutcome<S, M, F> {
+  /// Wraps `outcome`.
+  #[inline]
+  pub const fn new(outcome: Outcome<S, M, F>) -> Self {
+    Self { outcome }
+  }
+
+  /// Returns a reference to the wrapped [`Outcome`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[inline]
+  pub const fn outcome(&self) -> &Outcome<S, M, F> {
+    &self.outcome
+  }
+
+  /// Consumes the `ReportOutcome`, returning the wrapped [`Outcome`].
+  ///
+  /// [`Outcome`]: crate::prelude::Outcome
+  #[inline]
+  pub fn into_inner(self) -> Outcome<S, M, F> {
+    self.outcome
+  }
+}
+
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+impl<S, M, F> From<Outcome<S, M, F>> for ReportOutcome<S, M, F> {
+  #[inline]
+  fn from(outcome: Outcome<S, M, F>) -> Self {
+    Self::new(outcome)
+  }
+}
+
+#[cfg(any(feature = "report", feature = "diagnostic"))]
+impl<S, M, F> Termination for ReportOutcome<S, M, F>
+where
+  S: Termination,
+  M: Debug + 'static,
+  F: Debug + 'static,
+{
+  fn report(self) -> ExitCode {
+    match self.outcome {
+      Success(value) => value.report(),
+      Mistake(value) => {
+        crate::private::render("Mistake", value);
+        ExitCode::FAILURE
+      }
+      Failure(value) => {
+        crate::private::render("Failure", value);
+        ExitCode::FAILURE
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn distinct_codes() {
+    let mistake = ExitOutcome::<(), _, ()>::new(Mistake("busy"));
+    assert_eq!(mistake.report(), ExitCode::from(MISTAKE));
+    let failure = ExitOutcome::<(), (), _>::new(Failure("gone"));
+    assert_eq!(failure.with_failure_code(70).report(), ExitCode::from(70));
+    let success = ExitOutcome::<(), (), ()>::new(Success(()));
+    assert_eq!(success.report(), ExitCode::SUCCESS);
+  }
+
+  #[cfg(any(feature = "report", feature = "diagnostic"))]
+  #[test]
+  fn report_outcome() {
+    use std::{boxed::Box, error::Error};
+    let error: Box<dyn Error + Send + Sync> = "gone".into();
+    let failure = ReportOutcome::<(), (), _>::new(Failure(error));
+    assert_eq!(failure.report(), ExitCode::FAILURE);
+    let success = ReportOutcome::<(), (), ()>::from(Success(()));
+    assert_eq!(success.report(), ExitCode::SUCCESS);
+  }
+}
@@ -0,0 +1,173 @@
+//! Integration with the [`rayon`] data parallelism library.
+//!
+//! When the `rayon` feature is enabled, a [`ParallelIterator`] over
+//! [`Outcome`]s can be collected into an [`Outcome`], in the same way as a
+//! sequential [`Iterator`]: every [`Success`] is collected, unless a
+//! [`Mistake`] or [`Failure`] is found, in which case no further items are
+//! processed and that value is returned instead. Because items are processed
+//! in parallel, if more than one [`Mistake`] or [`Failure`] is found, which
+//! one is returned is not deterministic.
+//!
+//! The [`ParallelOutcomeIteratorExt`] extension trait provides
+//! [`par_partition_outcomes`], the parallel equivalent of
+//! [`OutcomeIteratorExt::partition_outcomes`], so that every error in a large
+//! batch can be reported at once.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use rayon::prelude::*;
+//!
+//! fn parse(input: &str) -> Outcome<u32, &str, &str> {
+//!   match input.parse() {
+//!     Ok(value) => Success(value),
+//!     Err(_) if input.is_empty() => Mistake("empty"),
+//!     Err(_) => Failure(input),
+//!   }
+//! }
+//!
+//! let x: Outcome<Vec<_>, _, _> = ["1", "2", "3"].par_iter().map(|s| parse(s)).collect();
+//! assert_eq!(x, Success(vec![1, 2, 3]));
+//!
+//! let x: Outcome<Vec<_>, _, _> = ["1", "", "3"].par_iter().map(|s| parse(s)).collect();
+//! assert_eq!(x, Mistake("empty"));
+//! ```
+//!
+//! [`rayon`]: https://docs.rs/rayon
+//! [`ParallelIterator`]: ::rayon::iter::ParallelIterator
+//! [`par_partition_outcomes`]: ParallelOutcomeIteratorExt::par_partition_outcomes
+//! [`OutcomeIteratorExt::partition_outcomes`]: crate::OutcomeIteratorExt::partition_outcomes
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+extern crate std;
+
+use std::sync::{Mutex, PoisonError};
+
+use ::rayon::iter::{
+  Either, FromParallelIterator, IntoParallelIterator, ParallelExtend,
+  ParallelIterator,
+};
+
+use crate::prelude::*;
+
+/// Collects every [`Success`] of a [`ParallelIterator`], unless a [`Mistake`]
+/// or [`Failure`] is found, in which case that value is returned.
+///
+/// If more than one [`Mistake`] or [`Failure`] is found, which one is
+/// returned is not deterministic.
+///
+/// [`ParallelIterator`]: ::rayon::iter::ParallelIterator
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+impl<C, S, M, F> FromParallelIterator<Outcome<S, M, F>> for Outcome<C, M, F>
+where
+  C: FromParallelIterator<S>,
+  S: Send,
+  M: Send,
+  F: Send,
+{
+  fn from_par_iter<I>(iter: I) -> Self
+  where
+    I: IntoParallelIterator<Item = Outcome<S, M, F>>,
+  {
+    let saved = Mutex::new(None);
+    let collection = iter
+      .into_par_iter()
+      .map(|outcome| match outcome {
+        Success(value) => Some(value),
+        Mistake(value) => save(&saved, Aberration::Mistake(value)),
+        Failure(value) => save(&saved, Aberration::Failure(value)),
+      })
+      .while_some()
+      .collect();
+    match saved.into_inner().unwrap_or_else(PoisonError::into_inner) {
+      None => Success(collection),
+      Some(Aberration::Mistake(value)) => Mistake(value),
+      Some(Aberration::Failure(value)) => Failure(value),
+    }
+  }
+}
+
+/* A blocking lock is not needed, as whoever else holds the lock is saving an
+ * error of their own, making ours irrelevant.
+ */
+fn save<S, M, F>(
+  saved: &Mutex<Option<Aberration<M, F>>>,
+  error: Aberration<M, F>,
+) -> Option<S> {
+  if let Ok(mut guard) = saved.try_lock() {
+    guard.get_or_insert(error);
+  }
+  None
+}
+
+/// Extension methods for a [`ParallelIterator`] over [`Outcome`]s.
+///
+/// This trait is implemented for every [`ParallelIterator`] whose items are
+/// an [`Outcome`].
+///
+/// [`ParallelIterator`]: ::rayon::iter::ParallelIterator
+/// [`Outcome`]: crate::prelude::Outcome
+pub trait ParallelOutcomeIteratorExt<S, M, F>:
+  ParallelIterator<Item = Outcome<S, M, F>>
+where
+  S: Send,
+  M: Send,
+  F: Send,
+{
+  /// Consumes the iterator in parallel, splitting every [`Success`],
+  /// [`Mistake`], and [`Failure`] into separate collections.
+  ///
+  /// This does not stop at the first [`Mistake`] or [`Failure`]. Unless the
+  /// iterator is indexed and the collections preserve order (such as
+  /// [`Vec`]), the order of the values in each collection is not
+  /// deterministic.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::rayon::ParallelOutcomeIteratorExt;
+  /// use rayon::prelude::*;
+  ///
+  /// let batch = vec![Success(1), Mistake("busy"), Success(2), Failure(0.5)];
+  /// let (successes, mistakes, failures): (Vec<_>, Vec<_>, Vec<_>) =
+  ///   batch.into_par_iter().par_partition_outcomes();
+  ///
+  /// assert_eq!(successes, [1, 2]);
+  /// assert_eq!(mistakes, ["busy"]);
+  /// assert_eq!(failures, [0.5]);
+  /// ```
+  ///
+  /// [`Vec`]: std::vec::Vec
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn par_partition_outcomes<A, B, C>(self) -> (A, B, C)
+  where
+    A: Default + Send + ParallelExtend<S>,
+    B: Default + Send + ParallelExtend<M>,
+    C: Default + Send + ParallelExtend<F>,
+  {
+    let (successes, (mistakes, failures)) =
+      self.partition_map(|outcome| match outcome {
+        Success(value) => Either::Left(value),
+        Mistake(value) => Either::Right(Either::Left(value)),
+        Failure(value) => Either::Right(Either::Right(value)),
+      });
+    (successes, mistakes, failures)
+  }
+}
+
+impl<I, S, M, F> ParallelOutcomeIteratorExt<S, M, F> for I
+where
+  I: ParallelIterator<Item = Outcome<S, M, F>>,
+  S: Send,
+  M: Send,
+  F: Send,
+{
+}
@@ -1,22 +1,241 @@
 //! Support for the [`eyre`] crate.
 //!
-//! This module re-exports [`Report`] as well as [`Result`], and provides a
-//! trait, [`WrapFailure`], as a mirror to the [`WrapErr`] trait. Additionally,
+//! This module re-exports [`Report`] as well as [`Result`], and the
+//! [`WrapFailure`] trait, as a mirror to the [`WrapErr`] trait. Additionally,
 //! [`WrapFailure`] is also implemented for [`Result<T, E>`]. Lastly, to stay
 //! in line with behavior from [`eyre`], the [`WrapFailure`] trait is *also*
-//! sealed.
+//! sealed. Notes can be attached to a failure with the [`Section`] trait.
+//!
+//! These traits are shared with the `diagnostic` and `anyhow` modules (see
+//! the [`backend`](crate::backend) module), and convert a failure into
+//! whichever report type is expected:
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::report::{Report, WrapFailure};
+//! use std::io::{Error, ErrorKind};
+//!
+//! fn load() -> Outcome<(), (), Report> {
+//!   let x: Outcome<(), (), Error> = Failure(ErrorKind::NotFound.into());
+//!   x.wrap_failure("Failed to read configuration")
+//! }
+//!
+//! let report = load().unwrap_failure();
+//! assert_eq!(report.to_string(), "Failed to read configuration");
+//! ```
+//!
+//! Several failures can be joined into a single [`Report`] with
+//! [`aggregate_reports`].
+//!
+//! A function returning an `Outcome` can exit early with a new [`Report`]
+//! through the [`fail!`] and [`retry!`] macros, which produce a [`Failure`]
+//! and a [`Mistake`] respectively. The [`ensure!`] and [`ensure_or_mistake!`]
+//! macros do the same when a condition does not hold.
+//!
+//! Mistakes can be given context as well, with the [`WrapMistake`] trait:
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::report::{Report, WrapMistake};
+//! use std::io::{Error, ErrorKind};
+//!
+//! let x: Outcome<(), Error, ()> = Mistake(ErrorKind::WouldBlock.into());
+//! let x: Outcome<(), Report, ()> = x.wrap_mistake("Failed to connect");
+//! assert_eq!(x.unwrap_mistake().to_string(), "Failed to connect");
+//! ```
 //!
 //! [`WrapErr`]: eyre::WrapErr
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
 //! [`Result`]: eyre::Result
 //! [`eyre`]: https://crates.io/crates/eyre
 extern crate std;
 
 use crate::prelude::*;
-use std::{error::Error, fmt::Display};
+use std::fmt::Display;
 
+#[doc(no_inline)]
+pub use crate::backend::{Section, WrapFailure, WrapMistake};
 #[doc(no_inline)]
 pub use eyre::{Report, Result};
 
-crate::wrap::r#trait!(Error);
-crate::wrap::r#impl!(Error);
-crate::wrap::result!(eyre);
+crate::wrap::aggregate!(report);
+
+/// Returns a [`Failure`] from the enclosing function, holding a [`Report`].
+///
+/// This is the `outcome` analogue of [`eyre::bail!`], and takes the same
+/// arguments as [`eyre::eyre!`]. The [`Report`] is converted with [`From`],
+/// so the function must return an `Outcome<_, _, Report>`, or any failure type
+/// that a [`Report`] converts into. The registered failure hook is invoked, as
+/// with [`Outcome::failure_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::report::{fail, Report};
+///
+/// fn parse(input: &str) -> Outcome<u32, (), Report> {
+///   if input.is_empty() {
+///     fail!("no input given");
+///   }
+///   match input.parse() {
+///     Ok(value) => Success(value),
+///     Err(error) => fail!("invalid input {input:?}: {error}"),
+///   }
+/// }
+///
+/// assert_eq!(parse("47").unwrap(), 47);
+/// assert_eq!(parse("").unwrap_failure().to_string(), "no input given");
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`eyre::bail!`]: ::eyre::bail
+/// [`eyre::eyre!`]: ::eyre::eyre
+/// [`From`]: core::convert::From
+/// [`Outcome::failure_hooked`]: crate::prelude::Outcome::failure_hooked
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __report_fail {
+  ($($arguments:tt)+) => {
+    return $crate::__private::failure(::core::convert::From::from(
+      $crate::__private::contextualize(
+        $crate::__private::eyre::eyre!($($arguments)+),
+      ),
+    ))
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function, holding a [`Report`].
+///
+/// This is [`fail!`], for conditions that are worth retrying. The [`Report`]
+/// is converted with [`From`], so the function must return an
+/// `Outcome<_, Report, _>`, or any mistake type that a [`Report`] converts
+/// into. The registered mistake hook is invoked, as with
+/// [`Outcome::mistake_hooked`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::report::{retry, Report};
+///
+/// fn acquire(locked: bool) -> Outcome<(), Report, Report> {
+///   if locked {
+///     retry!("resource is locked");
+///   }
+///   Success(())
+/// }
+///
+/// assert!(acquire(false).is_success());
+/// assert_eq!(acquire(true).unwrap_mistake().to_string(), "resource is locked");
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`fail!`]: crate::report::fail
+/// [`From`]: core::convert::From
+/// [`Outcome::mistake_hooked`]: crate::prelude::Outcome::mistake_hooked
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __report_retry {
+  ($($arguments:tt)+) => {
+    return $crate::__private::mistake(::core::convert::From::from(
+      $crate::__private::contextualize(
+        $crate::__private::eyre::eyre!($($arguments)+),
+      ),
+    ))
+  };
+}
+
+/// Returns a [`Failure`] from the enclosing function, holding a [`Report`], if
+/// a condition does not hold.
+///
+/// This is the `outcome` analogue of [`eyre::ensure!`]. Without a message,
+/// the [`Report`] describes the condition that failed.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::report::{ensure, Report};
+///
+/// fn resize(width: u32, height: u32) -> Outcome<u32, (), Report> {
+///   ensure!(width > 0);
+///   ensure!(height <= 4096, "height {height} is too large");
+///   Success(width * height)
+/// }
+///
+/// assert_eq!(resize(2, 3).unwrap(), 6);
+/// let failure = resize(0, 3).unwrap_failure();
+/// assert_eq!(failure.to_string(), "Condition failed: `width > 0`");
+/// let failure = resize(2, 8192).unwrap_failure();
+/// assert_eq!(failure.to_string(), "height 8192 is too large");
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+/// [`eyre::ensure!`]: ::eyre::ensure
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __report_ensure {
+  ($condition:expr $(,)?) => {
+    if !$condition {
+      $crate::__report_fail!(::core::concat!(
+        "Condition failed: `",
+        ::core::stringify!($condition),
+        "`",
+      ));
+    }
+  };
+  ($condition:expr, $($arguments:tt)+) => {
+    if !$condition {
+      $crate::__report_fail!($($arguments)+);
+    }
+  };
+}
+
+/// Returns a [`Mistake`] from the enclosing function, holding a [`Report`], if
+/// a condition does not hold.
+///
+/// This is [`ensure!`], for conditions that are worth retrying.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::report::{ensure_or_mistake, Report};
+///
+/// fn poll(ready: bool) -> Outcome<(), Report, ()> {
+///   ensure_or_mistake!(ready, "device is not ready");
+///   Success(())
+/// }
+///
+/// assert!(poll(true).is_success());
+/// assert_eq!(poll(false).unwrap_mistake().to_string(), "device is not ready");
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`ensure!`]: crate::report::ensure
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __report_ensure_or_mistake {
+  ($condition:expr $(,)?) => {
+    if !$condition {
+      $crate::__report_retry!(::core::concat!(
+        "Condition failed: `",
+        ::core::stringify!($condition),
+        "`",
+      ));
+    }
+  };
+  ($condition:expr, $($arguments:tt)+) => {
+    if !$condition {
+      $crate::__report_retry!($($arguments)+);
+    }
+  };
+}
+
+#[doc(inline)]
+pub use crate::{
+  __report_ensure as ensure, __report_ensure_or_mistake as ensure_or_mistake,
+  __report_fail as fail, __report_retry as retry,
+};
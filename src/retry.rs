@@ -0,0 +1,717 @@
+//! Retrying operations that produce a [`Mistake`].
+//!
+//! A [`Mistake`] is a state that is worth retrying, while a [`Failure`] is
+//! not. This module turns that convention into a small retry loop: an
+//! operation is invoked until it produces a [`Success`] or a [`Failure`], or
+//! until a [`RetryPolicy`] runs out of attempts. Whatever stopped the loop is
+//! returned as a [`Result`], with the last [`Mistake`] or [`Failure`] wrapped
+//! in an [`Aberration`].
+//!
+//! The core of this module does not require `std`. A [`RetryPolicy`] only
+//! describes how long to wait between attempts, via the [`Backoff`] iterator,
+//! and [`retry_with`] leaves the waiting itself to a [`Sleep`]
+//! implementation. When the `std` feature is enabled, [`retry`] waits by
+//! blocking the current thread.
+//!
+//! When the `futures` feature is enabled, [`retry_async`] retries an
+//! asynchronous operation instead, awaiting a [`Sleeper`] between attempts.
+//!
+//! A [`Mistake`] type may also implement [`Retryable`] to describe when and
+//! how often it should be retried, such as a `Retry-After` header or a rate
+//! limit's remaining quota. [`Outcome::retry_hint`] exposes this information,
+//! and [`retry_with_hints`] takes it into account.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use core::time::Duration;
+//! use outcome::retry::{retry_with, RetryPolicy};
+//!
+//! let policy = RetryPolicy::exponential(5, Duration::from_millis(10));
+//! let mut waited = Vec::new();
+//! let mut attempts = 0;
+//!
+//! let result = retry_with(&policy, |d| waited.push(d), || {
+//!   attempts += 1;
+//!   match attempts {
+//!     1..=2 => Mistake::<u32, _, ()>("busy"),
+//!     _ => Success(attempts),
+//!   }
+//! });
+//!
+//! assert_eq!(result, Ok(3));
+//! assert_eq!(waited, [Duration::from_millis(10), Duration::from_millis(20)]);
+//! ```
+//!
+//! [`Success`]: crate::prelude::Success
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+//! [`Aberration`]: crate::prelude::Aberration
+//! [`Sleeper`]: crate::future::Sleeper
+//! [`Outcome::retry_hint`]: crate::prelude::Outcome::retry_hint
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::time::Duration;
+
+use crate::prelude::*;
+
+/// Describes how many times an operation is attempted, and how long to wait
+/// between attempts.
+///
+/// The delay before the first retry is the initial delay. Each following
+/// delay is the previous one multiplied by a factor, and capped at a maximum
+/// delay. A [fixed](RetryPolicy::fixed) policy uses a factor of 1, while an
+/// [exponential](RetryPolicy::exponential) policy uses a factor of 2.
+///
+/// When jitter is enabled, each delay is randomly shortened by up to half, so
+/// that many clients retrying at once do not do so in lockstep.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use outcome::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::exponential(4, Duration::from_millis(100))
+///   .with_max_delay(Duration::from_millis(300));
+/// let delays: Vec<_> = policy.backoff().collect();
+/// assert_eq!(
+///   delays,
+///   [
+///     Duration::from_millis(100),
+///     Duration::from_millis(200),
+///     Duration::from_millis(300),
+///   ]
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct RetryPolicy {
+  attempts: u32,
+  delay: Duration,
+  factor: u32,
+  max_delay: Duration,
+  jitter: bool,
+}
+
+impl RetryPolicy {
+  /// Creates a policy that makes at most `attempts` attempts, waiting `delay`
+  /// between each of them.
+  ///
+  /// An operation is always attempted at least once, even if `attempts` is
+  /// zero.
+  #[must_use]
+  #[inline]
+  pub const fn fixed(attempts: u32, delay: Duration) -> Self {
+    Self {
+      attempts,
+      delay,
+      factor: 1,
+      max_delay: Duration::MAX,
+      jitter: false,
+    }
+  }
+
+  /// Creates a policy that makes at most `attempts` attempts, waiting
+  /// `initial` before the first retry, and doubling the delay for every retry
+  /// after that.
+  ///
+  /// An operation is always attempted at least once, even if `attempts` is
+  /// zero.
+  #[must_use]
+  #[inline]
+  pub const fn exponential(attempts: u32, initial: Duration) -> Self {
+    Self {
+      attempts,
+      delay: initial,
+      factor: 2,
+      max_delay: Duration::MAX,
+      jitter: false,
+    }
+  }
+
+  /// Caps every delay at `max_delay`.
+  #[must_use]
+  #[inline]
+  pub const fn with_max_delay(self, max_delay: Duration) -> Self {
+    Self { max_delay, ..self }
+  }
+
+  /// Randomly shortens every delay by up to half.
+  #[must_use]
+  #[inline]
+  pub const fn with_jitter(self) -> Self {
+    Self {
+      jitter: true,
+      ..self
+    }
+  }
+
+  /// Returns the maximum number of attempts, including the first.
+  #[must_use]
+  #[inline]
+  pub const fn max_attempts(&self) -> u32 {
+    if self.attempts == 0 {
+      return 1;
+    }
+    self.attempts
+  }
+
+  /// Returns whether delays are randomly shortened.
+  #[must_use]
+  #[inline]
+  pub const fn has_jitter(&self) -> bool {
+    self.jitter
+  }
+
+  /// Returns the delay before the `retry`th retry, starting at zero, without
+  /// any jitter applied.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use core::time::Duration;
+  /// use outcome::retry::RetryPolicy;
+  ///
+  /// let policy = RetryPolicy::exponential(3, Duration::from_secs(1));
+  /// assert_eq!(policy.delay(0), Duration::from_secs(1));
+  /// assert_eq!(policy.delay(2), Duration::from_secs(4));
+  /// ```
+  #[must_use]
+  pub fn delay(&self, retry: u32) -> Duration {
+    self
+      .factor
+      .checked_pow(retry)
+      .and_then(|factor| self.delay.checked_mul(factor))
+      .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+  }
+
+  /// Returns an iterator over the delays between every attempt.
+  ///
+  /// The iterator yields one fewer delay than [`max_attempts`], as there is
+  /// no delay before the first attempt.
+  ///
+  /// [`max_attempts`]: RetryPolicy::max_attempts
+  #[must_use]
+  #[inline]
+  pub fn backoff(&self) -> Backoff {
+    Backoff {
+      policy: *self,
+      retry: 0,
+      state: seed(),
+    }
+  }
+}
+
+impl Default for RetryPolicy {
+  /// Three attempts, with an exponential backoff starting at 100 milliseconds.
+  #[inline]
+  fn default() -> Self {
+    Self::exponential(3, Duration::from_millis(100))
+  }
+}
+
+/// An iterator over the delays between the attempts of a [`RetryPolicy`].
+///
+/// This is created by [`RetryPolicy::backoff`].
+#[derive(Clone, Debug)]
+pub struct Backoff {
+  policy: RetryPolicy,
+  retry: u32,
+  state: u64,
+}
+
+impl Backoff {
+  /* splitmix64, which is more than enough to decorrelate clients. */
+  fn random(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut value = self.state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+  }
+}
+
+impl Iterator for Backoff {
+  type Item = Duration;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.retry + 1 >= self.policy.max_attempts() {
+      return None;
+    }
+    let delay = self.policy.delay(self.retry);
+    self.retry += 1;
+    if !self.policy.jitter {
+      return Some(delay);
+    }
+    let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+    let half = nanos / 2;
+    let jitter = self.random() % (half + 1);
+    Some(Duration::from_nanos(nanos - half + jitter))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = self.policy.max_attempts() - 1 - self.retry;
+    let remaining = usize::try_from(remaining).unwrap_or(usize::MAX);
+    (remaining, Some(remaining))
+  }
+}
+
+impl ExactSizeIterator for Backoff {}
+
+/// A [`Mistake`] that knows when, and how many more times, it may be retried.
+///
+/// Both methods default to returning `None`, meaning that the [`RetryPolicy`]
+/// alone decides.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::time::Duration;
+/// use outcome::retry::Retryable;
+///
+/// struct RateLimited {
+///   reset: Duration,
+///   remaining: u32,
+/// }
+///
+/// impl Retryable for RateLimited {
+///   fn retry_after(&self) -> Option<Duration> {
+///     Some(self.reset)
+///   }
+///
+///   fn attempts_remaining(&self) -> Option<u32> {
+///     Some(self.remaining)
+///   }
+/// }
+///
+/// let limited = RateLimited { reset: Duration::from_secs(30), remaining: 2 };
+/// let x: Outcome<(), RateLimited, ()> = Mistake(limited);
+/// let hint = x.retry_hint().unwrap();
+/// assert_eq!(hint.retry_after, Some(Duration::from_secs(30)));
+/// assert_eq!(hint.attempts_remaining, Some(2));
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+pub trait Retryable {
+  /// Returns the minimum time to wait before retrying, if known.
+  #[inline]
+  fn retry_after(&self) -> Option<Duration> {
+    None
+  }
+
+  /// Returns how many more attempts may be made, if known.
+  #[inline]
+  fn attempts_remaining(&self) -> Option<u32> {
+    None
+  }
+}
+
+impl<T: Retryable + ?Sized> Retryable for &T {
+  #[inline]
+  fn retry_after(&self) -> Option<Duration> {
+    (**self).retry_after()
+  }
+
+  #[inline]
+  fn attempts_remaining(&self) -> Option<u32> {
+    (**self).attempts_remaining()
+  }
+}
+
+/// The information provided by a [`Retryable`] mistake.
+///
+/// This is created by [`Outcome::retry_hint`].
+///
+/// [`Outcome::retry_hint`]: crate::prelude::Outcome::retry_hint
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct RetryHint {
+  /// The minimum time to wait before retrying, if known.
+  pub retry_after: Option<Duration>,
+  /// How many more attempts may be made, if known.
+  pub attempts_remaining: Option<u32>,
+}
+
+impl RetryHint {
+  /// Returns `delay`, extended to [`retry_after`] if that is longer.
+  ///
+  /// [`retry_after`]: RetryHint::retry_after
+  #[must_use]
+  #[inline]
+  pub fn delay(&self, delay: Duration) -> Duration {
+    self.retry_after.map_or(delay, |after| delay.max(after))
+  }
+
+  /// Returns `true` if the mistake has indicated it may not be retried.
+  #[must_use]
+  #[inline]
+  pub const fn is_exhausted(&self) -> bool {
+    matches!(self.attempts_remaining, Some(0))
+  }
+}
+
+impl<S, M: Retryable, F> Outcome<S, M, F> {
+  /// Returns the [`RetryHint`] of a [`Mistake`], or `None` for any other
+  /// variant.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::retry::Retryable;
+  ///
+  /// struct Busy;
+  /// impl Retryable for Busy {}
+  ///
+  /// let x: Outcome<(), Busy, ()> = Mistake(Busy);
+  /// assert_eq!(x.retry_hint(), Some(Default::default()));
+  ///
+  /// let x: Outcome<(), Busy, ()> = Failure(());
+  /// assert_eq!(x.retry_hint(), None);
+  /// ```
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub fn retry_hint(&self) -> Option<RetryHint> {
+    match self {
+      Mistake(value) => Some(RetryHint {
+        retry_after: value.retry_after(),
+        attempts_remaining: value.attempts_remaining(),
+      }),
+      _ => None,
+    }
+  }
+}
+
+/// Blocks for a given [`Duration`] between attempts of [`retry_with`].
+///
+/// This trait is implemented for any closure taking a [`Duration`], which
+/// allows waiting to be replaced entirely in tests, or implemented with a
+/// platform specific delay on embedded targets.
+pub trait Sleep {
+  /// Blocks for `duration`.
+  fn sleep(&mut self, duration: Duration);
+}
+
+impl<C: FnMut(Duration)> Sleep for C {
+  #[inline]
+  fn sleep(&mut self, duration: Duration) {
+    self(duration);
+  }
+}
+
+/// A [`Sleep`] that blocks the current thread with [`std::thread::sleep`].
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct ThreadSleep;
+
+#[cfg(feature = "std")]
+impl Sleep for ThreadSleep {
+  #[inline]
+  fn sleep(&mut self, duration: Duration) {
+    std::thread::sleep(duration);
+  }
+}
+
+/// Invokes `callable` until it returns a [`Success`] or [`Failure`], or until
+/// `policy` runs out of attempts, waiting with `sleep` after every
+/// [`Mistake`].
+///
+/// A [`Failure`] is returned immediately, without being retried. If every
+/// attempt produced a [`Mistake`], the last one is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::time::Duration;
+/// use outcome::retry::{retry_with, RetryPolicy};
+///
+/// let policy = RetryPolicy::fixed(3, Duration::from_secs(1));
+/// let mut attempts = 0;
+/// let result = retry_with(&policy, |_| {}, || {
+///   attempts += 1;
+///   Mistake::<(), _, ()>(attempts)
+/// });
+/// assert_eq!(result, Err(Aberration::Mistake(3)));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+pub fn retry_with<S, M, F, C, W>(
+  policy: &RetryPolicy,
+  mut sleep: W,
+  mut callable: C,
+) -> Result<S, Aberration<M, F>>
+where
+  C: FnMut() -> Outcome<S, M, F>,
+  W: Sleep,
+{
+  let mut backoff = policy.backoff();
+  loop {
+    match callable() {
+      Success(value) => return Ok(value),
+      Failure(value) => return Err(Aberration::Failure(value)),
+      Mistake(value) => match backoff.next() {
+        Some(delay) => sleep.sleep(delay),
+        None => return Err(Aberration::Mistake(value)),
+      },
+    }
+  }
+}
+
+/// Invokes `callable` until it returns a [`Success`] or [`Failure`], or until
+/// either `policy` or the [`Mistake`] itself runs out of attempts, waiting
+/// with `sleep` after every [`Mistake`].
+///
+/// This is [`retry_with`], where the [`RetryHint`] of every [`Mistake`] is
+/// taken into account. A delay is never shorter than the mistake's
+/// [`retry_after`], and a mistake with no [`attempts_remaining`] is returned
+/// immediately.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::time::Duration;
+/// use outcome::retry::{retry_with_hints, RetryPolicy, Retryable};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Throttled(u64);
+///
+/// impl Retryable for Throttled {
+///   fn retry_after(&self) -> Option<Duration> {
+///     Some(Duration::from_secs(self.0))
+///   }
+/// }
+///
+/// let policy = RetryPolicy::fixed(3, Duration::from_secs(1));
+/// let mut waited = Vec::new();
+/// let mut attempts = 0;
+/// let result = retry_with_hints(&policy, |d| waited.push(d), || {
+///   attempts += 1;
+///   match attempts {
+///     1 => Mistake::<_, _, ()>(Throttled(5)),
+///     _ => Success(attempts),
+///   }
+/// });
+/// assert_eq!(result, Ok(2));
+/// assert_eq!(waited, [Duration::from_secs(5)]);
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`retry_after`]: Retryable::retry_after
+/// [`attempts_remaining`]: Retryable::attempts_remaining
+pub fn retry_with_hints<S, M, F, C, W>(
+  policy: &RetryPolicy,
+  mut sleep: W,
+  mut callable: C,
+) -> Result<S, Aberration<M, F>>
+where
+  M: Retryable,
+  C: FnMut() -> Outcome<S, M, F>,
+  W: Sleep,
+{
+  let mut backoff = policy.backoff();
+  loop {
+    let outcome = callable();
+    let hint = outcome.retry_hint().unwrap_or_default();
+    match outcome {
+      Success(value) => return Ok(value),
+      Failure(value) => return Err(Aberration::Failure(value)),
+      Mistake(value) => match backoff.next() {
+        Some(delay) if !hint.is_exhausted() => sleep.sleep(hint.delay(delay)),
+        _ => return Err(Aberration::Mistake(value)),
+      },
+    }
+  }
+}
+
+/// Invokes `callable` until it returns a [`Success`] or [`Failure`], or until
+/// `policy` runs out of attempts, blocking the current thread after every
+/// [`Mistake`].
+///
+/// This is [`retry_with`] using a [`ThreadSleep`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::time::Duration;
+/// use outcome::retry::{retry, RetryPolicy};
+///
+/// let policy = RetryPolicy::exponential(5, Duration::from_millis(1)).with_jitter();
+/// let mut attempts = 0;
+/// let result = retry(&policy, || {
+///   attempts += 1;
+///   match attempts {
+///     1 => Mistake("busy"),
+///     2 => Failure("connection reset"),
+///     _ => Success(()),
+///   }
+/// });
+/// assert_eq!(result, Err(Aberration::Failure("connection reset")));
+/// assert_eq!(attempts, 2);
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+#[inline]
+pub fn retry<S, M, F, C>(
+  policy: &RetryPolicy,
+  callable: C,
+) -> Result<S, Aberration<M, F>>
+where
+  C: FnMut() -> Outcome<S, M, F>,
+{
+  retry_with(policy, ThreadSleep, callable)
+}
+
+/// Awaits the future returned by `callable` until it resolves to a
+/// [`Success`] or [`Failure`], or until `policy` runs out of attempts,
+/// awaiting `sleeper` after every [`Mistake`].
+///
+/// This is the asynchronous equivalent of [`retry_with`]. As waiting is left
+/// to a [`Sleeper`], any runtime's timer can be used, such as
+/// `|d| tokio::time::sleep(d)`.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use core::future::ready;
+/// use core::time::Duration;
+/// use futures::executor::block_on;
+/// use outcome::retry::{retry_async, RetryPolicy};
+///
+/// let policy = RetryPolicy::fixed(5, Duration::from_millis(10));
+/// let mut attempts = 0;
+/// let result = block_on(retry_async(&policy, |_| ready(()), || {
+///   attempts += 1;
+///   ready(match attempts {
+///     1..=3 => Mistake::<u32, _, ()>("would block"),
+///     _ => Success(attempts),
+///   })
+/// }));
+/// assert_eq!(result, Ok(4));
+/// ```
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+/// [`Sleeper`]: crate::future::Sleeper
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "futures")))]
+#[cfg(feature = "futures")]
+pub async fn retry_async<S, M, F, C, Fut, W>(
+  policy: &RetryPolicy,
+  sleeper: W,
+  mut callable: C,
+) -> Result<S, Aberration<M, F>>
+where
+  C: FnMut() -> Fut,
+  Fut: core::future::Future<Output = Outcome<S, M, F>>,
+  W: crate::future::Sleeper,
+{
+  let mut backoff = policy.backoff();
+  loop {
+    match callable().await {
+      Success(value) => return Ok(value),
+      Failure(value) => return Err(Aberration::Failure(value)),
+      Mistake(value) => match backoff.next() {
+        Some(delay) => sleeper.sleep(delay).await,
+        None => return Err(Aberration::Mistake(value)),
+      },
+    }
+  }
+}
+
+/* Without `std` there is no source of entropy, so every `Backoff` starts from
+ * the same state. Jitter still spreads the delays of a single client, but
+ * clients will only be decorrelated when `std` is enabled.
+ */
+#[cfg(feature = "std")]
+fn seed() -> u64 {
+  use std::hash::{BuildHasher, Hasher};
+  std::collections::hash_map::RandomState::new()
+    .build_hasher()
+    .finish()
+}
+
+#[cfg(not(feature = "std"))]
+const fn seed() -> u64 {
+  0x853C_49E6_748F_EA9B
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn delay_saturates() {
+    let policy = RetryPolicy::exponential(u32::MAX, Duration::from_secs(1))
+      .with_max_delay(Duration::from_secs(60));
+    assert_eq!(policy.delay(5), Duration::from_secs(32));
+    assert_eq!(policy.delay(6), Duration::from_secs(60));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_secs(60));
+  }
+
+  #[test]
+  fn jitter_is_bounded() {
+    let delay = Duration::from_millis(100);
+    let policy = RetryPolicy::fixed(64, delay).with_jitter();
+    let backoff = policy.backoff();
+    assert_eq!(backoff.len(), 63);
+    for jittered in backoff {
+      assert!(jittered >= delay / 2 && jittered <= delay);
+    }
+  }
+
+  #[test]
+  fn hints_stop_exhausted_mistakes() {
+    struct Quota(u32);
+    impl Retryable for Quota {
+      fn attempts_remaining(&self) -> Option<u32> {
+        Some(self.0)
+      }
+    }
+
+    let policy = RetryPolicy::fixed(10, Duration::ZERO);
+    let mut attempts = 0;
+    let result = retry_with_hints(
+      &policy,
+      |_| {},
+      || {
+        attempts += 1;
+        Mistake::<(), _, ()>(Quota(2 - attempts))
+      },
+    );
+    assert!(matches!(result, Err(Aberration::Mistake(Quota(0)))));
+    assert_eq!(attempts, 2);
+  }
+
+  #[test]
+  fn zero_attempts_runs_once() {
+    let policy = RetryPolicy::fixed(0, Duration::ZERO);
+    let mut attempts = 0;
+    let result = retry_with(
+      &policy,
+      |_| {},
+      || {
+        attempts += 1;
+        Mistake::<(), _, ()>(())
+      },
+    );
+    assert_eq!(result, Err(Aberration::Mistake(())));
+    assert_eq!(attempts, 1);
+  }
+}
@@ -0,0 +1,79 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The variant of an [`Outcome`], [`Concern`], or [`Aberration`], without its
+/// value.
+///
+/// Severities are ordered from [`Success`] to [`Failure`], so the worst
+/// severity of a batch of outcomes can be found with [`Iterator::max`].
+///
+/// This is returned by [`Outcome::severity`], [`Concern::severity`], and
+/// [`Aberration::severity`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::Severity;
+///
+/// let subsystems: [Outcome<(), &str, &str>; 3] =
+///   [Success(()), Mistake("disk is almost full"), Success(())];
+/// let health = subsystems.iter().map(Outcome::severity).max();
+/// assert_eq!(health, Some(Severity::Mistake));
+/// assert!(Severity::Mistake < Severity::Failure);
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Concern`]: crate::prelude::Concern
+/// [`Aberration`]: crate::prelude::Aberration
+/// [`Outcome::severity`]: crate::prelude::Outcome::severity
+/// [`Concern::severity`]: crate::prelude::Concern::severity
+/// [`Aberration::severity`]: crate::prelude::Aberration::severity
+/// [`Success`]: Severity::Success
+/// [`Failure`]: Severity::Failure
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "lowercase")
+)]
+pub enum Severity {
+  /// The value is a [`Success`](crate::prelude::Success).
+  Success,
+  /// The value is a [`Mistake`](crate::prelude::Mistake).
+  Mistake,
+  /// The value is a [`Failure`](crate::prelude::Failure).
+  Failure,
+}
+
+impl Severity {
+  /// Returns `true` if the severity is [`Severity::Success`].
+  #[must_use]
+  #[inline]
+  pub const fn is_success(self) -> bool {
+    matches!(self, Self::Success)
+  }
+
+  /// Returns `true` if the severity is [`Severity::Mistake`].
+  #[must_use]
+  #[inline]
+  pub const fn is_mistake(self) -> bool {
+    matches!(self, Self::Mistake)
+  }
+
+  /// Returns `true` if the severity is [`Severity::Failure`].
+  #[must_use]
+  #[inline]
+  pub const fn is_failure(self) -> bool {
+    matches!(self, Self::Failure)
+  }
+}
+
+impl Display for Severity {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Success => "success",
+      Self::Mistake => "mistake",
+      Self::Failure => "failure",
+    })
+  }
+}
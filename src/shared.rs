@@ -0,0 +1,286 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::sync::Arc;
+use core::fmt::{self, Debug, Formatter};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::prelude::*;
+
+/// A cheaply cloneable, reference counted [`Outcome`].
+///
+/// Some outcomes are expensive to produce, and are needed by many consumers,
+/// such as the result of loading a configuration file or performing a
+/// handshake. `SharedOutcome` places the outcome behind an [`Arc`], so that
+/// cloning it only increments a reference count, regardless of the size of
+/// the contained value. The contained value can be accessed by reference.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::SharedOutcome;
+///
+/// let config: Outcome<Vec<u8>, (), &str> = Success(vec![0; 4096]);
+/// let shared = SharedOutcome::new(config);
+///
+/// let handles: Vec<_> = (0..4)
+///   .map(|_| {
+///     let shared = shared.clone();
+///     std::thread::spawn(move || shared.success().map(Vec::len))
+///   })
+///   .collect();
+///
+/// for handle in handles {
+///   assert_eq!(handle.join().unwrap(), Some(4096));
+/// }
+/// assert!(shared.try_unwrap().is_ok());
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Arc`]: alloc::sync::Arc
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SharedOutcome<S, M, F> {
+  inner: Arc<Outcome<S, M, F>>,
+}
+
+impl<S, M, F> SharedOutcome<S, M, F> {
+  /// Places `outcome` behind a new reference count.
+  #[inline]
+  pub fn new(outcome: Outcome<S, M, F>) -> Self {
+    Self {
+      inner: Arc::new(outcome),
+    }
+  }
+
+  /// Converts from `&SharedOutcome<S, M, F>` to `Outcome<&S, &M, &F>`.
+  #[inline]
+  pub fn as_ref(&self) -> Outcome<&S, &M, &F> {
+    self.inner.as_ref().as_ref()
+  }
+
+  /// Returns a reference to the contained [`Success`] value, if any.
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[must_use]
+  #[inline]
+  pub fn success(&self) -> Option<&S> {
+    self.as_ref().success()
+  }
+
+  /// Returns a reference to the contained [`Mistake`] value, if any.
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub fn mistake(&self) -> Option<&M> {
+    self.as_ref().mistake()
+  }
+
+  /// Returns a reference to the contained [`Failure`] value, if any.
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn failure(&self) -> Option<&F> {
+    self.as_ref().failure()
+  }
+
+  /// Returns `true` if the outcome is a [`Success`].
+  ///
+  /// [`Success`]: crate::prelude::Success
+  #[must_use]
+  #[inline]
+  pub fn is_success(&self) -> bool {
+    self.inner.is_success()
+  }
+
+  /// Returns `true` if the outcome is a [`Mistake`].
+  ///
+  /// [`Mistake`]: crate::prelude::Mistake
+  #[must_use]
+  #[inline]
+  pub fn is_mistake(&self) -> bool {
+    self.inner.is_mistake()
+  }
+
+  /// Returns `true` if the outcome is a [`Failure`].
+  ///
+  /// [`Failure`]: crate::prelude::Failure
+  #[must_use]
+  #[inline]
+  pub fn is_failure(&self) -> bool {
+    self.inner.is_failure()
+  }
+
+  /// Returns `true` if both values share the same allocation.
+  #[must_use]
+  #[inline]
+  pub fn ptr_eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.inner, &other.inner)
+  }
+
+  /// Returns the contained outcome if this is the only reference to it.
+  ///
+  /// # Errors
+  ///
+  /// Returns `self` if other references to the outcome still exist.
+  #[inline]
+  pub fn try_unwrap(self) -> Result<Outcome<S, M, F>, Self> {
+    Arc::try_unwrap(self.inner).map_err(|inner| Self { inner })
+  }
+}
+
+impl<S: Clone, M: Clone, F: Clone> SharedOutcome<S, M, F> {
+  /// Returns the contained outcome, cloning it if other references to it
+  /// still exist.
+  #[inline]
+  pub fn unwrap_or_clone(self) -> Outcome<S, M, F> {
+    Arc::try_unwrap(self.inner).unwrap_or_else(|inner| (*inner).clone())
+  }
+}
+
+impl<S, M, F> Clone for SharedOutcome<S, M, F> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self {
+      inner: Arc::clone(&self.inner),
+    }
+  }
+}
+
+impl<S, M, F> From<Outcome<S, M, F>> for SharedOutcome<S, M, F> {
+  #[inline]
+  fn from(outcome: Outcome<S, M, F>) -> Self {
+    Self::new(outcome)
+  }
+}
+
+impl<S: Debug, M: Debug, F: Debug> Debug for SharedOutcome<S, M, F> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("SharedOutcome").field(&self.inner).finish()
+  }
+}
+
+/// A thread-safe cell that is set at most once with a [`SharedOutcome`].
+///
+/// This allows a [`SharedOutcome`] to be placed in a `static`, or handed to
+/// consumers before the outcome itself is available.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::SharedOutcomeLock;
+///
+/// static HANDSHAKE: SharedOutcomeLock<u32, (), &str> = SharedOutcomeLock::new();
+///
+/// assert!(HANDSHAKE.get().is_none());
+/// let first = HANDSHAKE.get_or_init(|| Success(47));
+/// let second = HANDSHAKE.get_or_init(|| Failure("never called"));
+/// assert!(first.ptr_eq(&second));
+/// assert_eq!(second.success(), Some(&47));
+/// ```
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub struct SharedOutcomeLock<S, M, F> {
+  inner: OnceLock<SharedOutcome<S, M, F>>,
+}
+
+#[cfg(feature = "std")]
+impl<S, M, F> SharedOutcomeLock<S, M, F> {
+  /// Creates a new, uninitialized cell.
+  #[must_use]
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      inner: OnceLock::new(),
+    }
+  }
+
+  /// Returns a new reference to the stored outcome, if any.
+  ///
+  /// This does not block.
+  #[must_use]
+  #[inline]
+  pub fn get(&self) -> Option<SharedOutcome<S, M, F>> {
+    self.inner.get().cloned()
+  }
+
+  /// Stores `outcome`, returning a new reference to it.
+  ///
+  /// # Errors
+  ///
+  /// Returns `outcome` if the cell has already been initialized.
+  pub fn set(
+    &self,
+    outcome: Outcome<S, M, F>,
+  ) -> Result<SharedOutcome<S, M, F>, Outcome<S, M, F>> {
+    let mut outcome = Some(outcome);
+    let shared = self.inner.get_or_init(|| match outcome.take() {
+      Some(outcome) => SharedOutcome::new(outcome),
+      None => unreachable!(),
+    });
+    match outcome {
+      Some(outcome) => Err(outcome),
+      None => Ok(shared.clone()),
+    }
+  }
+
+  /// Returns a new reference to the stored outcome, initializing the cell
+  /// with `initializer` if it has not been initialized yet.
+  ///
+  /// Unlike [`OnceOutcomeLock`], every variant returned by `initializer` is
+  /// stored.
+  ///
+  /// [`OnceOutcomeLock`]: crate::cell::OnceOutcomeLock
+  pub fn get_or_init<C>(&self, initializer: C) -> SharedOutcome<S, M, F>
+  where
+    C: FnOnce() -> Outcome<S, M, F>,
+  {
+    self
+      .inner
+      .get_or_init(|| SharedOutcome::new(initializer()))
+      .clone()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S, M, F> Default for SharedOutcomeLock<S, M, F> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<S: Debug, M: Debug, F: Debug> Debug for SharedOutcomeLock<S, M, F> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("SharedOutcomeLock")
+      .field(&self.inner.get())
+      .finish()
+  }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn try_unwrap_shared() {
+    let x = SharedOutcome::<(), _, ()>::new(Mistake(1));
+    let y = x.clone();
+    let x = x.try_unwrap().unwrap_err();
+    assert_eq!(y.unwrap_or_clone(), Mistake(1));
+    assert_eq!(x.try_unwrap(), Ok(Mistake(1)));
+  }
+
+  #[test]
+  fn set_once() {
+    let lock = SharedOutcomeLock::<(), (), _>::new();
+    assert!(lock.set(Failure(1)).is_ok());
+    assert_eq!(lock.set(Failure(2)), Err(Failure(2)));
+    assert_eq!(lock.get().unwrap().failure(), Some(&1));
+  }
+}
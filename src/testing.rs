@@ -0,0 +1,633 @@
+//! Utilities for writing tests against [`Outcome`].
+//!
+//! This module provides the [`OutcomeAssertions`] trait, a method-call
+//! alternative to matching on an [`Outcome`] in tests. Each assertion returns
+//! the contained value, so further checks can be chained onto it, and panics
+//! with a message describing the variant that was actually found.
+//!
+//! The [`should_mistake`] and [`should_fail`] attributes declare that a test
+//! function returning an [`Outcome`] is expected to produce a [`Mistake`] or
+//! [`Failure`], optionally matching its value against a pattern or predicate.
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::testing::{should_fail, should_mistake};
+//!
+//! #[derive(Debug)]
+//! enum Error {
+//!   Busy { attempts: u32 },
+//!   NotFound,
+//! }
+//!
+//! #[should_mistake(matches = Error::Busy { attempts: 1.. })]
+//! fn busy_is_retryable() -> Outcome<(), Error, Error> {
+//!   Mistake(Error::Busy { attempts: 3 })
+//! }
+//!
+//! #[should_fail(with = |error: &Error| matches!(error, Error::NotFound))]
+//! fn missing_is_fatal() -> Outcome<(), Error, Error> {
+//!   Failure(Error::NotFound)
+//! }
+//!
+//! # busy_is_retryable();
+//! # missing_is_fatal();
+//! ```
+//!
+//! In a test suite, these attributes are placed alongside `#[test]`.
+//!
+//! The [`assert_success!`], [`assert_mistake!`], and [`assert_failure!`]
+//! macros are the macro forms of [`OutcomeAssertions`], and additionally
+//! accept a custom message.
+//!
+//! When the `alloc` feature is enabled, the [`assert_outcome_eq!`] macro is
+//! also available. It compares two outcomes and, on mismatch, prints a diff of
+//! their payloads that remains readable for large values.
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`assert_outcome_eq!`]: crate::assert_outcome_eq
+//! [`assert_success!`]: crate::assert_success
+//! [`assert_mistake!`]: crate::assert_mistake
+//! [`assert_failure!`]: crate::assert_failure
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt::Debug;
+
+use crate::prelude::*;
+
+/// Expects a test function to return a [`Mistake`].
+///
+/// Without arguments, any [`Mistake`] passes the test. The contained value
+/// can additionally be checked with either `matches = <pattern>` or
+/// `with = <predicate>`, where the predicate is called with a reference to
+/// the value.
+///
+/// ```should_panic
+/// # use outcome::prelude::*;
+/// use outcome::testing::should_mistake;
+///
+/// #[should_mistake(matches = 1..=3)]
+/// fn attempts() -> Outcome<(), u32, ()> {
+///   Mistake(4)
+/// }
+///
+/// attempts(); // panics, as `Mistake(4)` does not match `1..=3`
+/// ```
+///
+/// [`Mistake`]: crate::prelude::Mistake
+#[doc(inline)]
+pub use outcome_macros::should_mistake;
+
+/// Expects a test function to return a [`Failure`].
+///
+/// This accepts the same arguments as [`should_mistake`].
+///
+/// ```should_panic
+/// # use outcome::prelude::*;
+/// use outcome::testing::should_fail;
+///
+/// #[should_fail]
+/// fn fails() -> Outcome<(), (), ()> {
+///   Success(())
+/// }
+///
+/// fails(); // panics with "expected `Failure`, found `Success(())`"
+/// ```
+///
+/// [`Failure`]: crate::prelude::Failure
+#[doc(inline)]
+pub use outcome_macros::should_fail;
+
+#[inline(never)]
+#[track_caller]
+#[cold]
+fn mismatch(expected: &str, found: &str, value: &dyn Debug) -> ! {
+  panic!("assertion failed: expected `{expected}`, found `{found}({value:?})`");
+}
+
+/// Fluent assertions for [`Outcome`].
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// [`Outcome`]: crate::prelude::Outcome
+pub trait OutcomeAssertions: crate::private::Sealed {
+  /// The [`Success`] type
+  type Success;
+  /// The [`Mistake`] type
+  type Mistake;
+  /// The [`Failure`] type
+  type Failure;
+
+  /// Asserts that the outcome is a [`Success`], returning the contained
+  /// value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the outcome is a [`Mistake`] or [`Failure`], with a message
+  /// containing the actual variant and its value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<&str, u32, u32> = Success("hello");
+  /// assert_eq!(x.assert_success().len(), 5);
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<&str, u32, u32> = Mistake(47);
+  /// x.assert_success(); // panics with "expected `Success`, found `Mistake(47)`"
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn assert_success(self) -> Self::Success;
+
+  /// Asserts that the outcome is a [`Mistake`], returning the contained
+  /// value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the outcome is a [`Success`] or [`Failure`], with a message
+  /// containing the actual variant and its value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<(), &str, u32> = Mistake("try again");
+  /// assert_eq!(x.assert_mistake(), "try again");
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn assert_mistake(self) -> Self::Mistake;
+
+  /// Asserts that the outcome is a [`Failure`], returning the contained
+  /// value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the outcome is a [`Success`] or [`Mistake`], with a message
+  /// containing the actual variant and its value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<(), u32, &str> = Failure("emergency!");
+  /// assert!(x.assert_failure().starts_with("emergency"));
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn assert_failure(self) -> Self::Failure;
+
+  /// Asserts that the outcome is a [`Success`] equal to `expected`,
+  /// returning the contained value.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the outcome is a [`Mistake`] or [`Failure`], or if the
+  /// contained value is not equal to `expected`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<u32, (), ()> = Success(47);
+  /// assert_eq!(x.assert_success_eq(47), 47);
+  /// ```
+  ///
+  /// ```should_panic
+  /// # use outcome::prelude::*;
+  /// use outcome::testing::OutcomeAssertions;
+  ///
+  /// let x: Outcome<u32, (), ()> = Success(42);
+  /// x.assert_success_eq(47); // panics, as 42 != 47
+  /// ```
+  ///
+  /// [`Success`]: crate::prelude::Success
+  /// [`Mistake`]: crate::prelude::Mistake
+  /// [`Failure`]: crate::prelude::Failure
+  fn assert_success_eq(self, expected: Self::Success) -> Self::Success
+  where
+    Self::Success: PartialEq;
+}
+
+impl<S: Debug, M: Debug, F: Debug> OutcomeAssertions for Outcome<S, M, F> {
+  type Success = S;
+  type Mistake = M;
+  type Failure = F;
+
+  #[track_caller]
+  fn assert_success(self) -> S {
+    match self {
+      Success(s) => s,
+      Mistake(m) => mismatch("Success", "Mistake", &m),
+      Failure(f) => mismatch("Success", "Failure", &f),
+    }
+  }
+
+  #[track_caller]
+  fn assert_mistake(self) -> M {
+    match self {
+      Success(s) => mismatch("Mistake", "Success", &s),
+      Mistake(m) => m,
+      Failure(f) => mismatch("Mistake", "Failure", &f),
+    }
+  }
+
+  #[track_caller]
+  fn assert_failure(self) -> F {
+    match self {
+      Success(s) => mismatch("Failure", "Success", &s),
+      Mistake(m) => mismatch("Failure", "Mistake", &m),
+      Failure(f) => f,
+    }
+  }
+
+  #[track_caller]
+  fn assert_success_eq(self, expected: S) -> S
+  where
+    S: PartialEq,
+  {
+    let success = self.assert_success();
+    assert_eq!(success, expected, "`Success` value did not match");
+    success
+  }
+}
+
+/// Asserts that two [`Outcome`]s are equal.
+///
+/// Unlike [`assert_eq!`], a mismatch is reported as a line by line diff of the
+/// pretty printed payloads, alongside the state of each outcome. Removed lines
+/// are shown in red and prefixed with `-`, while added lines are shown in
+/// green and prefixed with `+`. When the `std` feature is enabled, colors are
+/// disabled if the `NO_COLOR` environment variable is set.
+///
+/// A custom message may be provided after the two outcomes, using the same
+/// syntax as [`format!`](alloc::format).
+///
+/// # Panics
+///
+/// Panics if the outcomes are not equal.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::assert_outcome_eq;
+///
+/// let x: Outcome<u32, (), &str> = Success(47);
+/// assert_outcome_eq!(x, Success(47));
+/// ```
+///
+/// ```should_panic
+/// # use outcome::prelude::*;
+/// use outcome::assert_outcome_eq;
+///
+/// let x: Outcome<(), (), [u32; 3]> = Failure([1, 2, 3]);
+/// // panics with:
+/// //  state: `Failure` == `Failure`
+/// //  payload (- left, + right):
+/// //    [
+/// //        1,
+/// //  -     2,
+/// //  +     4,
+/// //        3,
+/// //    ]
+/// assert_outcome_eq!(x, Failure([1, 4, 3]), "while checking {}", "payloads");
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+#[cfg_attr(any(docsrs, nightly), doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_outcome_eq {
+  ($left:expr, $right:expr $(,)?) => {
+    match (&$left, &$right) {
+      (left, right) => {
+        if !(*left == *right) {
+          $crate::testing::outcome_mismatch(
+            left,
+            right,
+            ::core::option::Option::None,
+          );
+        }
+      }
+    }
+  };
+  ($left:expr, $right:expr, $($arg:tt)+) => {
+    match (&$left, &$right) {
+      (left, right) => {
+        if !(*left == *right) {
+          $crate::testing::outcome_mismatch(
+            left,
+            right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+          );
+        }
+      }
+    }
+  };
+}
+
+/// Asserts that an [`Outcome`] is a [`Success`], evaluating to the contained
+/// value.
+///
+/// This is the macro form of [`OutcomeAssertions::assert_success`]. A custom
+/// message may be provided after the outcome, using the same syntax as
+/// [`format_args!`].
+///
+/// # Panics
+///
+/// Panics if the outcome is a [`Mistake`] or [`Failure`], with a message
+/// containing the actual variant and its value, and the location of the
+/// assertion.
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::assert_success;
+///
+/// let x: Outcome<&str, u32, u32> = Success("hello");
+/// assert_eq!(assert_success!(x).len(), 5);
+/// ```
+///
+/// ```should_panic
+/// # use outcome::prelude::*;
+/// use outcome::assert_success;
+///
+/// let x: Outcome<&str, u32, u32> = Mistake(3);
+/// // panics with "expected `Success`, found `Mistake(3)`: after 3 attempts"
+/// assert_success!(x, "after {} attempts", 3);
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! assert_success {
+  ($outcome:expr $(,)?) => {
+    $crate::testing::OutcomeAssertions::assert_success($outcome)
+  };
+  ($outcome:expr, $($arg:tt)+) => {
+    match $outcome {
+      $crate::Outcome::Success(value) => value,
+      outcome => $crate::testing::__private::unexpected(
+        "Success",
+        outcome,
+        ::core::format_args!($($arg)+),
+      ),
+    }
+  };
+}
+
+/// Asserts that an [`Outcome`] is a [`Mistake`], evaluating to the contained
+/// value.
+///
+/// This is the macro form of [`OutcomeAssertions::assert_mistake`], and
+/// accepts a custom message as [`assert_success!`] does.
+///
+/// # Panics
+///
+/// Panics if the outcome is a [`Success`] or [`Failure`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::assert_mistake;
+///
+/// let x: Outcome<(), &str, u32> = Mistake("try again");
+/// assert_eq!(assert_mistake!(x), "try again");
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! assert_mistake {
+  ($outcome:expr $(,)?) => {
+    $crate::testing::OutcomeAssertions::assert_mistake($outcome)
+  };
+  ($outcome:expr, $($arg:tt)+) => {
+    match $outcome {
+      $crate::Outcome::Mistake(value) => value,
+      outcome => $crate::testing::__private::unexpected(
+        "Mistake",
+        outcome,
+        ::core::format_args!($($arg)+),
+      ),
+    }
+  };
+}
+
+/// Asserts that an [`Outcome`] is a [`Failure`], evaluating to the contained
+/// value.
+///
+/// This is the macro form of [`OutcomeAssertions::assert_failure`], and
+/// accepts a custom message as [`assert_success!`] does.
+///
+/// # Panics
+///
+/// Panics if the outcome is a [`Success`] or [`Mistake`].
+///
+/// # Examples
+///
+/// ```
+/// # use outcome::prelude::*;
+/// use outcome::assert_failure;
+///
+/// let x: Outcome<(), u32, &str> = Failure("emergency!");
+/// assert_eq!(assert_failure!(x, "while shutting down"), "emergency!");
+/// ```
+///
+/// [`Outcome`]: crate::prelude::Outcome
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+#[macro_export]
+macro_rules! assert_failure {
+  ($outcome:expr $(,)?) => {
+    $crate::testing::OutcomeAssertions::assert_failure($outcome)
+  };
+  ($outcome:expr, $($arg:tt)+) => {
+    match $outcome {
+      $crate::Outcome::Failure(value) => value,
+      outcome => $crate::testing::__private::unexpected(
+        "Failure",
+        outcome,
+        ::core::format_args!($($arg)+),
+      ),
+    }
+  };
+}
+
+#[cfg(feature = "alloc")]
+fn parts<S: Debug, M: Debug, F: Debug>(
+  outcome: &Outcome<S, M, F>,
+) -> (&'static str, &dyn Debug) {
+  match outcome {
+    Success(s) => ("Success", s),
+    Mistake(m) => ("Mistake", m),
+    Failure(f) => ("Failure", f),
+  }
+}
+
+/* A longest common subsequence of lines. Payloads in tests are small enough
+ * that the quadratic table is not a concern.
+ */
+#[cfg(feature = "alloc")]
+fn diff(left: &str, right: &str, colored: bool) -> alloc::string::String {
+  use alloc::{string::String, vec, vec::Vec};
+  use core::fmt::Write;
+
+  let left: Vec<_> = left.lines().collect();
+  let right: Vec<_> = right.lines().collect();
+  let width = right.len() + 1;
+  let mut table = vec![0usize; (left.len() + 1) * width];
+  for i in (0..left.len()).rev() {
+    for j in (0..right.len()).rev() {
+      table[i * width + j] = if left[i] == right[j] {
+        table[(i + 1) * width + j + 1] + 1
+      } else {
+        table[(i + 1) * width + j].max(table[i * width + j + 1])
+      };
+    }
+  }
+  let (red, green, reset) = if colored {
+    ("\x1b[31m", "\x1b[32m", "\x1b[0m")
+  } else {
+    ("", "", "")
+  };
+  let mut output = String::new();
+  let (mut i, mut j) = (0, 0);
+  while i < left.len() || j < right.len() {
+    let _ = if i < left.len() && j < right.len() && left[i] == right[j] {
+      i += 1;
+      j += 1;
+      writeln!(output, "  {}", left[i - 1])
+    } else if i < left.len()
+      && (j == right.len()
+        || table[(i + 1) * width + j] >= table[i * width + j + 1])
+    {
+      i += 1;
+      writeln!(output, "{red}- {}{reset}", left[i - 1])
+    } else {
+      j += 1;
+      writeln!(output, "{green}+ {}{reset}", right[j - 1])
+    };
+  }
+  output
+}
+
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[inline(never)]
+#[track_caller]
+#[cold]
+pub fn outcome_mismatch<S: Debug, M: Debug, F: Debug>(
+  left: &Outcome<S, M, F>,
+  right: &Outcome<S, M, F>,
+  message: Option<core::fmt::Arguments<'_>>,
+) -> ! {
+  use alloc::format;
+
+  #[cfg(feature = "std")]
+  let colored = std::env::var_os("NO_COLOR").is_none();
+  #[cfg(not(feature = "std"))]
+  let colored = true;
+
+  let (left_state, left_value) = parts(left);
+  let (right_state, right_value) = parts(right);
+  let relation = if left_state == right_state {
+    "=="
+  } else {
+    "!="
+  };
+  let payload = diff(
+    &format!("{left_value:#?}"),
+    &format!("{right_value:#?}"),
+    colored,
+  );
+  let message = message.map_or_else(Default::default, |m| format!(": {m}"));
+  panic!(
+    "assertion `left == right` failed{message}\n\
+     state: `{left_state}` {relation} `{right_state}`\n\
+     payload (- left, + right):\n{payload}"
+  );
+}
+
+#[doc(hidden)]
+pub mod __private {
+  use super::{Debug, Failure, Mistake, Outcome, OutcomeAssertions, Success};
+
+  #[inline(never)]
+  #[track_caller]
+  #[cold]
+  pub fn unexpected<S: Debug, M: Debug, F: Debug>(
+    expected: &str,
+    outcome: Outcome<S, M, F>,
+    message: core::fmt::Arguments<'_>,
+  ) -> ! {
+    let (found, value): (_, &dyn Debug) = match &outcome {
+      Success(s) => ("Success", s),
+      Mistake(m) => ("Mistake", m),
+      Failure(f) => ("Failure", f),
+    };
+    panic!(
+      "assertion failed: expected `{expected}`, found `{found}({value:?})`: \
+       {message}"
+    );
+  }
+
+  #[track_caller]
+  pub fn expect_mistake<S: Debug, M: Debug, F: Debug>(
+    outcome: Outcome<S, M, F>,
+    predicate: impl FnOnce(&M) -> bool,
+    description: &str,
+  ) {
+    let mistake = outcome.assert_mistake();
+    if !predicate(&mistake) {
+      panic!(
+        "assertion failed: `Mistake({mistake:?})` does not match `{description}`"
+      );
+    }
+  }
+
+  #[track_caller]
+  pub fn expect_failure<S: Debug, M: Debug, F: Debug>(
+    outcome: Outcome<S, M, F>,
+    predicate: impl FnOnce(&F) -> bool,
+    description: &str,
+  ) {
+    let failure = outcome.assert_failure();
+    if !predicate(&failure) {
+      panic!(
+        "assertion failed: `Failure({failure:?})` does not match `{description}`"
+      );
+    }
+  }
+}
@@ -0,0 +1,189 @@
+//! Running fallible tasks on scoped threads.
+//!
+//! [`scope_outcomes`] wraps [`std::thread::scope`], spawning closures that
+//! return an [`Outcome`] and joining all of them once the scope ends. A thread
+//! that panics is not propagated as a panic, but is instead converted into a
+//! [`Failure`] through the [`Panicked`] type. The results of every thread are
+//! then combined into a single [`Outcome`], as with
+//! [`Outcomes::into_outcome`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::thread::{scope_outcomes, Panicked};
+//!
+//! let inputs = ["1", "2", "x"];
+//! let outcome: Outcome<_, _, Vec<Panicked>> = scope_outcomes(|s| {
+//!   for input in &inputs {
+//!     s.spawn(move || match input.parse::<u32>() {
+//!       Ok(value) => Success(value),
+//!       Err(_) => Mistake(*input),
+//!     });
+//!   }
+//! });
+//! assert_eq!(outcome, Mistake(vec!["x"]));
+//! ```
+//!
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Failure`]: crate::prelude::Failure
+//! [`Outcomes::into_outcome`]: crate::Outcomes::into_outcome
+extern crate std;
+
+use core::{
+  any::Any,
+  cell::RefCell,
+  fmt::{self, Display, Formatter},
+};
+use std::{
+  boxed::Box,
+  string::String,
+  thread::{Scope, ScopedJoinHandle},
+  vec::Vec,
+};
+
+use crate::{prelude::*, Outcomes};
+
+/// The failure produced when a thread spawned by [`scope_outcomes`] panics.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Panicked {
+  message: Option<String>,
+}
+
+impl Panicked {
+  fn new(payload: &(dyn Any + Send)) -> Self {
+    let message = payload
+      .downcast_ref::<&str>()
+      .map(|message| String::from(*message))
+      .or_else(|| payload.downcast_ref::<String>().cloned());
+    #[cfg(feature = "panic-payload")]
+    let message = message.or_else(|| {
+      payload
+        .downcast_ref::<crate::panic::UnwrapPanic>()
+        .map(std::string::ToString::to_string)
+    });
+    Self { message }
+  }
+
+  /// Returns the panic message, if the payload was a string or, with the
+  /// `panic-payload` feature, an `UnwrapPanic`.
+  #[must_use]
+  #[inline]
+  pub fn message(&self) -> Option<&str> {
+    self.message.as_deref()
+  }
+}
+
+impl Display for Panicked {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match &self.message {
+      Some(message) => write!(f, "thread panicked: {message}"),
+      None => f.write_str("thread panicked"),
+    }
+  }
+}
+
+impl std::error::Error for Panicked {}
+
+/// Spawns threads within a [`scope_outcomes`] call.
+///
+/// This is a thin wrapper around [`Scope`] that records each spawned thread so
+/// it can be joined when the scope ends.
+///
+/// [`Scope`]: std::thread::Scope
+pub struct Spawner<'scope, 'env, S, M, F> {
+  scope: &'scope Scope<'scope, 'env>,
+  #[allow(clippy::type_complexity)]
+  handles: RefCell<Vec<ScopedJoinHandle<'scope, Outcome<S, M, F>>>>,
+}
+
+impl<'scope, S, M, F> Spawner<'scope, '_, S, M, F>
+where
+  S: Send + 'scope,
+  M: Send + 'scope,
+  F: Send + 'scope,
+{
+  /// Spawns a new scoped thread running `callable`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the operating system fails to create a thread, as with
+  /// [`std::thread::spawn`].
+  pub fn spawn<C>(&self, callable: C)
+  where
+    C: FnOnce() -> Outcome<S, M, F> + Send + 'scope,
+  {
+    let handle = self.scope.spawn(callable);
+    self.handles.borrow_mut().push(handle);
+  }
+}
+
+/// Runs `callable` with a [`Spawner`], then joins every spawned thread and
+/// combines their results.
+///
+/// The result is a [`Failure`] if any thread failed or panicked, otherwise a
+/// [`Mistake`] if any thread returned a mistake, and a [`Success`] containing
+/// every value otherwise. Values are ordered by when each thread was spawned.
+///
+/// See the [module documentation](crate::thread) for details.
+///
+/// [`Success`]: crate::prelude::Success
+/// [`Mistake`]: crate::prelude::Mistake
+/// [`Failure`]: crate::prelude::Failure
+pub fn scope_outcomes<'env, S, M, F, C>(
+  callable: C,
+) -> Outcome<Vec<S>, Vec<M>, Vec<F>>
+where
+  S: Send,
+  M: Send,
+  F: Send + From<Panicked>,
+  C: for<'scope> FnOnce(&Spawner<'scope, 'env, S, M, F>),
+{
+  std::thread::scope(|scope| {
+    let spawner = Spawner {
+      scope,
+      handles: RefCell::new(Vec::new()),
+    };
+    callable(&spawner);
+    spawner
+      .handles
+      .into_inner()
+      .into_iter()
+      .map(|handle| {
+        handle
+          .join()
+          .unwrap_or_else(|payload: Box<dyn Any + Send>| {
+            Failure(Panicked::new(&*payload).into())
+          })
+      })
+      .collect::<Outcomes<_, _, _>>()
+  })
+  .into_outcome()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn panics_become_failures() {
+    let outcome: Outcome<Vec<()>, Vec<()>, Vec<Panicked>> =
+      scope_outcomes(|s| {
+        s.spawn(|| Success(()));
+        s.spawn(|| panic!("boom"));
+      });
+    let failures = outcome.unwrap_failure();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].message(), Some("boom"));
+  }
+
+  #[test]
+  fn successes_in_spawn_order() {
+    let outcome: Outcome<_, Vec<()>, Vec<Panicked>> = scope_outcomes(|s| {
+      for n in 0..4 {
+        s.spawn(move || Success(n));
+      }
+    });
+    assert_eq!(outcome, Success(std::vec![0, 1, 2, 3]));
+  }
+}
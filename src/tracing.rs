@@ -0,0 +1,116 @@
+//! Integration with the [`tracing`] instrumentation framework.
+//!
+//! The [`TracedOutcome`] extension trait emits an event for an [`Outcome`],
+//! or records its variant on a [`Span`], and returns the [`Outcome`] as is, so
+//! that it can be placed in the middle of a call chain.
+//!
+//! Every event has an `outcome` field containing the name of the variant
+//! (`success`, `mistake`, or `failure`), and a `value` field containing the
+//! [`Debug`] representation of the contained value. The level of the event
+//! is chosen per variant, with [`TracedOutcome::traced`].
+//!
+//! Additionally, the value discarded by [`Outcome::ok_mistake`] or
+//! [`Outcome::ok_failure`] is emitted as a debug level event.
+//!
+//! This module does not require `std`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use outcome::tracing::TracedOutcome;
+//! use tracing::{field, info_span, Level};
+//!
+//! fn connect() -> Outcome<u16, &'static str, &'static str> {
+//!   Mistake("connection refused")
+//! }
+//!
+//! let span = info_span!("connect", outcome = field::Empty);
+//! let port = connect()
+//!   .record_outcome(&span)
+//!   .traced(Level::DEBUG, Level::WARN, Level::ERROR);
+//! assert_eq!(port, Mistake("connection refused"));
+//! ```
+//!
+//! [`tracing`]: https://docs.rs/tracing
+//! [`Span`]: ::tracing::Span
+//! [`Outcome`]: crate::prelude::Outcome
+//! [`Outcome::ok_mistake`]: crate::prelude::Outcome::ok_mistake
+//! [`Outcome::ok_failure`]: crate::prelude::Outcome::ok_failure
+//! [`Debug`]: core::fmt::Debug
+use core::fmt::Debug;
+
+use ::tracing::{field, Level, Span};
+
+use crate::prelude::*;
+
+/* `tracing::event!` requires a level known at compile time, so the level
+ * chosen at runtime is dispatched to one of five invocations.
+ */
+macro_rules! event {
+  ($level:expr, $($field:tt)+) => {
+    match $level {
+      Level::TRACE => ::tracing::event!(Level::TRACE, $($field)+),
+      Level::DEBUG => ::tracing::event!(Level::DEBUG, $($field)+),
+      Level::INFO => ::tracing::event!(Level::INFO, $($field)+),
+      Level::WARN => ::tracing::event!(Level::WARN, $($field)+),
+      Level::ERROR => ::tracing::event!(Level::ERROR, $($field)+),
+    }
+  };
+}
+
+/// Emits [`tracing`] events for an [`Outcome`].
+///
+/// This trait is sealed and cannot be implemented for types outside of
+/// `outcome`.
+///
+/// See the [module documentation](crate::tracing) for details.
+///
+/// [`tracing`]: https://docs.rs/tracing
+/// [`Outcome`]: crate::prelude::Outcome
+pub trait TracedOutcome: crate::private::Sealed + Sized {
+  /// Emits an event at the level given for the variant of the outcome, and
+  /// returns the outcome unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # use outcome::prelude::*;
+  /// use outcome::tracing::TracedOutcome;
+  /// use tracing::Level;
+  ///
+  /// let x: Outcome<u32, &str, &str> = Failure("disk is full");
+  /// let x = x.traced(Level::TRACE, Level::WARN, Level::ERROR);
+  /// assert_eq!(x, Failure("disk is full"));
+  /// ```
+  #[must_use]
+  fn traced(self, success: Level, mistake: Level, failure: Level) -> Self;
+
+  /// Records the name of the variant of the outcome in the `outcome` field
+  /// of `span`, and returns the outcome unchanged.
+  ///
+  /// As with [`Span::record`], the `outcome` field must have been declared
+  /// when the span was created, usually as [`field::Empty`].
+  ///
+  /// [`Span::record`]: ::tracing::Span::record
+  /// [`field::Empty`]: ::tracing::field::Empty
+  #[must_use]
+  fn record_outcome(self, span: &Span) -> Self;
+}
+
+impl<S: Debug, M: Debug, F: Debug> TracedOutcome for Outcome<S, M, F> {
+  fn traced(self, success: Level, mistake: Level, failure: Level) -> Self {
+    match &self {
+      Success(value) => event!(success, outcome = "success", value = ?value),
+      Mistake(value) => event!(mistake, outcome = "mistake", value = ?value),
+      Failure(value) => event!(failure, outcome = "failure", value = ?value),
+    }
+    self
+  }
+
+  #[inline]
+  fn record_outcome(self, span: &Span) -> Self {
+    span.record("outcome", field::display(self.severity()));
+    self
+  }
+}
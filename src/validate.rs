@@ -0,0 +1,199 @@
+//! Support types for validating constructors.
+//!
+//! The [`AttemptNew`] derive generates an `attempt_new` constructor for a
+//! struct with named fields. Each field may carry one or more `#[attempt(...)]`
+//! attributes describing how its value is checked. Checks that a caller can
+//! correct (a value out of range, an empty string, etc.) are collected as
+//! [`Violation`]s and returned together as a [`Mistake`]. Structural errors,
+//! where the checks themselves cannot be carried out, are returned
+//! immediately as a [`Failure`] containing a [`Malformed`].
+//!
+//! The following attributes are supported:
+//!
+//!  - `#[attempt(range = <range>)]` &mdash; the value must be contained in the
+//!    given range expression.
+//!  - `#[attempt(non_empty)]` &mdash; the value's `is_empty()` method must
+//!    return `false`.
+//!  - `#[attempt(regex = "<pattern>")]` &mdash; the value must match the given
+//!    pattern. Requires the `regex` feature. A pattern that fails to compile
+//!    is a [`Failure`].
+//!  - `#[attempt(with = <path>)]` &mdash; calls a function with the signature
+//!    `fn(&T) -> Outcome<(), Reason, Malformed>`.
+//!
+//! # Examples
+//!
+//! ```
+//! use outcome::prelude::*;
+//! use outcome::validate::{Reason, Violation};
+//! use outcome::AttemptNew;
+//!
+//! #[derive(AttemptNew, Debug)]
+//! struct Volume {
+//!   #[attempt(non_empty)]
+//!   name: String,
+//!   #[attempt(range = 1..=100)]
+//!   level: u8,
+//! }
+//!
+//! let volume = Volume::attempt_new("master".into(), 47);
+//! assert!(volume.is_success());
+//!
+//! let volume = Volume::attempt_new(String::new(), 0);
+//! assert_eq!(
+//!   volume.unwrap_mistake(),
+//!   vec![
+//!     Violation::new("name", Reason::Empty),
+//!     Violation::new("level", Reason::OutOfRange),
+//!   ]
+//! );
+//! ```
+//!
+//! Custom validators may reject a value with a [`Reason`], or report a
+//! structural error with a [`Malformed`]:
+//!
+#![cfg_attr(feature = "regex", doc = "```")]
+#![cfg_attr(not(feature = "regex"), doc = "```ignore")]
+//! use outcome::prelude::*;
+//! use outcome::validate::{Malformed, Reason, Violation};
+//! use outcome::AttemptNew;
+//!
+//! fn even(value: &u32) -> Outcome<(), Reason, Malformed> {
+//!   match value % 2 {
+//!     0 => Success(()),
+//!     _ => Mistake(Reason::Invalid("value must be even")),
+//!   }
+//! }
+//!
+//! #[derive(AttemptNew, Debug)]
+//! struct Account {
+//!   #[attempt(non_empty, regex = "^[a-z]+$")]
+//!   user: String,
+//!   #[attempt(with = even)]
+//!   pin: u32,
+//! }
+//!
+//! let account = Account::attempt_new("Root".into(), 47);
+//! assert_eq!(
+//!   account.unwrap_mistake(),
+//!   vec![
+//!     Violation::new("user", Reason::Mismatch),
+//!     Violation::new("pin", Reason::Invalid("value must be even")),
+//!   ]
+//! );
+//! ```
+//!
+//! [`AttemptNew`]: crate::AttemptNew
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter, Result};
+
+/// Describes why a single field was rejected.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Reason {
+  /// The value was not contained in the expected range.
+  OutOfRange,
+  /// The value was empty.
+  Empty,
+  /// The value did not match the expected pattern.
+  Mismatch,
+  /// The value was rejected by a custom validator.
+  Invalid(&'static str),
+}
+
+/// A correctable error found while validating a single field.
+///
+/// A list of these is returned as the [`Mistake`] of a generated
+/// `attempt_new` constructor.
+///
+/// [`Mistake`]: crate::prelude::Mistake
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Violation {
+  /// The name of the field that was rejected.
+  pub field: &'static str,
+  /// The reason the field was rejected.
+  pub reason: Reason,
+}
+
+/// A structural error that prevented a field from being validated at all.
+///
+/// This is returned as the [`Failure`] of a generated `attempt_new`
+/// constructor.
+///
+/// [`Failure`]: crate::prelude::Failure
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Malformed {
+  /// The name of the field that could not be validated.
+  pub field: &'static str,
+  /// A description of what went wrong.
+  pub message: String,
+}
+
+impl Violation {
+  /// Creates a new `Violation` for the given field.
+  #[inline]
+  pub fn new(field: &'static str, reason: Reason) -> Self {
+    Self { field, reason }
+  }
+}
+
+impl Malformed {
+  /// Creates a new `Malformed` for the given field.
+  #[inline]
+  pub fn new(field: &'static str, message: impl Into<String>) -> Self {
+    Self {
+      field,
+      message: message.into(),
+    }
+  }
+}
+
+impl Display for Reason {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    match self {
+      Self::OutOfRange => f.write_str("value is out of range"),
+      Self::Empty => f.write_str("value is empty"),
+      Self::Mismatch => f.write_str("value does not match the pattern"),
+      Self::Invalid(message) => f.write_str(message),
+    }
+  }
+}
+
+impl Display for Violation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    write!(f, "`{}`: {}", self.field, self.reason)
+  }
+}
+
+impl Display for Malformed {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    write!(
+      f,
+      "`{}` could not be validated: {}",
+      self.field, self.message
+    )
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Violation {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Malformed {}
+
+#[doc(hidden)]
+pub mod __private {
+  #[cfg(feature = "regex")]
+  extern crate std;
+
+  #[cfg(feature = "regex")]
+  pub use regex;
+  #[cfg(feature = "regex")]
+  pub use std::sync::OnceLock;
+
+  pub use alloc::{string::ToString, vec::Vec};
+}
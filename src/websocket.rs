@@ -0,0 +1,90 @@
+//! Classification of [`tungstenite`] WebSocket errors.
+//!
+//! Reconnect loops need to distinguish a socket that is merely not ready from
+//! a connection that is gone. This module implements [`Classify`] for
+//! [`tungstenite::Error`] and [`HandshakeError`], so that
+//! [`ResultExt::into_outcome`] produces the following:
+//!
+//!  - [`Mistake`]: an interrupted handshake, a full write buffer, and
+//!    [`Io`] errors that are a [`Mistake`] themselves, such as `WouldBlock`
+//!    or a timeout.
+//!  - [`Failure`]: protocol violations, closed connections, and every other
+//!    error.
+//!
+//! # Examples
+//!
+//! ```
+//! # use outcome::prelude::*;
+//! use std::io::{Error, ErrorKind};
+//! use outcome::ext::ResultExt;
+//! use tungstenite::error::ProtocolError;
+//!
+//! let x: Result<(), _> = Err(tungstenite::Error::Io(Error::from(ErrorKind::WouldBlock)));
+//! assert!(x.into_outcome().is_mistake());
+//!
+//! let x: Result<(), _> = Err(tungstenite::Error::Protocol(ProtocolError::ResetWithoutClosingHandshake));
+//! assert!(x.into_outcome().is_failure());
+//!
+//! let x: Result<(), _> = Err(tungstenite::Error::ConnectionClosed);
+//! assert!(x.into_outcome().is_failure());
+//! ```
+//!
+//! [`tungstenite`]: https://crates.io/crates/tungstenite
+//! [`HandshakeError`]: tungstenite::HandshakeError
+//! [`Io`]: tungstenite::Error::Io
+//! [`Classify`]: crate::ext::Classify
+//! [`ResultExt::into_outcome`]: crate::ext::ResultExt::into_outcome
+//! [`Mistake`]: crate::prelude::Mistake
+//! [`Failure`]: crate::prelude::Failure
+use tungstenite::{handshake::HandshakeRole, Error, HandshakeError};
+
+use crate::ext::Classify;
+
+impl Classify for Error {
+  fn is_mistake(&self) -> bool {
+    match self {
+      Self::Io(error) => error.is_mistake(),
+      Self::WriteBufferFull(_) => true,
+      _ => false,
+    }
+  }
+
+  fn kind(&self) -> Option<&str> {
+    match self {
+      Self::Io(error) => Classify::kind(error),
+      _ => None,
+    }
+  }
+
+  fn code(&self) -> Option<i64> {
+    match self {
+      Self::Io(error) => error.code(),
+      Self::Http(response) => Some(response.status().as_u16().into()),
+      _ => None,
+    }
+  }
+}
+
+impl<Role: HandshakeRole> Classify for HandshakeError<Role> {
+  #[inline]
+  fn is_mistake(&self) -> bool {
+    match self {
+      Self::Interrupted(_) => true,
+      Self::Failure(error) => error.is_mistake(),
+    }
+  }
+
+  fn kind(&self) -> Option<&str> {
+    match self {
+      Self::Interrupted(_) => None,
+      Self::Failure(error) => error.kind(),
+    }
+  }
+
+  fn code(&self) -> Option<i64> {
+    match self {
+      Self::Interrupted(_) => None,
+      Self::Failure(error) => error.code(),
+    }
+  }
+}
@@ -1,228 +1,93 @@
 #![allow(clippy::redundant_pub_crate)]
 
-macro_rules! r#trait {
-  ($type:ty) => {
-    /// This trait is meant to be the `outcome` analogue of *both*
-    /// [`miette::WrapErr`] and [`eyre::WrapErr`]. Therefore, any type that
-    /// implements `WrapErr` for either of these libraries will automatically work
-    /// with `WrapFailure`.
-    ///
-    /// This trait is sealed and cannot be implemented for types outside of this
-    /// `outcome`.
+macro_rules! r#aggregate {
+  ($module:ident) => {
+    /// A [`Report`] joining several failures, created by
+    /// [`aggregate_reports`].
     ///
-    /// [`Outcome`]: crate::prelude::Outcome
-    pub trait WrapFailure: crate::private::Sealed {
-      /// The expected return type for an `impl`.
-      ///
-      /// This will always be the same enumeration type, but with a [`Report`]
-      /// in the error or failure position.
-      type Return;
-
-      /// Wrap the failure value with a new adhoc error that is evaluated lazily
-      /// only once an error does occur.
-      fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D;
-
-      /// Wrap the failure value with a new adhoc error.
-      fn wrap_failure<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static;
-
-      /// Compatibility re-export of [`wrap_failure_with`] for interop with
-      /// [`anyhow`] and [`eyre`].
-      ///
-      /// [`wrap_failure_with`]: WrapFailure::wrap_failure_with
-      /// [`anyhow`]: https://crates.io/crates/anyhow
-      /// [`eyre`]: https://crates.io/crates/eyre
-      fn with_context<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D;
-      /// Compatibility re-export of [`wrap_failure`] for interop with
-      /// [`anyhow`] and [`eyre`].
-      ///
-      /// [`wrap_failure`]: WrapFailure::wrap_failure
-      /// [`anyhow`]: https://crates.io/crates/anyhow
-      /// [`eyre`]: https://crates.io/crates/eyre
-      fn context<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static;
+    /// Each failure is listed when this error is displayed.
+    #[derive(Debug)]
+    pub struct AggregateFailure {
+      failures: std::vec::Vec<Report>,
     }
-  };
-}
-
-macro_rules! r#impl {
-  ($type:ident) => {
-    impl<S, M, E> WrapFailure for Outcome<S, M, E>
-    where
-      E: $type + Send + Sync + 'static,
-    {
-      type Return = Outcome<S, M, Report>;
-
-      #[track_caller]
-      #[inline]
-      fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        self.map_failure(|f| Report::new(f).wrap_err(message()))
-      }
 
-      #[track_caller]
+    impl AggregateFailure {
+      /// Returns the joined failures, in the order they were encountered.
       #[inline]
-      fn wrap_failure<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        self.map_failure(|f| Report::new(f).wrap_err(message))
+      pub fn failures(&self) -> &[Report] {
+        &self.failures
       }
 
-      #[track_caller]
+      /// Consumes the `AggregateFailure`, returning the joined failures.
+      #[must_use]
       #[inline]
-      fn with_context<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        self.wrap_failure_with(message)
-      }
-
-      #[track_caller]
-      #[inline]
-      fn context<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        self.wrap_failure(message)
+      pub fn into_failures(self) -> std::vec::Vec<Report> {
+        self.failures
       }
     }
 
-    impl<M, E> WrapFailure for Aberration<M, E>
-    where
-      E: $type + Send + Sync + 'static,
-    {
-      type Return = Aberration<M, Report>;
-
-      #[track_caller]
-      #[inline]
-      fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        self.map_failure(|f| Report::new(f).wrap_err(message()))
-      }
-
-      #[track_caller]
-      #[inline]
-      fn wrap_failure<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        self.map_failure(|f| Report::new(f).wrap_err(message))
-      }
-
-      #[track_caller]
-      #[inline]
-      fn with_context<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        self.wrap_failure_with(message)
-      }
-
-      #[track_caller]
-      #[inline]
-      fn context<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        self.wrap_failure(message)
+    impl Display for AggregateFailure {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failures occurred", self.failures.len())?;
+        for (index, failure) in self.failures.iter().enumerate() {
+          write!(f, "\n  {index}: {failure:#}")?;
+        }
+        Ok(())
       }
     }
-  };
-}
 
-macro_rules! r#use {
-  (miette) => {
-    "use outcome::diagnostic::{WrapFailure, Result, Report};"
-  };
-  (eyre) => {
-    "use outcome::report::{WrapFailure, Result, Report};"
-  };
-}
+    impl std::error::Error for AggregateFailure {}
 
-macro_rules! r#result {
-  ($module:ident) => {
-    /// Implementation of [`WrapFailure`] for `Result<T, E>` for any
-    /// implementations of [`WrapErr`].
+    /// Collects every [`Success`], [`Mistake`], and [`Failure`] from
+    /// `outcomes`, joining all failures into a single [`Report`].
     ///
-    /// ```
-    #[doc = crate::wrap::r#use!($module)]
+    /// The result is a [`Failure`] if any failure occurred, otherwise a
+    /// [`Mistake`] containing every mistake if any occurred, and a
+    /// [`Success`] containing every success otherwise. A lone failure is
+    /// returned as is, while several failures are joined by an
+    /// [`AggregateFailure`].
     ///
-    /// fn execute() -> Result<()> {
-    ///   # Err(Report::msg("error here"))?;
-    ///   # const IGNORE: &str = stringify! {
-    ///   ...
-    ///   # };
-    ///   # unreachable!()
-    /// }
+    /// ```
+    /// # use outcome::prelude::*;
+    #[doc = concat!("use outcome::", stringify!($module), "::{aggregate_reports, AggregateFailure, Report};")]
     ///
-    /// pub fn invoke() -> Result<Vec<u8>> {
-    ///   execute().wrap_failure("Failed to execute correctly")?;
-    ///   Ok(vec![])
-    /// }
+    /// let batch: Vec<Outcome<u32, (), Report>> = vec![
+    ///   Success(1),
+    ///   Failure(Report::msg("disk full")),
+    ///   Failure(Report::msg("permission denied")),
+    /// ];
+    /// let report = aggregate_reports(batch).unwrap_failure();
+    /// let aggregate = report.downcast_ref::<AggregateFailure>().unwrap();
+    /// assert_eq!(aggregate.failures().len(), 2);
+    /// assert!(report.to_string().starts_with("2 failures occurred"));
     /// ```
-    #[doc = concat!("[`WrapErr`]:", stringify!($module), "::WrapErr")]
-    impl<T, E> WrapFailure for Result<T, E>
+    ///
+    /// [`Success`]: crate::prelude::Success
+    /// [`Mistake`]: crate::prelude::Mistake
+    /// [`Failure`]: crate::prelude::Failure
+    pub fn aggregate_reports<I, S, M, F>(
+      outcomes: I,
+    ) -> Outcome<std::vec::Vec<S>, std::vec::Vec<M>, Report>
     where
-      Self: $module::WrapErr<T, E>,
+      I: IntoIterator<Item = Outcome<S, M, F>>,
+      F: Into<Report>,
     {
-      type Return = Result<T, $module::Report>;
-
-      #[track_caller]
-      #[inline]
-      fn wrap_failure_with<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        $module::WrapErr::wrap_err_with(self, message)
-      }
-
-      #[track_caller]
-      #[inline]
-      fn wrap_failure<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        $module::WrapErr::wrap_err(self, message)
-      }
-
-      #[track_caller]
-      #[inline]
-      fn with_context<D, F>(self, message: F) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-        F: FnOnce() -> D,
-      {
-        $module::WrapErr::with_context(self, message)
-      }
-
-      #[track_caller]
-      #[inline]
-      fn context<D>(self, message: D) -> Self::Return
-      where
-        D: Display + Send + Sync + 'static,
-      {
-        $module::WrapErr::context(self, message)
+      use crate::OutcomeIteratorExt;
+
+      let outcomes = outcomes.into_iter().map(|o| o.map_failure(Into::into));
+      match outcomes.aggregate_failures() {
+        Success(successes) => Success(successes),
+        Mistake(mistakes) => Mistake(mistakes),
+        Failure(mut failures) => Failure(match failures.pop() {
+          Some(report) if failures.is_empty() => report,
+          last => {
+            failures.extend(last);
+            Report::new(AggregateFailure { failures })
+          }
+        }),
       }
     }
   };
 }
 
-pub(crate) use {r#impl, r#result, r#trait, r#use};
+pub(crate) use r#aggregate;